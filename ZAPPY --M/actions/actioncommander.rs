@@ -1,23 +1,106 @@
-use std::sync::Arc;
+use std::{
+    cmp::Reverse,
+    sync::Arc,
+    time::{Duration as StdDuration, Instant},
+};
 
+use chrono::{Duration, Utc};
+use indexmap::IndexMap;
 use serde_json::json;
 use shared_logging::LogLevel;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    actions::{ActionError, ActionJournal, ActionOutcome, ActionRequest},
-    agents::{AgentRegistry, ExecutionContext},
+    actions::{
+        ActionDomain, ActionError, ActionEvent, ActionId, ActionJournal, ActionOutcome, ActionPlan,
+        ActionRequest, ActionStatus,
+    },
+    agents::{ActionAgent, AgentRegistry, ExecutionContext},
+    audit::{AuditKind, AuditSink},
+    callback::OutcomeCallback,
     commandgeneration::{CommandGenerator, HeuristicCommandGenerator},
     security_link::SecurityLink,
     telemetry::ActionTelemetry,
 };
 
+/// Default cap on a single retry delay for [`RetryPolicy::exponential`].
+pub const DEFAULT_MAX_BACKOFF: StdDuration = StdDuration::from_mins(1);
+
+/// Retry behaviour applied when an agent fails with a transient error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: StdDuration,
+    /// Factor applied to the delay after every retry.
+    pub multiplier: f64,
+    /// Upper bound on any single delay.
+    pub max_backoff: StdDuration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Executes once and never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: StdDuration::ZERO,
+            multiplier: 1.0,
+            max_backoff: StdDuration::ZERO,
+        }
+    }
+
+    /// Retries up to `max_attempts` total, doubling the delay each time up to
+    /// [`DEFAULT_MAX_BACKOFF`].
+    #[must_use]
+    pub fn exponential(max_attempts: u32, initial_backoff: StdDuration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            multiplier: 2.0,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Overrides the upper bound on a single delay.
+    #[must_use]
+    pub const fn with_max_backoff(mut self, max_backoff: StdDuration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Delay to wait after the given (1-based) failed attempt, saturating at
+    /// `max_backoff`.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> StdDuration {
+        if self.initial_backoff.is_zero() {
+            return StdDuration::ZERO;
+        }
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let factor = self.multiplier.max(1.0).powi(exponent);
+        StdDuration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+}
+
 /// Builder used to configure an [`ActionCommander`].
 pub struct ActionCommanderBuilder {
     registry: AgentRegistry,
     generator: Arc<dyn CommandGenerator>,
     security: SecurityLink,
     telemetry: Option<ActionTelemetry>,
+    retry_policies: IndexMap<String, RetryPolicy>,
+    audit: Option<Arc<AuditSink>>,
+    max_concurrency: Option<usize>,
+    callback: Option<OutcomeCallback>,
 }
 
 impl Default for ActionCommanderBuilder {
@@ -27,6 +110,10 @@ impl Default for ActionCommanderBuilder {
             generator: Arc::new(HeuristicCommandGenerator::default()),
             security: SecurityLink::builder().build(),
             telemetry: None,
+            retry_policies: IndexMap::new(),
+            audit: None,
+            max_concurrency: None,
+            callback: None,
         }
     }
 }
@@ -60,6 +147,35 @@ impl ActionCommanderBuilder {
         self
     }
 
+    /// Sets the retry policy for actions in `domain`; other domains never retry.
+    #[must_use]
+    pub fn retry_policy(mut self, domain: &ActionDomain, policy: RetryPolicy) -> Self {
+        self.retry_policies
+            .insert(domain.label().to_string(), policy);
+        self
+    }
+
+    /// Records every request and final outcome in a hash-chained audit log.
+    #[must_use]
+    pub fn audit(mut self, sink: AuditSink) -> Self {
+        self.audit = Some(Arc::new(sink));
+        self
+    }
+
+    /// Caps the number of agents executing at once; further actions wait their turn.
+    #[must_use]
+    pub const fn max_concurrency(mut self, limit: usize) -> Self {
+        self.max_concurrency = Some(limit);
+        self
+    }
+
+    /// Delivers outcomes to request callback URLs; without one, callback URLs are ignored.
+    #[must_use]
+    pub fn callback(mut self, callback: OutcomeCallback) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
     /// Finalizes the builder returning an [`ActionCommander`].
     #[must_use]
     pub fn build(self) -> ActionCommander {
@@ -69,6 +185,12 @@ impl ActionCommanderBuilder {
             security: self.security,
             journal: ActionJournal::new(),
             telemetry: self.telemetry,
+            retry_policies: self.retry_policies,
+            audit: self.audit,
+            concurrency: self
+                .max_concurrency
+                .map(|limit| Arc::new(Semaphore::new(limit.max(1)))),
+            callback: self.callback.map(Arc::new),
         }
     }
 }
@@ -80,6 +202,10 @@ pub struct ActionCommander {
     security: SecurityLink,
     journal: ActionJournal,
     telemetry: Option<ActionTelemetry>,
+    retry_policies: IndexMap<String, RetryPolicy>,
+    audit: Option<Arc<AuditSink>>,
+    concurrency: Option<Arc<Semaphore>>,
+    callback: Option<Arc<OutcomeCallback>>,
 }
 
 impl ActionCommander {
@@ -95,27 +221,105 @@ impl ActionCommander {
         self.journal.clone()
     }
 
+    /// Returns the retry policy applied to `domain`.
+    #[must_use]
+    pub fn retry_policy(&self, domain: &ActionDomain) -> RetryPolicy {
+        self.retry_policies
+            .get(domain.label())
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Submits an action for execution.
+    ///
+    /// When concurrency is capped this waits for an execution slot, so callers see
+    /// backpressure instead of an unbounded backlog of spawned tasks.
+    ///
+    /// # Errors
+    /// Returns the security, planning, or routing error that rejected the request, or
+    /// [`ActionError::Infrastructure`] if the request cannot be audited.
     pub async fn submit(&self, request: ActionRequest) -> Result<ExecutionHandle, ActionError> {
-        self.log(
-            LogLevel::Info,
-            "actions.request.accepted",
-            json!({
-                "action_id": request.id,
-                "domain": request.domain.label(),
-                "intent": request.intent.label(),
-                "priority": format!("{:?}", request.priority)
-            }),
-        );
-        self.event(
-            "actions.request.accepted",
-            json!({
-                "action_id": request.id,
-                "domain": request.domain.label(),
-                "intent": request.intent.label(),
-                "priority": format!("{:?}", request.priority)
-            }),
-        );
+        self.submit_cancellable(request, CancellationToken::new())
+            .await
+    }
+
+    /// Submits several actions at once, highest priority first.
+    ///
+    /// Execution slots are taken in priority order, so under a concurrency cap a
+    /// higher-priority request never waits behind a lower-priority one; this returns
+    /// once every request holds a slot. Requests rejected during submission resolve as
+    /// failed outcomes instead of aborting the rest of the batch.
+    pub async fn submit_batch(&self, requests: Vec<ActionRequest>) -> BatchHandle {
+        let mut queued = requests.into_iter().enumerate().collect::<Vec<_>>();
+        queued.sort_by_key(|(_, request)| Reverse(request.priority));
+        let mut entries = Vec::with_capacity(queued.len());
+        for (idx, request) in queued {
+            let started = Instant::now();
+            let entry = match self.submit(request).await {
+                Ok(handle) => BatchEntry::Running(handle),
+                Err(err) => {
+                    BatchEntry::Rejected(ActionOutcome::failed(&err, elapsed_since(started)))
+                }
+            };
+            entries.push((idx, entry));
+        }
+        entries.sort_by_key(|(idx, _)| *idx);
+        BatchHandle {
+            entries: entries.into_iter().map(|(_, entry)| entry).collect(),
+        }
+    }
+
+    /// Submits an action that is aborted once `cancellation` fires.
+    ///
+    /// The token is handed to the agent through [`ExecutionContext`]; agents that never
+    /// check it are still dropped at the commander's next checkpoint.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Self::submit`], plus [`ActionError::Cancelled`] when
+    /// `cancellation` fires while the request waits for an execution slot.
+    pub async fn submit_cancellable(
+        &self,
+        request: ActionRequest,
+        cancellation: CancellationToken,
+    ) -> Result<ExecutionHandle, ActionError> {
+        let action_id = request.id;
+        let callback_url = request.callback_url.clone();
+        if let Some(audit) = &self.audit {
+            audit
+                .append(action_id, AuditKind::Request, json!(request))
+                .map_err(|err| ActionError::Infrastructure(format!("audit log: {err}")))?;
+        }
+        let started = Instant::now();
+        let result = self.dispatch(request, cancellation).await;
+        if let Err(err) = &result {
+            let outcome = ActionOutcome::failed(err, elapsed_since(started));
+            audit_outcome(
+                self.audit.as_deref(),
+                self.telemetry.as_ref(),
+                action_id,
+                &outcome,
+            );
+            let denied = matches!(err, ActionError::SecurityViolation { .. });
+            if let (Some(callback), Some(url), false) = (&self.callback, callback_url, denied) {
+                callback.dispatch(url, action_id, outcome, self.telemetry.clone());
+            }
+        }
+        result
+    }
+
+    async fn dispatch(
+        &self,
+        request: ActionRequest,
+        cancellation: CancellationToken,
+    ) -> Result<ExecutionHandle, ActionError> {
+        let accepted = json!({
+            "action_id": request.id,
+            "domain": request.domain.label(),
+            "intent": request.intent.label(),
+            "priority": format!("{:?}", request.priority)
+        });
+        self.log(LogLevel::Info, "actions.request.accepted", accepted.clone());
+        self.event("actions.request.accepted", accepted);
 
         let verdict = self.security.enforce(&request).await?;
         self.log(
@@ -162,6 +366,7 @@ impl ActionCommander {
         let ctx = ExecutionContext {
             journal: self.journal.clone(),
             security_grade: verdict.grade,
+            cancellation: cancellation.clone(),
         };
 
         self.log(
@@ -181,64 +386,372 @@ impl ActionCommander {
             }),
         );
 
+        let permit = match acquire_slot(self.concurrency.clone(), &cancellation).await {
+            Ok(permit) => permit,
+            Err(err) => {
+                journal_interruption(&self.journal, request.id, &err, 0);
+                return Err(err);
+            }
+        };
+        let task = ActionTask {
+            agent,
+            plan,
+            ctx,
+            policy: self.retry_policy(&request.domain),
+            telemetry: self.telemetry.clone(),
+            audit: self.audit.clone(),
+            journal: self.journal.clone(),
+            callback: self.callback.clone().zip(request.callback_url.clone()),
+            request,
+        };
         let (tx, rx) = oneshot::channel();
-        let telemetry = self.telemetry.clone();
-        let action_id = request.id;
         tokio::spawn(async move {
-            let result = agent.execute(request, plan, ctx).await;
-            if let Some(tel) = telemetry {
-                match &result {
-                    Ok(outcome) => {
-                        let _ = tel.log(
-                            LogLevel::Info,
-                            "actions.agent.completed",
-                            json!({
-                                "action_id": action_id,
-                                "summary": outcome.summary,
-                            }),
-                        );
-                        let _ = tel.event(
-                            "actions.agent.completed",
-                            json!({
-                                "action_id": action_id,
-                                "status": "success"
-                            }),
-                        );
-                    }
-                    Err(err) => {
-                        let _ = tel.log(
-                            LogLevel::Error,
-                            "actions.agent.failed",
-                            json!({
-                                "error": err.to_string(),
-                            }),
-                        );
-                        let _ =
-                            tel.event("actions.agent.failed", json!({ "error": err.to_string() }));
-                    }
+            let _ = tx.send(task.run(permit).await);
+        });
+
+        Ok(ExecutionHandle {
+            rx,
+            submitted: Instant::now(),
+            cancellation,
+        })
+    }
+}
+
+/// Everything a spawned action needs once it holds an execution slot.
+struct ActionTask {
+    agent: Arc<dyn ActionAgent>,
+    request: ActionRequest,
+    plan: ActionPlan,
+    ctx: ExecutionContext,
+    policy: RetryPolicy,
+    telemetry: Option<ActionTelemetry>,
+    audit: Option<Arc<AuditSink>>,
+    journal: ActionJournal,
+    callback: Option<(Arc<OutcomeCallback>, String)>,
+}
+
+impl ActionTask {
+    /// Executes with retries while holding `_permit`, then journals, reports, audits,
+    /// and fires the outcome callback.
+    async fn run(
+        self,
+        _permit: Option<OwnedSemaphorePermit>,
+    ) -> (Result<ActionOutcome, ActionError>, u32) {
+        let Self {
+            agent,
+            request,
+            plan,
+            ctx,
+            policy,
+            telemetry,
+            audit,
+            journal,
+            callback,
+        } = self;
+        let action_id = request.id;
+        let started = Instant::now();
+        let (result, attempts) = execute_with_retry(
+            agent.as_ref(),
+            request,
+            plan,
+            ctx,
+            policy,
+            telemetry.as_ref(),
+        )
+        .await;
+        let result = result.map(|mut outcome| {
+            outcome.duration = elapsed_since(started);
+            outcome.attempts = attempts;
+            outcome
+        });
+        if let Err(err) = &result {
+            journal_interruption(&journal, action_id, err, attempts);
+        }
+        if let Some(tel) = &telemetry {
+            report_completion(tel, action_id, &result, attempts);
+        }
+        if audit.is_some() || callback.is_some() {
+            let outcome = match &result {
+                Ok(outcome) => outcome.clone(),
+                Err(err) => {
+                    let mut outcome = ActionOutcome::failed(err, elapsed_since(started));
+                    outcome.attempts = attempts;
+                    outcome
                 }
+            };
+            audit_outcome(audit.as_deref(), telemetry.as_ref(), action_id, &outcome);
+            if let Some((callback, url)) = callback {
+                callback.dispatch(url, action_id, outcome, telemetry);
             }
-            let _ = tx.send(result);
-        });
+        }
+        (result, attempts)
+    }
+}
 
-        Ok(ExecutionHandle { rx })
+fn report_completion(
+    tel: &ActionTelemetry,
+    action_id: ActionId,
+    result: &Result<ActionOutcome, ActionError>,
+    attempts: u32,
+) {
+    match result {
+        Ok(outcome) => {
+            let _ = tel.log(
+                LogLevel::Info,
+                "actions.agent.completed",
+                json!({
+                    "action_id": action_id,
+                    "summary": outcome.summary,
+                }),
+            );
+            let _ = tel.event(
+                "actions.agent.completed",
+                json!({
+                    "action_id": action_id,
+                    "status": "success"
+                }),
+            );
+        }
+        Err(err) => {
+            let _ = tel.log(
+                LogLevel::Error,
+                "actions.agent.failed",
+                json!({
+                    "error": err.to_string(),
+                    "attempts": attempts,
+                }),
+            );
+            let _ = tel.event("actions.agent.failed", json!({ "error": err.to_string() }));
+        }
     }
 }
 
 /// Handle returned to await action completion.
 pub struct ExecutionHandle {
-    rx: oneshot::Receiver<Result<ActionOutcome, ActionError>>,
+    rx: oneshot::Receiver<(Result<ActionOutcome, ActionError>, u32)>,
+    submitted: Instant,
+    cancellation: CancellationToken,
 }
 
 impl ExecutionHandle {
+    /// Requests cancellation; the outcome resolves as cancelled unless it already finished.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
     /// Awaits the final outcome.
     pub async fn outcome(self) -> Result<ActionOutcome, ActionError> {
         self.rx
             .await
             .map_err(|err| ActionError::Infrastructure(err.to_string()))?
+            .0
+    }
+
+    /// Awaits the final outcome, folding execution errors into a failed [`ActionOutcome`].
+    pub async fn report(self) -> ActionOutcome {
+        match self.rx.await {
+            Ok((Ok(outcome), _)) => outcome,
+            Ok((Err(err), attempts)) => {
+                let mut outcome = ActionOutcome::failed(&err, elapsed_since(self.submitted));
+                outcome.attempts = attempts;
+                outcome
+            }
+            Err(err) => ActionOutcome::failed(
+                &ActionError::Infrastructure(err.to_string()),
+                elapsed_since(self.submitted),
+            ),
+        }
+    }
+}
+
+enum BatchEntry {
+    Running(ExecutionHandle),
+    Rejected(ActionOutcome),
+}
+
+/// Handle returned by [`ActionCommander::submit_batch`].
+pub struct BatchHandle {
+    entries: Vec<BatchEntry>,
+}
+
+impl BatchHandle {
+    /// Number of actions in the batch.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the batch is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Awaits every action, returning outcomes in submission order.
+    pub async fn await_all(self) -> Vec<ActionOutcome> {
+        let mut outcomes = Vec::with_capacity(self.entries.len());
+        for entry in self.entries {
+            outcomes.push(match entry {
+                BatchEntry::Running(handle) => handle.report().await,
+                BatchEntry::Rejected(outcome) => outcome,
+            });
+        }
+        outcomes
+    }
+}
+
+/// Aggregate result of a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// Actions that succeeded.
+    pub completed: usize,
+    /// Actions that failed, were cancelled, or timed out.
+    pub failed: usize,
+}
+
+impl BatchSummary {
+    /// Counts successful and unsuccessful outcomes.
+    #[must_use]
+    pub fn from_outcomes(outcomes: &[ActionOutcome]) -> Self {
+        let completed = outcomes
+            .iter()
+            .filter(|outcome| outcome.is_success())
+            .count();
+        Self {
+            completed,
+            failed: outcomes.len() - completed,
+        }
+    }
+}
+
+async fn execute_with_retry(
+    agent: &dyn ActionAgent,
+    request: ActionRequest,
+    plan: ActionPlan,
+    ctx: ExecutionContext,
+    policy: RetryPolicy,
+    telemetry: Option<&ActionTelemetry>,
+) -> (Result<ActionOutcome, ActionError>, u32) {
+    let deadline = request
+        .timeout
+        .map(|timeout| tokio::time::Instant::now() + timeout);
+    let mut attempt = 1;
+    loop {
+        let cancellation = ctx.cancellation.clone();
+        let result = match ctx.checkpoint() {
+            Ok(()) => tokio::select! {
+                biased;
+                () = cancellation.cancelled() => {
+                    Err(ActionError::Cancelled("cancelled by caller".into()))
+                }
+                () = expired(deadline) => Err(timed_out(&request)),
+                result = agent.execute(request.clone(), plan.clone(), ctx.clone()) => result,
+            },
+            Err(err) => Err(err),
+        };
+        let out_of_time = deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline);
+        match result {
+            Err(err) if err.is_transient() && attempt < policy.max_attempts && !out_of_time => {
+                let delay = policy.backoff(attempt);
+                if let Some(tel) = telemetry {
+                    let _ = tel.log(
+                        LogLevel::Warn,
+                        "actions.agent.retry",
+                        json!({
+                            "action_id": request.id,
+                            "attempt": attempt,
+                            "delay_ms": delay.as_millis(),
+                            "error": err.to_string(),
+                        }),
+                    );
+                }
+                tokio::select! {
+                    () = cancellation.cancelled() => {}
+                    () = expired(deadline) => {}
+                    () = tokio::time::sleep(delay) => {}
+                }
+                attempt += 1;
+            }
+            other => return (other, attempt),
+        }
     }
 }
 
+/// Records cancellations and timeouts, which end an action without an agent verdict.
+fn journal_interruption(
+    journal: &ActionJournal,
+    action_id: ActionId,
+    err: &ActionError,
+    attempts: u32,
+) {
+    let (status, verb) = match err {
+        ActionError::Cancelled(reason) => (ActionStatus::Cancelled(reason.clone()), "cancelled"),
+        ActionError::Timeout(_) => (ActionStatus::Failed(err.clone()), "timed out"),
+        _ => return,
+    };
+    journal.push(ActionEvent {
+        id: action_id,
+        timestamp: Utc::now(),
+        status,
+        note: Some(format!("{verb} after {attempts} attempt(s)")),
+    });
+}
+
+/// Waits for an execution slot when the commander caps concurrency, giving up with
+/// [`ActionError::Cancelled`] if `cancellation` fires first.
+async fn acquire_slot(
+    concurrency: Option<Arc<Semaphore>>,
+    cancellation: &CancellationToken,
+) -> Result<Option<OwnedSemaphorePermit>, ActionError> {
+    let Some(semaphore) = concurrency else {
+        return Ok(None);
+    };
+    tokio::select! {
+        biased;
+        () = cancellation.cancelled() => {
+            Err(ActionError::Cancelled("cancelled while queued".into()))
+        }
+        permit = semaphore.acquire_owned() => Ok(permit.ok()),
+    }
+}
+
+/// Resolves at `deadline`, or never when there is none.
+async fn expired(deadline: Option<tokio::time::Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+fn timed_out(request: &ActionRequest) -> ActionError {
+    let timeout = request.timeout.unwrap_or_default();
+    ActionError::Timeout(Duration::from_std(timeout).unwrap_or(Duration::MAX))
+}
+
+fn audit_outcome(
+    audit: Option<&AuditSink>,
+    telemetry: Option<&ActionTelemetry>,
+    action_id: ActionId,
+    outcome: &ActionOutcome,
+) {
+    let Some(audit) = audit else {
+        return;
+    };
+    if let Err(err) = audit.append(action_id, AuditKind::Outcome, json!(outcome)) {
+        if let Some(tel) = telemetry {
+            let _ = tel.log(
+                LogLevel::Error,
+                "actions.audit.failed",
+                json!({ "action_id": action_id, "error": err.to_string() }),
+            );
+        }
+    }
+}
+
+fn elapsed_since(started: Instant) -> Duration {
+    Duration::from_std(started.elapsed()).unwrap_or_else(|_| Duration::zero())
+}
+
 impl ActionCommander {
     fn log(&self, level: LogLevel, message: &str, metadata: serde_json::Value) {
         if let Some(tel) = &self.telemetry {
@@ -255,9 +768,22 @@ impl ActionCommander {
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            mpsc,
+        },
+    };
+
+    use async_trait::async_trait;
+
     use super::*;
-    use crate::actions::{
-        ActionDomain, ActionIntent, ActionPayload, ActionRequest, PayloadAttachment,
+    use crate::{
+        actions::{ActionIntent, ActionPayload, ActionPriority, OutcomeStatus, PayloadAttachment},
+        audit::{AuditError, AuditRecord},
+        callback::{CallbackError, ACTION_ID_HEADER},
     };
 
     #[tokio::test]
@@ -284,5 +810,560 @@ mod tests {
         let handle = commander.submit(request).await.unwrap();
         let outcome = handle.outcome().await.unwrap();
         assert!(outcome.summary.contains("Prepared"));
+        assert!(outcome.is_success());
+        assert!(outcome.error.is_none());
+        let diff = outcome
+            .attachment("proposed_diff")
+            .expect("diff attachment");
+        assert_eq!(diff.content["path"], "src/lib.rs");
+        assert!(diff.content["diff"]
+            .as_str()
+            .unwrap()
+            .contains("+fn new_func() {}"));
+    }
+
+    struct FlakyAgent {
+        failures_left: AtomicU32,
+    }
+
+    #[async_trait]
+    impl ActionAgent for FlakyAgent {
+        fn domain(&self) -> ActionDomain {
+            ActionDomain::Network
+        }
+
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn execute(
+            &self,
+            _request: ActionRequest,
+            _plan: ActionPlan,
+            _ctx: ExecutionContext,
+        ) -> Result<ActionOutcome, ActionError> {
+            if self.failures_left.load(Ordering::SeqCst) > 0 {
+                self.failures_left.fetch_sub(1, Ordering::SeqCst);
+                return Err(ActionError::Execution("upstream reset".into()));
+            }
+            Ok(ActionOutcome::textual("fetched", Vec::new()))
+        }
+    }
+
+    fn flaky_commander(failures: u32, policy: RetryPolicy) -> ActionCommander {
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(FlakyAgent {
+            failures_left: AtomicU32::new(failures),
+        }));
+        ActionCommander::builder()
+            .registry(registry)
+            .retry_policy(&ActionDomain::Network, policy)
+            .build()
+    }
+
+    fn network_request() -> ActionRequest {
+        ActionRequest::builder(
+            ActionDomain::Network,
+            ActionIntent::Observe,
+            ActionPayload::textual("Fetch status", "poll the status page"),
+        )
+        .build()
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures_until_success() {
+        let policy = RetryPolicy::exponential(3, StdDuration::from_millis(1));
+        let commander = flaky_commander(2, policy);
+        let outcome = commander
+            .submit(network_request())
+            .await
+            .unwrap()
+            .outcome()
+            .await
+            .unwrap();
+        assert!(outcome.is_success());
+        assert_eq!(outcome.attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let policy = RetryPolicy::exponential(2, StdDuration::from_millis(1));
+        let commander = flaky_commander(5, policy);
+        let outcome = commander
+            .submit(network_request())
+            .await
+            .unwrap()
+            .report()
+            .await;
+        assert_eq!(outcome.status, OutcomeStatus::Failed);
+        assert_eq!(outcome.attempts, 2);
+    }
+
+    struct SlowAgent;
+
+    #[async_trait]
+    impl ActionAgent for SlowAgent {
+        fn domain(&self) -> ActionDomain {
+            ActionDomain::Network
+        }
+
+        fn name(&self) -> &'static str {
+            "slow"
+        }
+
+        async fn execute(
+            &self,
+            _request: ActionRequest,
+            _plan: ActionPlan,
+            _ctx: ExecutionContext,
+        ) -> Result<ActionOutcome, ActionError> {
+            tokio::time::sleep(StdDuration::from_secs(30)).await;
+            Ok(ActionOutcome::textual("finished", Vec::new()))
+        }
+    }
+
+    #[tokio::test]
+    async fn cancelling_slow_agent_yields_cancelled_outcome() {
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(SlowAgent));
+        let commander = ActionCommander::builder().registry(registry).build();
+        let token = CancellationToken::new();
+        let handle = commander
+            .submit_cancellable(network_request(), token.clone())
+            .await
+            .unwrap();
+        tokio::time::sleep(StdDuration::from_millis(20)).await;
+        token.cancel();
+
+        let outcome = tokio::time::timeout(StdDuration::from_secs(5), handle.report())
+            .await
+            .expect("cancellation should resolve promptly");
+        assert_eq!(outcome.status, OutcomeStatus::Cancelled);
+        assert!(commander
+            .journal()
+            .snapshot()
+            .iter()
+            .any(|event| matches!(event.status, ActionStatus::Cancelled(_))));
+    }
+
+    #[tokio::test]
+    async fn slow_agent_times_out_at_request_deadline() {
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(SlowAgent));
+        let commander = ActionCommander::builder()
+            .registry(registry)
+            .retry_policy(
+                &ActionDomain::Network,
+                RetryPolicy::exponential(3, StdDuration::from_millis(1)),
+            )
+            .build();
+        let request = ActionRequest::builder(
+            ActionDomain::Network,
+            ActionIntent::Observe,
+            ActionPayload::textual("Fetch status", "poll the status page"),
+        )
+        .timeout(StdDuration::from_millis(30))
+        .build();
+        let handle = commander.submit(request).await.unwrap();
+
+        let outcome = tokio::time::timeout(StdDuration::from_secs(5), handle.report())
+            .await
+            .expect("timeout should resolve promptly");
+        assert_eq!(outcome.status, OutcomeStatus::TimedOut);
+        assert_eq!(outcome.attempts, 1);
+        assert!(commander
+            .journal()
+            .snapshot()
+            .iter()
+            .any(|event| matches!(event.status, ActionStatus::Failed(ActionError::Timeout(_)))));
+    }
+
+    #[tokio::test]
+    async fn batch_reports_every_outcome() {
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(FlakyAgent {
+            failures_left: AtomicU32::new(0),
+        }));
+        let commander = ActionCommander::builder()
+            .registry(registry)
+            .max_concurrency(1)
+            .build();
+        let unroutable = ActionRequest::builder(
+            ActionDomain::Programming,
+            ActionIntent::Program,
+            ActionPayload::textual("Refactor", "no programming agent registered"),
+        )
+        .priority(ActionPriority::Critical)
+        .build();
+        let batch = commander
+            .submit_batch(vec![network_request(), unroutable, network_request()])
+            .await;
+        assert_eq!(batch.len(), 3);
+
+        let outcomes = batch.await_all().await;
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes[0].is_success());
+        assert_eq!(outcomes[1].status, OutcomeStatus::Failed);
+        assert!(outcomes[1].error.as_deref().unwrap().contains("no agent"));
+        assert!(outcomes[2].is_success());
+        assert_eq!(
+            BatchSummary::from_outcomes(&outcomes),
+            BatchSummary {
+                completed: 2,
+                failed: 1
+            }
+        );
+    }
+
+    struct RecordingAgent {
+        started: Arc<parking_lot::Mutex<Vec<ActionPriority>>>,
+    }
+
+    #[async_trait]
+    impl ActionAgent for RecordingAgent {
+        fn domain(&self) -> ActionDomain {
+            ActionDomain::Network
+        }
+
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn execute(
+            &self,
+            request: ActionRequest,
+            _plan: ActionPlan,
+            _ctx: ExecutionContext,
+        ) -> Result<ActionOutcome, ActionError> {
+            self.started.lock().push(request.priority);
+            tokio::time::sleep(StdDuration::from_millis(5)).await;
+            Ok(ActionOutcome::textual("recorded", Vec::new()))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn batch_slots_are_granted_in_priority_order() {
+        let started = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(RecordingAgent {
+            started: Arc::clone(&started),
+        }));
+        let commander = ActionCommander::builder()
+            .registry(registry)
+            .max_concurrency(1)
+            .build();
+        let requests = [
+            ActionPriority::Low,
+            ActionPriority::Critical,
+            ActionPriority::Normal,
+            ActionPriority::High,
+        ]
+        .map(|priority| {
+            ActionRequest::builder(
+                ActionDomain::Network,
+                ActionIntent::Observe,
+                ActionPayload::textual("Fetch status", "poll the status page"),
+            )
+            .priority(priority)
+            .build()
+        });
+
+        let outcomes = commander
+            .submit_batch(requests.to_vec())
+            .await
+            .await_all()
+            .await;
+        assert!(outcomes.iter().all(ActionOutcome::is_success));
+        assert_eq!(
+            *started.lock(),
+            [
+                ActionPriority::Critical,
+                ActionPriority::High,
+                ActionPriority::Normal,
+                ActionPriority::Low,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelling_while_queued_for_a_slot_rejects_the_request() {
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(SlowAgent));
+        let commander = ActionCommander::builder()
+            .registry(registry)
+            .max_concurrency(1)
+            .build();
+        let running = commander.submit(network_request()).await.unwrap();
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(StdDuration::from_millis(20)).await;
+            canceller.cancel();
+        });
+        let queued = tokio::time::timeout(
+            StdDuration::from_secs(5),
+            commander.submit_cancellable(network_request(), token),
+        )
+        .await
+        .expect("cancellation should release the queued submission");
+        assert!(matches!(queued, Err(ActionError::Cancelled(_))));
+        running.cancel();
+        assert_eq!(running.report().await.status, OutcomeStatus::Cancelled);
+    }
+
+    /// Accepts callbacks, failing the first `failures` with a 503.
+    fn spawn_callback_server(
+        failures: usize,
+    ) -> (String, mpsc::Receiver<(String, serde_json::Value)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/outcomes", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for (idx, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                let mut action_id = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        } else if name.eq_ignore_ascii_case(ACTION_ID_HEADER) {
+                            action_id = value.trim().to_string();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let status = if idx < failures {
+                    "503 Service Unavailable"
+                } else {
+                    "200 OK"
+                };
+                stream
+                    .write_all(
+                        format!(
+                            "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                        )
+                        .as_bytes(),
+                    )
+                    .unwrap();
+                if idx >= failures
+                    && tx
+                        .send((action_id, serde_json::from_slice(&body).unwrap()))
+                        .is_err()
+                {
+                    break;
+                }
+            }
+        });
+        (url, rx)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn callback_receives_outcome_after_completion() {
+        let (url, callbacks) = spawn_callback_server(1);
+        let mut registry = AgentRegistry::default();
+        registry.register(Arc::new(FlakyAgent {
+            failures_left: AtomicU32::new(0),
+        }));
+        let commander = ActionCommander::builder()
+            .registry(registry)
+            .callback(
+                OutcomeCallback::new()
+                    .unwrap()
+                    .allow_host("127.0.0.1")
+                    .retries(3, StdDuration::from_millis(20)),
+            )
+            .build();
+        let request = ActionRequest::builder(
+            ActionDomain::Network,
+            ActionIntent::Observe,
+            ActionPayload::textual("Fetch status", "poll the status page"),
+        )
+        .callback_url(url)
+        .build();
+        let action_id = request.id;
+
+        let outcome = commander
+            .submit(request)
+            .await
+            .unwrap()
+            .outcome()
+            .await
+            .unwrap();
+        assert!(outcome.is_success());
+
+        let (header, delivered) = tokio::task::spawn_blocking(move || {
+            callbacks.recv_timeout(StdDuration::from_secs(5)).unwrap()
+        })
+        .await
+        .unwrap();
+        assert_eq!(header, action_id.to_string());
+        assert_eq!(delivered["summary"], "fetched");
+        assert_eq!(delivered["status"], json!(outcome.status));
+        assert_eq!(delivered["attempts"], 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn callback_redirects_are_not_followed() {
+        let (target, callbacks) = spawn_callback_server(0);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/outcomes", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 && line.trim() != "" {
+                    line.clear();
+                }
+                let _ = stream.write_all(
+                    format!(
+                        "HTTP/1.1 307 Temporary Redirect\r\nlocation: {target}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    )
+                    .as_bytes(),
+                );
+            }
+        });
+        let callback = OutcomeCallback::new().unwrap().allow_host("127.0.0.1");
+        let outcome = ActionOutcome::textual("fetched", Vec::new());
+
+        let err = callback
+            .deliver(&url, ActionId::new_v4(), &outcome)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CallbackError::Status(status) if status.is_redirection()));
+        let delivered = tokio::task::spawn_blocking(move || {
+            callbacks.recv_timeout(StdDuration::from_millis(300))
+        })
+        .await
+        .unwrap();
+        assert!(delivered.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn security_denied_request_skips_callback() {
+        let (url, callbacks) = spawn_callback_server(0);
+        let commander = ActionCommander::builder()
+            .callback(OutcomeCallback::new().unwrap().allow_host("127.0.0.1"))
+            .build();
+        let request = ActionRequest::builder(
+            ActionDomain::Security,
+            ActionIntent::Execute,
+            ActionPayload::textual("Exploit dev", "zero day exploit kit"),
+        )
+        .callback_url(url)
+        .build();
+
+        let err = commander.submit(request).await.err().unwrap();
+        assert!(matches!(err, ActionError::SecurityViolation { .. }));
+        let delivered = tokio::task::spawn_blocking(move || {
+            callbacks.recv_timeout(StdDuration::from_millis(300))
+        })
+        .await
+        .unwrap();
+        assert!(delivered.is_err());
+    }
+
+    #[test]
+    fn callback_urls_must_be_allowlisted_http() {
+        let callback = OutcomeCallback::new()
+            .unwrap()
+            .allow_host("hooks.example.com");
+        assert!(callback
+            .permits("https://HOOKS.example.com:8443/outcomes")
+            .is_ok());
+        for url in [
+            "ftp://hooks.example.com/outcomes",
+            "http://169.254.169.254/latest",
+            "not a url",
+        ] {
+            assert!(matches!(
+                callback.permits(url),
+                Err(CallbackError::Disallowed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = RetryPolicy::exponential(4, StdDuration::from_millis(10));
+        assert_eq!(policy.backoff(1), StdDuration::from_millis(10));
+        assert_eq!(policy.backoff(3), StdDuration::from_millis(40));
+        assert_eq!(RetryPolicy::none().max_attempts, 1);
+    }
+
+    #[test]
+    fn backoff_saturates_at_the_cap() {
+        let policy = RetryPolicy::exponential(u32::MAX, StdDuration::from_secs(1));
+        assert_eq!(policy.backoff(u32::MAX), DEFAULT_MAX_BACKOFF);
+        let capped = policy.with_max_backoff(StdDuration::from_secs(5));
+        assert_eq!(capped.backoff(3), StdDuration::from_secs(4));
+        assert_eq!(capped.backoff(4), StdDuration::from_secs(5));
+        assert_eq!(RetryPolicy::none().backoff(u32::MAX), StdDuration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn report_folds_failures_into_outcome() {
+        let request = ActionRequest::builder(
+            ActionDomain::Programming,
+            ActionIntent::Program,
+            ActionPayload::textual("Refactor", "no context attached"),
+        )
+        .build();
+
+        let commander = ActionCommander::builder().build();
+        let outcome = commander.submit(request).await.unwrap().report().await;
+        assert_eq!(outcome.status, OutcomeStatus::Failed);
+        assert!(outcome.error.unwrap().contains("code_context"));
+        assert!(outcome.attachments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn audit_chain_detects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let commander = ActionCommander::builder()
+            .audit(AuditSink::open(&path).unwrap())
+            .build();
+        for _ in 0..2 {
+            let request = ActionRequest::builder(
+                ActionDomain::Programming,
+                ActionIntent::Program,
+                ActionPayload::textual("Refactor", "tidy module"),
+            )
+            .build();
+            commander.submit(request).await.unwrap().report().await;
+        }
+
+        assert_eq!(AuditSink::verify(&path).unwrap(), 4);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let kinds: Vec<AuditKind> = contents
+            .lines()
+            .map(|line| serde_json::from_str::<AuditRecord>(line).unwrap().kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                AuditKind::Request,
+                AuditKind::Outcome,
+                AuditKind::Request,
+                AuditKind::Outcome
+            ]
+        );
+
+        let tampered = contents.replacen("tidy module", "drop database", 1);
+        std::fs::write(&path, tampered).unwrap();
+        assert!(matches!(
+            AuditSink::verify(&path),
+            Err(AuditError::Tampered { seq: 0 })
+        ));
     }
 }
@@ -1,4 +1,4 @@
-use std::{fmt, sync::Arc};
+use std::{fmt, sync::Arc, time::Duration as StdDuration};
 
 use chrono::{DateTime, Duration, Utc};
 use indexmap::{IndexMap, IndexSet};
@@ -294,6 +294,12 @@ pub struct ActionRequest {
     pub requester: Option<String>,
     /// Correlation identifier for cross-system tracking.
     pub correlation_id: String,
+    /// Wall-clock budget for execution, including retries; `None` waits indefinitely.
+    #[serde(default)]
+    pub timeout: Option<StdDuration>,
+    /// Endpoint that receives the final [`ActionOutcome`] as a JSON POST.
+    #[serde(default)]
+    pub callback_url: Option<String>,
 }
 
 impl ActionRequest {
@@ -316,6 +322,8 @@ impl ActionRequest {
                 constraints: ActionConstraints::default(),
                 requester: None,
                 correlation_id: Self::generate_correlation_id(),
+                timeout: None,
+                callback_url: None,
             },
         }
     }
@@ -371,6 +379,20 @@ impl ActionRequestBuilder {
         self
     }
 
+    /// Aborts execution once `timeout` has elapsed, yielding a timed-out outcome.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: StdDuration) -> Self {
+        self.request.timeout = Some(timeout);
+        self
+    }
+
+    /// POSTs the final outcome to `url` once the action finishes.
+    #[must_use]
+    pub fn callback_url(mut self, url: impl Into<String>) -> Self {
+        self.request.callback_url = Some(url.into());
+        self
+    }
+
     /// Consumes the builder returning the finalized request.
     #[must_use]
     pub fn build(self) -> ActionRequest {
@@ -501,6 +523,18 @@ impl ActionStatus {
             Self::Completed(_) | Self::Failed(_) | Self::Cancelled(_) | Self::Rejected(_)
         )
     }
+
+    /// Terminal outcome status for this lifecycle state, `None` while still in flight.
+    #[must_use]
+    pub const fn outcome_status(&self) -> Option<OutcomeStatus> {
+        match self {
+            Self::Completed(outcome) => Some(outcome.status),
+            Self::Failed(error) => Some(OutcomeStatus::from_error(error)),
+            Self::Cancelled(_) => Some(OutcomeStatus::Cancelled),
+            Self::Rejected(_) => Some(OutcomeStatus::Failed),
+            Self::Pending(_) | Self::Validating | Self::Planned(_) | Self::Executing(_) => None,
+        }
+    }
 }
 
 /// Timeline information captured while executing.
@@ -529,13 +563,60 @@ impl ExecutionWindow {
     }
 }
 
-/// Successful outcome of an action.
+/// Terminal state recorded on an [`ActionOutcome`].
+///
+/// [`ActionStatus`] tracks the whole lifecycle and carries the outcome itself in
+/// [`ActionStatus::Completed`], so the outcome cannot embed it without becoming
+/// recursive. This is the terminal projection of that lifecycle; see
+/// [`ActionStatus::outcome_status`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeStatus {
+    /// The agent finished the plan.
+    #[default]
+    Succeeded,
+    /// The action failed before or during execution.
+    Failed,
+    /// The action was cancelled before it finished.
+    Cancelled,
+    /// The action exceeded its timeout.
+    TimedOut,
+}
+
+impl OutcomeStatus {
+    /// Status of an action that ended with `error`.
+    #[must_use]
+    pub const fn from_error(error: &ActionError) -> Self {
+        match error {
+            ActionError::Cancelled(_) => Self::Cancelled,
+            ActionError::Timeout(_) => Self::TimedOut,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// Outcome of an action, successful or not.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionOutcome {
+    /// Terminal status of the action.
+    #[serde(default)]
+    pub status: OutcomeStatus,
     /// Narrative summary of the result.
     pub summary: String,
     /// Produced artifacts.
     pub artifacts: Vec<ActionArtifact>,
+    /// Typed results callers can inspect (diffs, reports, datasets).
+    #[serde(default)]
+    pub attachments: Vec<PayloadAttachment>,
+    /// Error detail when the action failed.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Wall-clock time spent executing the plan.
+    #[serde(default)]
+    pub duration: Duration,
+    /// Number of execution attempts, including retries.
+    #[serde(default)]
+    pub attempts: u32,
     /// Follow-up recommendations.
     pub follow_up: Vec<String>,
     /// Execution metrics.
@@ -547,12 +628,48 @@ impl ActionOutcome {
     #[must_use]
     pub fn textual(summary: impl Into<String>, artifacts: Vec<ActionArtifact>) -> Self {
         Self {
+            status: OutcomeStatus::Succeeded,
             summary: summary.into(),
             artifacts,
+            attachments: Vec::new(),
+            error: None,
+            duration: Duration::zero(),
+            attempts: 1,
             follow_up: Vec::new(),
             metrics: ExecutionMetrics::default(),
         }
     }
+
+    /// Builds a failed outcome from an error; cancellation and timeout errors yield
+    /// cancelled and timed-out outcomes respectively.
+    #[must_use]
+    pub fn failed(error: &ActionError, duration: Duration) -> Self {
+        Self {
+            status: OutcomeStatus::from_error(error),
+            error: Some(error.to_string()),
+            duration,
+            ..Self::textual(format!("Action failed: {error}"), Vec::new())
+        }
+    }
+
+    /// Attaches a typed result.
+    #[must_use]
+    pub fn with_attachment(mut self, attachment: PayloadAttachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Returns the first attachment carrying `label`.
+    #[must_use]
+    pub fn attachment(&self, label: &str) -> Option<&PayloadAttachment> {
+        self.attachments.iter().find(|att| att.label == label)
+    }
+
+    /// Whether the action succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.status == OutcomeStatus::Succeeded
+    }
 }
 
 /// Artifact generated by actions (report, code, dataset, etc.).
@@ -627,6 +744,20 @@ pub enum ActionError {
     /// Internal infrastructure failure.
     #[error("infrastructure: {0}")]
     Infrastructure(String),
+    /// Execution was cancelled through the action's cancellation token.
+    #[error("cancelled: {0}")]
+    Cancelled(String),
+}
+
+impl ActionError {
+    /// Whether retrying the action could plausibly succeed.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::Execution(_) | Self::Timeout(_) | Self::Infrastructure(_)
+        )
+    }
 }
 
 /// Structured validation issue.
@@ -701,4 +832,22 @@ mod tests {
         assert!(request.correlation_id.len() >= 8);
         assert_eq!(request.domain, ActionDomain::Programming);
     }
+
+    #[test]
+    fn lifecycle_states_project_onto_outcome_status() {
+        let timeout = ActionError::Timeout(Duration::seconds(5));
+        assert_eq!(
+            ActionStatus::Failed(timeout).outcome_status(),
+            Some(OutcomeStatus::TimedOut)
+        );
+        assert_eq!(
+            ActionStatus::Completed(ActionOutcome::textual("done", Vec::new())).outcome_status(),
+            Some(OutcomeStatus::Succeeded)
+        );
+        assert_eq!(
+            ActionStatus::Rejected(Vec::new()).outcome_status(),
+            Some(OutcomeStatus::Failed)
+        );
+        assert_eq!(ActionStatus::Validating.outcome_status(), None);
+    }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::Utc;
 use indexmap::IndexMap;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     actions::{
@@ -40,6 +41,21 @@ pub struct ExecutionContext {
     pub journal: ActionJournal,
     /// Security grade enforced for downstream operations.
     pub security_grade: SecurityGrade,
+    /// Cancelled when the caller aborts the action.
+    pub cancellation: CancellationToken,
+}
+
+impl ExecutionContext {
+    /// Cancellation point; agents call this between steps.
+    ///
+    /// # Errors
+    /// Returns [`ActionError::Cancelled`] once the caller has cancelled the action.
+    pub fn checkpoint(&self) -> Result<(), ActionError> {
+        if self.cancellation.is_cancelled() {
+            return Err(ActionError::Cancelled("cancelled by caller".into()));
+        }
+        Ok(())
+    }
 }
 
 /// Registry that keeps track of available action agents.
@@ -128,6 +144,7 @@ impl ActionAgent for InternetOpsAgent {
             status: ActionStatus::Executing(ExecutionWindow::start()),
             note: Some("Internet agent executing".into()),
         });
+        ctx.checkpoint()?;
         self.executor.execute_plan(&request, &plan).await
     }
 }
@@ -159,6 +176,7 @@ impl ActionAgent for OfflineOpsAgent {
             status: ActionStatus::Executing(ExecutionWindow::start()),
             note: Some("Offline agent executing".into()),
         });
+        ctx.checkpoint()?;
         self.executor
             .execute_plan(&request, &plan, Vec::new())
             .await
@@ -192,6 +210,7 @@ impl ActionAgent for ProgrammingOpsAgent {
             status: ActionStatus::Executing(ExecutionWindow::start()),
             note: Some("Programming agent executing".into()),
         });
+        ctx.checkpoint()?;
         self.executor.execute_plan(&request, &plan).await
     }
 }
@@ -223,6 +242,7 @@ impl ActionAgent for SelfTrainingOpsAgent {
             status: ActionStatus::Executing(ExecutionWindow::start()),
             note: Some("Self-training agent executing".into()),
         });
+        ctx.checkpoint()?;
         self.executor.execute_plan(&request, &plan).await
     }
 }
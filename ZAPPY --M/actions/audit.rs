@@ -0,0 +1,210 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::actions::ActionId;
+
+/// Hash used as the predecessor of the first record in a chain.
+pub const GENESIS_HASH: &str = "genesis";
+
+/// Lifecycle stage captured by an audit record.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditKind {
+    /// Request accepted by the commander.
+    Request,
+    /// Final outcome of the action.
+    Outcome,
+}
+
+/// Single hash-chained entry in the audit log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditRecord {
+    /// Position in the chain, starting at 0.
+    pub seq: u64,
+    /// Action the record belongs to.
+    pub action_id: ActionId,
+    /// Lifecycle stage.
+    pub kind: AuditKind,
+    /// Serialized request or outcome.
+    pub payload: Value,
+    /// Time the record was appended.
+    pub recorded_at: DateTime<Utc>,
+    /// Hash of the previous record.
+    pub prev_hash: String,
+    /// SHA-256 over this record's fields and `prev_hash`.
+    pub hash: String,
+}
+
+#[derive(Serialize)]
+struct UnsignedRecord<'a> {
+    seq: u64,
+    action_id: ActionId,
+    kind: AuditKind,
+    payload: &'a Value,
+    recorded_at: DateTime<Utc>,
+    prev_hash: &'a str,
+}
+
+impl AuditRecord {
+    /// Recomputes the hash from the record contents.
+    ///
+    /// # Errors
+    /// Returns [`AuditError::Json`] if the record cannot be serialised.
+    pub fn compute_hash(&self) -> Result<String, AuditError> {
+        let unsigned = UnsignedRecord {
+            seq: self.seq,
+            action_id: self.action_id,
+            kind: self.kind,
+            payload: &self.payload,
+            recorded_at: self.recorded_at,
+            prev_hash: &self.prev_hash,
+        };
+        let digest = Sha256::digest(serde_json::to_vec(&unsigned)?);
+        Ok(format!("{digest:x}"))
+    }
+}
+
+/// Errors raised while writing or verifying the audit log.
+#[derive(Debug, Error)]
+pub enum AuditError {
+    /// I/O error (filesystem).
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// JSON encoding or parsing failure.
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    /// A record does not match its hash or does not link to its predecessor.
+    #[error("audit chain broken at record {seq}")]
+    Tampered {
+        /// Sequence number of the first invalid record.
+        seq: u64,
+    },
+}
+
+#[derive(Debug)]
+struct ChainHead {
+    next_seq: u64,
+    last_hash: String,
+}
+
+/// Append-only JSONL audit log where each record links to the previous one by hash.
+#[derive(Debug)]
+pub struct AuditSink {
+    path: PathBuf,
+    head: Mutex<ChainHead>,
+}
+
+impl AuditSink {
+    /// Opens (or creates) the log at `path`, resuming the chain from its last record.
+    ///
+    /// # Errors
+    /// Returns [`AuditError::Io`] if the log directory or file cannot be read, and
+    /// [`AuditError::Json`] if an existing record is malformed.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AuditError> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let last = if path.exists() {
+            read_records(&path)?.pop()
+        } else {
+            None
+        };
+        let head = last.map_or_else(
+            || ChainHead {
+                next_seq: 0,
+                last_hash: GENESIS_HASH.to_string(),
+            },
+            |record| ChainHead {
+                next_seq: record.seq + 1,
+                last_hash: record.hash,
+            },
+        );
+        Ok(Self {
+            path,
+            head: Mutex::new(head),
+        })
+    }
+
+    /// Path of the underlying log file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends a record for `action_id` and advances the chain.
+    ///
+    /// # Errors
+    /// Returns [`AuditError::Json`] if the record cannot be serialised and
+    /// [`AuditError::Io`] if it cannot be appended; the chain does not advance on failure.
+    pub fn append(
+        &self,
+        action_id: ActionId,
+        kind: AuditKind,
+        payload: Value,
+    ) -> Result<AuditRecord, AuditError> {
+        let mut head = self.head.lock();
+        let mut record = AuditRecord {
+            seq: head.next_seq,
+            action_id,
+            kind,
+            payload,
+            recorded_at: Utc::now(),
+            prev_hash: head.last_hash.clone(),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash()?;
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(&line)?;
+        head.next_seq += 1;
+        head.last_hash.clone_from(&record.hash);
+        drop(head);
+        Ok(record)
+    }
+
+    /// Validates every record in the log, returning how many were checked.
+    ///
+    /// # Errors
+    /// Returns [`AuditError::Tampered`] for the first record whose sequence, link, or hash
+    /// is wrong, and [`AuditError::Io`] or [`AuditError::Json`] if the log cannot be read.
+    pub fn verify(path: impl AsRef<Path>) -> Result<usize, AuditError> {
+        let records = read_records(path.as_ref())?;
+        let mut prev_hash = GENESIS_HASH.to_string();
+        for (expected_seq, record) in (0_u64..).zip(&records) {
+            if record.seq != expected_seq
+                || record.prev_hash != prev_hash
+                || record.compute_hash()? != record.hash
+            {
+                return Err(AuditError::Tampered { seq: expected_seq });
+            }
+            prev_hash.clone_from(&record.hash);
+        }
+        Ok(records.len())
+    }
+}
+
+fn read_records(path: &Path) -> Result<Vec<AuditRecord>, AuditError> {
+    let mut records = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            records.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(records)
+}
@@ -0,0 +1,183 @@
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use reqwest::{Client, StatusCode, Url};
+use serde_json::json;
+use shared_logging::LogLevel;
+use thiserror::Error;
+
+use crate::{
+    actions::{ActionId, ActionOutcome},
+    telemetry::ActionTelemetry,
+};
+
+/// Header carrying the action identifier on callback requests.
+pub const ACTION_ID_HEADER: &str = "x-zappy-action-id";
+
+/// Failure to deliver an outcome to its callback URL.
+#[derive(Debug, Error)]
+pub enum CallbackError {
+    /// The endpoint answered with a non-success status.
+    #[error("callback returned {0}")]
+    Status(StatusCode),
+    /// The request could not be sent.
+    #[error("callback transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+    /// The URL is malformed, not http(s), or its host is not allowlisted.
+    #[error("callback url not permitted: {0}")]
+    Disallowed(String),
+}
+
+/// POSTs final outcomes to the callback URL attached to a request.
+///
+/// Only http(s) URLs whose host was registered through [`OutcomeCallback::allow_host`]
+/// are ever called.
+#[derive(Debug, Clone)]
+pub struct OutcomeCallback {
+    client: Client,
+    max_attempts: u32,
+    backoff: StdDuration,
+    allowed_hosts: Vec<String>,
+}
+
+impl OutcomeCallback {
+    /// Creates a deliverer making 3 attempts, starting with a 200ms backoff.
+    ///
+    /// The allowlist starts empty, so no URL is permitted until a host is allowed.
+    ///
+    /// # Errors
+    /// Returns [`CallbackError::Transport`] when the HTTP client cannot be initialised.
+    pub fn new() -> Result<Self, CallbackError> {
+        let client = Client::builder()
+            .user_agent("zappy-actions/alpha")
+            .timeout(StdDuration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        Ok(Self {
+            client,
+            max_attempts: 3,
+            backoff: StdDuration::from_millis(200),
+            allowed_hosts: Vec::new(),
+        })
+    }
+
+    /// Permits callbacks to `host` (matched case-insensitively, on any port).
+    #[must_use]
+    pub fn allow_host(mut self, host: impl Into<String>) -> Self {
+        self.allowed_hosts.push(host.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Parses `url`, accepting only http(s) URLs whose host is allowlisted.
+    ///
+    /// # Errors
+    /// Returns [`CallbackError::Disallowed`] for any other URL.
+    pub fn permits(&self, url: &str) -> Result<Url, CallbackError> {
+        let parsed =
+            Url::parse(url).map_err(|err| CallbackError::Disallowed(format!("{url}: {err}")))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(CallbackError::Disallowed(format!(
+                "{url}: scheme must be http or https"
+            )));
+        }
+        let allowed = parsed
+            .host_str()
+            .is_some_and(|host| self.allowed_hosts.contains(&host.to_ascii_lowercase()));
+        if !allowed {
+            return Err(CallbackError::Disallowed(format!(
+                "{url}: host is not allowlisted"
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// Retries failed deliveries up to `max_attempts`, doubling `backoff` each time.
+    #[must_use]
+    pub fn retries(mut self, max_attempts: u32, backoff: StdDuration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.backoff = backoff;
+        self
+    }
+
+    /// POSTs `outcome` as JSON, retrying non-success responses and transport errors.
+    ///
+    /// Redirects are never followed, so the allowlist check on `url` covers every host
+    /// the outcome is sent to.
+    ///
+    /// # Errors
+    /// Returns [`CallbackError::Disallowed`] when `url` fails [`Self::permits`], and
+    /// [`CallbackError::Status`] (including 3xx redirects) or [`CallbackError::Transport`]
+    /// when the final attempt does not succeed.
+    pub async fn deliver(
+        &self,
+        url: &str,
+        action_id: ActionId,
+        outcome: &ActionOutcome,
+    ) -> Result<u32, CallbackError> {
+        let url = self.permits(url)?;
+        let mut delay = self.backoff;
+        let mut attempt = 1;
+        loop {
+            let error = match self
+                .client
+                .post(url.clone())
+                .header(ACTION_ID_HEADER, action_id.to_string())
+                .json(outcome)
+                .send()
+                .await
+            {
+                Ok(response) if response.status().is_success() => return Ok(attempt),
+                Ok(response) => CallbackError::Status(response.status()),
+                Err(err) => err.into(),
+            };
+            if attempt >= self.max_attempts {
+                return Err(error);
+            }
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2);
+            attempt += 1;
+        }
+    }
+
+    /// Delivers in the background so the submitter never waits on the endpoint.
+    pub(crate) fn dispatch(
+        self: &Arc<Self>,
+        url: String,
+        action_id: ActionId,
+        outcome: ActionOutcome,
+        telemetry: Option<ActionTelemetry>,
+    ) {
+        if let Err(err) = self.permits(&url) {
+            if let Some(tel) = &telemetry {
+                let _ = tel.log(
+                    LogLevel::Warn,
+                    "actions.callback.rejected",
+                    json!({ "action_id": action_id, "url": url, "error": err.to_string() }),
+                );
+            }
+            return;
+        }
+        let callback = Arc::clone(self);
+        tokio::spawn(async move {
+            let result = callback.deliver(&url, action_id, &outcome).await;
+            let Some(tel) = &telemetry else {
+                return;
+            };
+            match result {
+                Ok(attempts) => {
+                    let _ = tel.log(
+                        LogLevel::Debug,
+                        "actions.callback.delivered",
+                        json!({ "action_id": action_id, "url": url, "attempts": attempts }),
+                    );
+                }
+                Err(err) => {
+                    let _ = tel.log(
+                        LogLevel::Warn,
+                        "actions.callback.failed",
+                        json!({ "action_id": action_id, "url": url, "error": err.to_string() }),
+                    );
+                }
+            }
+        });
+    }
+}
@@ -11,6 +11,7 @@ use tokio::{io::AsyncWriteExt, process::Command, time::timeout};
 use crate::{
     actions::{
         ActionArtifact, ActionError, ActionOutcome, ActionPlan, ActionRequest, ArtifactContent,
+        PayloadAttachment,
     },
     programminghelper::{CodeChangeProposal, ProgrammingHelper},
 };
@@ -75,7 +76,8 @@ impl ProgrammingActionExecutor {
 
         let (command_reports, command_stats) = self.run_commands(commands).await;
 
-        let summary = if command_stats.total > 0 {
+        let invalid = patches.iter().filter(|p| !p.is_valid()).count();
+        let mut summary = if command_stats.total > 0 {
             format!(
                 "Prepared {} patches across {} plan steps; executed {} commands ({} success, {} failed, {} timeout, {} rejected)",
                 patches.len(),
@@ -93,16 +95,41 @@ impl ProgrammingActionExecutor {
                 plan.steps.len()
             )
         };
+        if invalid > 0 {
+            summary = format!("{summary}; {invalid} rejected for syntax errors");
+        }
+
+        let diffs = patches
+            .iter()
+            .filter(|p| p.is_valid())
+            .map(|p| PayloadAttachment {
+                label: "proposed_diff".into(),
+                content_type: "text/x-diff".into(),
+                content: serde_json::json!({
+                    "path": p.path,
+                    "diff": p.diff,
+                }),
+            })
+            .collect::<Vec<_>>();
 
         let mut artifacts = vec![ActionArtifact {
             label: "programming_patches".into(),
             importance: request.priority,
             content: ArtifactContent::Json(serde_json::json!(patches
-                .into_iter()
-                .map(|p| serde_json::json!({
-                    "path": p.path,
-                    "diff": p.diff,
-                }))
+                .iter()
+                .map(|p| if p.is_valid() {
+                    serde_json::json!({
+                        "path": p.path,
+                        "valid": true,
+                        "diff": p.diff,
+                    })
+                } else {
+                    serde_json::json!({
+                        "path": p.path,
+                        "valid": false,
+                        "diagnostics": p.diagnostics,
+                    })
+                })
                 .collect::<Vec<_>>())),
         }];
 
@@ -114,7 +141,15 @@ impl ProgrammingActionExecutor {
             });
         }
 
-        Ok(ActionOutcome::textual(summary, artifacts))
+        let mut outcome = ActionOutcome::textual(summary, artifacts);
+        outcome.attachments = diffs;
+        outcome.follow_up.extend(
+            patches
+                .iter()
+                .filter(|p| !p.is_valid())
+                .map(|p| format!("fix syntax errors in {}", p.path.display())),
+        );
+        Ok(outcome)
     }
 
     fn extract_proposals(
@@ -150,6 +185,7 @@ impl ProgrammingActionExecutor {
                 path: path.into(),
                 original,
                 proposed,
+                content_type: attachment.content_type.clone(),
             });
         }
 
@@ -435,3 +471,54 @@ fn is_command_allowed(program: &str) -> bool {
         .to_ascii_lowercase();
     SAFE_COMMANDS.iter().any(|allowed| *allowed == lowered)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{ActionDomain, ActionIntent, ActionPayload};
+
+    fn code_context(path: &str, content_type: &str, proposed: &str) -> PayloadAttachment {
+        PayloadAttachment {
+            label: "code_context".into(),
+            content_type: content_type.into(),
+            content: serde_json::json!({ "path": path, "original": "", "proposed": proposed }),
+        }
+    }
+
+    #[tokio::test]
+    async fn unparsable_rust_patch_is_flagged_invalid() {
+        let mut payload = ActionPayload::textual("patch", "fix the parser");
+        payload.attachments = vec![
+            code_context("src/ok.rs", "text/x-rust", "fn ok() -> u8 { 1 }\n"),
+            code_context("src/broken.rs", "text/x-rust", "fn broken( {\n"),
+            code_context("script.py", "text/x-python", "def broken(:\n"),
+        ];
+        let request =
+            ActionRequest::builder(ActionDomain::Programming, ActionIntent::Program, payload)
+                .build();
+        let executor = ProgrammingActionExecutor::new(ProgrammingHelper::new(100));
+
+        let outcome = executor
+            .execute_plan(&request, &ActionPlan::new("patch", Vec::new()))
+            .await
+            .unwrap();
+
+        assert!(outcome.summary.contains("1 rejected for syntax errors"));
+        let ArtifactContent::Json(patches) = &outcome.artifacts[0].content else {
+            panic!("expected json patches");
+        };
+        assert_eq!(patches[0]["valid"], true);
+        assert_eq!(patches[1]["valid"], false);
+        assert!(patches[1].get("diff").is_none());
+        assert!(patches[1]["diagnostics"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("src/broken.rs"));
+        assert_eq!(patches[2]["valid"], true);
+        assert_eq!(outcome.attachments.len(), 2);
+        assert_eq!(
+            outcome.follow_up,
+            vec!["fix syntax errors in src/broken.rs"]
+        );
+    }
+}
@@ -13,6 +13,17 @@ pub struct CodeChangeProposal {
     pub original: String,
     /// Proposed contents.
     pub proposed: String,
+    /// MIME-style hint for the contents (e.g. `text/x-rust`).
+    pub content_type: String,
+}
+
+impl CodeChangeProposal {
+    /// Whether the proposal holds Rust source, by content type or file extension.
+    #[must_use]
+    pub fn is_rust(&self) -> bool {
+        self.content_type.to_ascii_lowercase().contains("rust")
+            || self.path.extension().is_some_and(|ext| ext == "rs")
+    }
 }
 
 /// Normalized patch ready for review.
@@ -22,6 +33,16 @@ pub struct PatchPreview {
     pub path: PathBuf,
     /// Unified diff string.
     pub diff: String,
+    /// Syntax errors found in the proposed contents; empty when the patch is valid.
+    pub diagnostics: Vec<String>,
+}
+
+impl PatchPreview {
+    /// Whether the proposed contents passed the syntax check.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
 }
 
 /// Helper that generates diffs and enforces guardrails.
@@ -37,7 +58,24 @@ impl ProgrammingHelper {
         Self { max_diff_lines }
     }
 
-    /// Generates a unified diff for the given proposal.
+    /// Parses Rust proposals with `syn`, returning one diagnostic per syntax error.
+    ///
+    /// Other content types are not checked.
+    #[must_use]
+    pub fn check_syntax(&self, proposal: &CodeChangeProposal) -> Vec<String> {
+        if !proposal.is_rust() {
+            return Vec::new();
+        }
+        match syn::parse_file(&proposal.proposed) {
+            Ok(_) => Vec::new(),
+            Err(err) => err
+                .into_iter()
+                .map(|err| format!("{}: {err}", proposal.path.display()))
+                .collect(),
+        }
+    }
+
+    /// Generates a unified diff for the given proposal, flagging proposals that do not parse.
     pub fn generate_patch(
         &self,
         proposal: &CodeChangeProposal,
@@ -68,6 +106,7 @@ impl ProgrammingHelper {
         Ok(PatchPreview {
             path: proposal.path.clone(),
             diff: diff_body,
+            diagnostics: self.check_syntax(proposal),
         })
     }
 }
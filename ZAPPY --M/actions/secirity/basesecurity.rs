@@ -12,6 +12,8 @@ pub struct BasePolicyDecision {
     pub required_grade: SecurityGrade,
     /// Human readable notes.
     pub notes: Vec<String>,
+    /// Identifiers of the rules that matched.
+    pub matched_rules: Vec<String>,
 }
 
 impl BasePolicyDecision {
@@ -22,6 +24,7 @@ impl BasePolicyDecision {
             effect: PolicyEffect::Allow,
             required_grade: SecurityGrade::Low,
             notes: Vec::new(),
+            matched_rules: Vec::new(),
         }
     }
 }
@@ -118,6 +121,7 @@ impl SecurityPolicy {
             }
 
             decision.required_grade = decision.required_grade.max(rule.enforced_grade);
+            decision.matched_rules.push(rule.id.to_string());
             decision.notes.push(format!(
                 "{} triggered ({} | safety {:?})",
                 rule.id, rule.description, rule.safety_class
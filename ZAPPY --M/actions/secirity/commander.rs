@@ -1,3 +1,5 @@
+use indexmap::IndexMap;
+
 use crate::{actions::ActionRequest, security_link::SecurityGrade};
 
 use super::{
@@ -14,6 +16,12 @@ pub struct CommanderVerdict {
     pub grade: SecurityGrade,
     /// Notes justifying the verdict.
     pub notes: Vec<String>,
+    /// Policy rules that matched the request.
+    pub matched_rules: Vec<String>,
+    /// Confidence of each heuristic signal, keyed by its reason.
+    pub heuristic_scores: IndexMap<String, f32>,
+    /// One-line explanation of the outcome.
+    pub rationale: String,
 }
 
 /// Aggregates base policy evaluation with advanced heuristics.
@@ -42,20 +50,50 @@ impl SecurityCommander {
         let mut approved = !matches!(base.effect, PolicyEffect::Deny);
         let mut grade = base.required_grade;
         let mut notes = base.notes;
+        let heuristic_scores = signals
+            .iter()
+            .map(|signal| (signal.reason.clone(), signal.confidence))
+            .collect();
+        let mut rationale = if approved {
+            None
+        } else {
+            Some(format!(
+                "denied by policy rule {}",
+                base.matched_rules.last().map_or("unknown", String::as_str)
+            ))
+        };
 
         for signal in signals {
             grade = grade.max(signal.grade);
-            notes.push(signal.reason);
             if signal.grade == SecurityGrade::Maximum && signal.confidence > 0.7 {
                 approved = false;
+                rationale.get_or_insert_with(|| {
+                    format!(
+                        "denied by heuristic '{}' (confidence {:.2})",
+                        signal.reason, signal.confidence
+                    )
+                });
+                notes.push(signal.reason);
                 break;
             }
+            notes.push(signal.reason);
         }
 
+        let rationale = rationale.unwrap_or_else(|| match base.effect {
+            PolicyEffect::Escalate => format!(
+                "allowed with escalation to {grade:?} by {}",
+                base.matched_rules.join(", ")
+            ),
+            _ => format!("allowed at {grade:?} grade"),
+        });
+
         CommanderVerdict {
             approved,
             grade,
             notes,
+            matched_rules: base.matched_rules,
+            heuristic_scores,
+            rationale,
         }
     }
 }
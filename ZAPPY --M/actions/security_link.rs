@@ -1,5 +1,6 @@
 use std::{collections::VecDeque, sync::Arc};
 
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
@@ -45,6 +46,22 @@ pub struct SecurityVerdict {
     pub grade: SecurityGrade,
     /// Detailed justification.
     pub notes: Vec<String>,
+    /// Structured explanation of the decision.
+    #[serde(default)]
+    pub decision: SecurityDecision,
+}
+
+/// Explainable breakdown of why a request was allowed or denied.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecurityDecision {
+    /// Whether the request may proceed.
+    pub allowed: bool,
+    /// Identifiers of the policy rules that matched.
+    pub matched_rules: Vec<String>,
+    /// Confidence of each heuristic that fired, keyed by its reason.
+    pub heuristic_scores: IndexMap<String, f32>,
+    /// Human readable summary of the deciding factor.
+    pub rationale: String,
 }
 
 /// Config builder for [`SecurityLink`].
@@ -144,6 +161,12 @@ impl SecurityLink {
             approved: verdict.approved,
             grade: verdict.grade,
             notes: verdict.notes,
+            decision: SecurityDecision {
+                allowed: verdict.approved,
+                matched_rules: verdict.matched_rules,
+                heuristic_scores: verdict.heuristic_scores,
+                rationale: verdict.rationale,
+            },
         }
     }
 
@@ -179,4 +202,28 @@ mod tests {
         let verdict = link.evaluate(&request).await;
         assert!(!verdict.approved);
     }
+
+    #[tokio::test]
+    async fn decision_explains_matched_rule() {
+        let payload = ActionPayload::textual("Exploit dev", "zero day exploit kit");
+        let request =
+            ActionRequest::builder(ActionDomain::Security, ActionIntent::Execute, payload).build();
+        let decision = SecurityLink::builder()
+            .build()
+            .evaluate(&request)
+            .await
+            .decision;
+
+        assert!(!decision.allowed);
+        assert_eq!(decision.matched_rules, vec!["deny-external-ops"]);
+        assert_eq!(
+            decision
+                .heuristic_scores
+                .get("Potential exploit development"),
+            Some(&0.85)
+        );
+        assert!(decision
+            .rationale
+            .contains("heuristic 'Potential exploit development'"));
+    }
 }
@@ -12,6 +12,14 @@
 #[path = "../actions.rs"]
 pub mod actions;
 
+/// Hash-chained audit trail of requests and outcomes.
+#[path = "../audit.rs"]
+pub mod audit;
+
+/// Webhook delivery of final outcomes.
+#[path = "../callback.rs"]
+pub mod callback;
+
 /// Domain-aware execution agents and registry.
 #[path = "../agents.rs"]
 pub mod agents;
@@ -70,13 +78,17 @@ pub mod telemetry;
 
 /// Prelude exports for consumers that interact with the action fabric.
 pub mod prelude {
-    pub use crate::actioncommander::{ActionCommander, ActionCommanderBuilder};
+    pub use crate::actioncommander::{
+        ActionCommander, ActionCommanderBuilder, BatchHandle, BatchSummary, RetryPolicy,
+    };
     pub use crate::actions::{
-        ActionDomain, ActionId, ActionIntent, ActionPayload, ActionPriority, ActionRequest,
-        ActionStatus,
+        ActionDomain, ActionId, ActionIntent, ActionOutcome, ActionPayload, ActionPriority,
+        ActionRequest, ActionStatus, OutcomeStatus, PayloadAttachment,
     };
     pub use crate::agents::{ActionAgent, AgentRegistry};
+    pub use crate::audit::{AuditKind, AuditRecord, AuditSink};
+    pub use crate::callback::OutcomeCallback;
     pub use crate::commandgeneration::{CommandGenerator, HeuristicCommandGenerator};
-    pub use crate::security_link::{SecurityLink, SecurityLinkBuilder};
+    pub use crate::security_link::{SecurityDecision, SecurityLink, SecurityLinkBuilder};
     pub use crate::telemetry::{ActionTelemetry, ActionTelemetryBuilder};
 }
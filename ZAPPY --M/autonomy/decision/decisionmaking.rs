@@ -124,11 +124,11 @@ impl Default for DecisionEngine {
     }
 }
 
-/// Utility for constructing inputs from the current registry.
+/// Utility for constructing inputs from the registry modules that handle the signal's scope.
 pub fn build_input(signal: AutonomySignal, registry: &ModuleRegistry) -> DecisionInput {
     DecisionInput {
+        registry_snapshot: registry.in_scope(&signal.scope),
         signal,
-        registry_snapshot: registry.snapshot(),
         context: DecisionContext::default(),
     }
 }
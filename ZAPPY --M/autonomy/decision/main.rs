@@ -10,6 +10,7 @@ pub mod reviewerno2;
 use std::{fmt, sync::Arc};
 
 use decisionmaking::{build_input, DecisionEngine, DecisionHypothesis, DecisionInput};
+use indexmap::IndexMap;
 use reviewer::{DecisionReviewer, GovernanceReviewer, ReviewFinding};
 use reviewerno2::ContinuityReviewer;
 use serde::{Deserialize, Serialize};
@@ -30,10 +31,21 @@ pub struct DecisionVerdict {
     pub directives: Vec<crate::module::ControlDirective>,
     /// Individual reviewer notes.
     pub findings: Vec<ReviewFinding>,
-    /// Confidence adjusted by reviewer outcomes.
+    /// Confidence deducted per reviewer.
+    #[serde(default)]
+    pub penalties: IndexMap<String, f32>,
+    /// Draft confidence minus the summed reviewer penalties.
     pub confidence: f32,
 }
 
+impl DecisionVerdict {
+    /// Sum of every reviewer penalty.
+    #[must_use]
+    pub fn total_penalty(&self) -> f32 {
+        self.penalties.values().sum()
+    }
+}
+
 /// Directs the decision engine and reviewers.
 #[derive(Clone)]
 pub struct DecisionDirector {
@@ -151,16 +163,17 @@ impl DecisionDirector {
             ));
         }
 
-        let confidence_penalty = findings
-            .iter()
-            .filter(|finding| !finding.notes.contains("acceptable"))
-            .count() as f32
-            * 0.05;
+        let mut penalties: IndexMap<String, f32> = IndexMap::new();
+        for finding in &findings {
+            *penalties.entry(finding.reviewer.clone()).or_default() += finding.penalty.max(0.0);
+        }
+        let confidence_penalty: f32 = penalties.values().sum();
 
         let verdict = DecisionVerdict {
             hypothesis: draft.hypothesis,
             directives: draft.directives,
             findings,
+            penalties,
             confidence: (draft.confidence - confidence_penalty).clamp(0.0, 1.0),
         };
         if let Some(tel) = &self.telemetry {
@@ -170,7 +183,8 @@ impl DecisionDirector {
                 json!({
                     "hypothesis": verdict.hypothesis.summary,
                     "directives": verdict.directives.len(),
-                    "confidence": verdict.confidence
+                    "confidence": verdict.confidence,
+                    "penalties": verdict.penalties
                 }),
             );
             let _ = tel.event(
@@ -206,4 +220,39 @@ mod tests {
         let verdict = director.decide_signal(signal).await.unwrap();
         assert!(verdict.confidence > 0.0);
     }
+
+    struct PenalizingReviewer;
+
+    #[async_trait::async_trait]
+    impl DecisionReviewer for PenalizingReviewer {
+        fn name(&self) -> &'static str {
+            "budget"
+        }
+
+        async fn review(&self, _draft: &decisionmaking::DecisionDraft) -> ReviewFinding {
+            ReviewFinding {
+                reviewer: self.name().into(),
+                passed: true,
+                notes: "within budget, acceptable".into(),
+                penalty: 0.1,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn confidence_deducts_explicit_reviewer_penalties() {
+        let registry = ModuleRegistry::default();
+        registry.upsert(ModuleSpec::new("planner", ModuleKind::Planner));
+        let signal = AutonomySignal::new(SignalScope::Global, "steady").with_metric("load", 0.3);
+        let input = build_input(signal, &registry);
+        let draft = DecisionEngine::default().evaluate(&input).unwrap();
+        let director = DecisionDirector::new(registry).with_reviewer(Arc::new(PenalizingReviewer));
+
+        let verdict = director.decide(input).await.unwrap();
+        assert!((verdict.penalties["governance"]).abs() < f32::EPSILON);
+        assert!((verdict.penalties["continuity"]).abs() < f32::EPSILON);
+        assert!((verdict.penalties["budget"] - 0.1).abs() < f32::EPSILON);
+        let expected = draft.confidence - verdict.total_penalty();
+        assert!((verdict.confidence - expected).abs() < 1e-6);
+    }
 }
@@ -12,6 +12,9 @@ pub struct ReviewFinding {
     pub passed: bool,
     /// Notes for audit logs.
     pub notes: String,
+    /// Confidence deducted from the draft; 0 for a clean pass.
+    #[serde(default)]
+    pub penalty: f32,
 }
 
 /// Contract implemented by every reviewer.
@@ -24,6 +27,9 @@ pub trait DecisionReviewer: Send + Sync {
     async fn review(&self, draft: &DecisionDraft) -> ReviewFinding;
 }
 
+/// Penalty applied by a governance review that fails its risk threshold.
+pub const GOVERNANCE_PENALTY: f32 = 0.05;
+
 /// Reviewer that enforces governance risk thresholds.
 #[derive(Debug, Clone)]
 pub struct GovernanceReviewer {
@@ -54,6 +60,7 @@ impl DecisionReviewer for GovernanceReviewer {
             } else {
                 format!("risk {:.2} above {}", draft.hypothesis.risk, self.max_risk)
             },
+            penalty: if passed { 0.0 } else { GOVERNANCE_PENALTY },
         }
     }
 }
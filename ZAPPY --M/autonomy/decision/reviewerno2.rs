@@ -5,6 +5,9 @@ use super::{
     reviewer::{DecisionReviewer, ReviewFinding},
 };
 
+/// Penalty applied by a continuity review that fails.
+pub const CONTINUITY_PENALTY: f32 = 0.05;
+
 /// Reviewer that ensures redundancy and continuity considerations.
 #[derive(Debug, Clone)]
 pub struct ContinuityReviewer;
@@ -17,14 +20,16 @@ impl DecisionReviewer for ContinuityReviewer {
 
     async fn review(&self, draft: &DecisionDraft) -> ReviewFinding {
         let sufficient_directives = draft.directives.len() >= 1;
+        let passed = sufficient_directives && draft.confidence >= 0.4;
         ReviewFinding {
             reviewer: self.name().into(),
-            passed: sufficient_directives && draft.confidence >= 0.4,
+            passed,
             notes: format!(
                 "directives={} confidence={:.2}",
                 draft.directives.len(),
                 draft.confidence
             ),
+            penalty: if passed { 0.0 } else { CONTINUITY_PENALTY },
         }
     }
 }
@@ -58,5 +63,13 @@ mod tests {
         let reviewer = ContinuityReviewer;
         let finding = reviewer.review(&draft(0.2)).await;
         assert!(!finding.passed);
+        assert!((finding.penalty - CONTINUITY_PENALTY).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn passing_review_carries_no_penalty() {
+        let finding = ContinuityReviewer.review(&draft(0.8)).await;
+        assert!(finding.passed);
+        assert!(finding.penalty.abs() < f32::EPSILON);
     }
 }
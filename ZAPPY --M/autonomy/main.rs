@@ -110,3 +110,21 @@ pub async fn demo_run(iterations: usize) -> Result<Vec<CycleReport>> {
     }
     Ok(reports)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn bootstrap_modules_handle_domain_and_local_signals() {
+        let runtime = AutonomyRuntime::bootstrap();
+        for scope in [
+            SignalScope::Domain("finance".into()),
+            SignalScope::Local("edge-7".into()),
+        ] {
+            let signal = AutonomySignal::new(scope, "scoped cycle").with_metric("load", 0.2);
+            let report = runtime.run_cycle(signal).await.unwrap();
+            assert!(!report.verdict.directives.is_empty());
+        }
+    }
+}
@@ -5,22 +5,36 @@ pub mod maker;
 /// Reliability calculations for control loops.
 pub mod masterfunc;
 
-use std::sync::Arc;
+use std::{
+    cmp::Reverse,
+    collections::{HashSet, VecDeque},
+    fs,
+    future::Future,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use maker::MasterControllerBuilder;
-use masterfunc::ReliabilityCalculator;
-use parking_lot::RwLock;
+use masterfunc::{ReliabilityCalculator, TokenBucket};
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use shared_logging::LogLevel;
+use tokio::sync::oneshot;
+use uuid::Uuid;
 
 use crate::{
     decision::DecisionVerdict,
-    module::{AutonomyError, ModuleBroker},
+    module::{AutonomyError, ControlDirective, DirectivePriority, ModuleBroker, ModuleTarget},
     telemetry::AutonomyTelemetry,
 };
 
+/// Issued directives retained for inspection by default.
+const DEFAULT_HISTORY_CAPACITY: usize = 256;
+
 /// Observability metrics for the master loop.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MasterMetrics {
@@ -28,6 +42,16 @@ pub struct MasterMetrics {
     pub last_cycle: Option<String>,
     /// Total directives issued in the current epoch.
     pub directives_issued: usize,
+    /// Directives deferred by per-priority rate limits or the in-flight cap; a
+    /// directive deferred again on retry is counted only once.
+    #[serde(default)]
+    pub directives_deferred: usize,
+    /// Tracked directives whose target reported completion.
+    #[serde(default)]
+    pub directives_acknowledged: usize,
+    /// Tracked directives that timed out without an acknowledgement.
+    #[serde(default)]
+    pub directives_unacknowledged: usize,
     /// Average reviewer confidence over time.
     pub avg_confidence: f32,
     /// Number of active modules in the registry.
@@ -41,6 +65,9 @@ impl Default for MasterMetrics {
         Self {
             last_cycle: None,
             directives_issued: 0,
+            directives_deferred: 0,
+            directives_acknowledged: 0,
+            directives_unacknowledged: 0,
             avg_confidence: 0.0,
             modules_active: 0,
             updated_at: Utc::now(),
@@ -48,6 +75,74 @@ impl Default for MasterMetrics {
     }
 }
 
+/// Completion report sent by a target module for a directive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DirectiveOutcome {
+    /// Whether the module carried out the directive.
+    pub success: bool,
+    /// Free-form detail from the module.
+    pub detail: String,
+}
+
+impl DirectiveOutcome {
+    /// Successful completion.
+    #[must_use]
+    pub fn completed(detail: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            detail: detail.into(),
+        }
+    }
+
+    /// Failed attempt.
+    #[must_use]
+    pub fn failed(detail: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Final status of a tracked directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectiveStatus {
+    /// The target module acknowledged the directive.
+    Acknowledged(DirectiveOutcome),
+    /// No acknowledgement arrived before the timeout.
+    Unacknowledged,
+}
+
+#[derive(Debug)]
+enum AckSlot {
+    /// One sender per pending tracker of the directive.
+    Waiting(Vec<oneshot::Sender<DirectiveOutcome>>),
+    Received(DirectiveOutcome, Instant),
+}
+
+type AckMap = Arc<Mutex<IndexMap<Uuid, AckSlot>>>;
+
+/// A tracker's pending ack; dropping it (on timeout, completion or when the tracking
+/// future is dropped) removes its sender and the slot once no tracker is left.
+struct AckWait {
+    id: Uuid,
+    acks: AckMap,
+    rx: oneshot::Receiver<DirectiveOutcome>,
+}
+
+impl Drop for AckWait {
+    fn drop(&mut self) {
+        self.rx.close();
+        let mut acks = self.acks.lock();
+        if let Some(AckSlot::Waiting(senders)) = acks.get_mut(&self.id) {
+            senders.retain(|tx| !tx.is_closed());
+            if senders.is_empty() {
+                acks.shift_remove(&self.id);
+            }
+        }
+    }
+}
+
 /// Applies decisions to modules and tracks reliability.
 #[derive(Debug, Clone)]
 pub struct MasterController {
@@ -55,6 +150,12 @@ pub struct MasterController {
     max_inflight: usize,
     metrics: Arc<RwLock<MasterMetrics>>,
     reliability: Arc<RwLock<ReliabilityCalculator>>,
+    buckets: Arc<RwLock<IndexMap<DirectivePriority, TokenBucket>>>,
+    acks: AckMap,
+    ack_timeout: Duration,
+    history: Arc<Mutex<VecDeque<ControlDirective>>>,
+    history_capacity: usize,
+    deferred: Arc<Mutex<VecDeque<ControlDirective>>>,
     telemetry: Option<AutonomyTelemetry>,
 }
 
@@ -67,6 +168,12 @@ impl MasterController {
             max_inflight,
             metrics: Arc::new(RwLock::new(MasterMetrics::default())),
             reliability: Arc::new(RwLock::new(ReliabilityCalculator::default())),
+            buckets: Arc::new(RwLock::new(IndexMap::new())),
+            acks: Arc::new(Mutex::new(IndexMap::new())),
+            ack_timeout: Duration::from_secs(30),
+            history: Arc::new(Mutex::new(VecDeque::new())),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
+            deferred: Arc::new(Mutex::new(VecDeque::new())),
             telemetry: None,
         }
     }
@@ -83,19 +190,160 @@ impl MasterController {
         self
     }
 
+    /// Rate limits `priority` with a token bucket; Critical directives always pass.
+    #[must_use]
+    pub fn with_rate_limit(
+        self,
+        priority: DirectivePriority,
+        capacity: u16,
+        refill_per_sec: f32,
+    ) -> Self {
+        if priority != DirectivePriority::Critical {
+            self.buckets
+                .write()
+                .insert(priority, TokenBucket::new(capacity, refill_per_sec));
+        }
+        self
+    }
+
+    /// Sets how long [`Self::track_directive`] waits for an acknowledgement.
+    #[must_use]
+    pub const fn with_ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// Sets how many issued directives are retained for [`Self::directives_for`].
+    #[must_use]
+    pub fn with_history_capacity(mut self, capacity: usize) -> Self {
+        self.history_capacity = capacity.max(1);
+        self
+    }
+
+    /// Most recently issued directives addressed to exactly `target`, newest first.
+    ///
+    /// Deferred directives are not recorded; at most `limit` entries are returned.
+    #[must_use]
+    pub fn directives_for(&self, target: &ModuleTarget, limit: usize) -> Vec<ControlDirective> {
+        self.history
+            .lock()
+            .iter()
+            .rev()
+            .filter(|directive| &directive.target == target)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Waits for the target of directive `id` to report completion via [`Self::acknowledge`].
+    ///
+    /// Resolves to [`DirectiveStatus::Unacknowledged`] after the ack timeout; either
+    /// result is counted in [`MasterMetrics`]. Several trackers of the same id all
+    /// receive the ack, and dropping the future stops tracking.
+    pub fn track_directive(&self, id: Uuid) -> impl Future<Output = DirectiveStatus> + Send {
+        let early = {
+            let mut acks = self.acks.lock();
+            match acks.shift_remove(&id) {
+                Some(AckSlot::Received(outcome, at)) if at.elapsed() < self.ack_timeout => {
+                    drop(acks);
+                    Err(outcome)
+                }
+                slot => {
+                    let mut senders = match slot {
+                        Some(AckSlot::Waiting(senders)) => senders,
+                        _ => Vec::new(),
+                    };
+                    let (tx, rx) = oneshot::channel();
+                    senders.push(tx);
+                    acks.insert(id, AckSlot::Waiting(senders));
+                    drop(acks);
+                    Ok(AckWait {
+                        id,
+                        acks: Arc::clone(&self.acks),
+                        rx,
+                    })
+                }
+            }
+        };
+        let metrics = Arc::clone(&self.metrics);
+        let telemetry = self.telemetry.clone();
+        let timeout = self.ack_timeout;
+        async move {
+            let status = match early {
+                Err(outcome) => DirectiveStatus::Acknowledged(outcome),
+                Ok(mut wait) => {
+                    if let Ok(Ok(outcome)) = tokio::time::timeout(timeout, &mut wait.rx).await {
+                        DirectiveStatus::Acknowledged(outcome)
+                    } else {
+                        DirectiveStatus::Unacknowledged
+                    }
+                }
+            };
+            {
+                let mut metrics = metrics.write();
+                match &status {
+                    DirectiveStatus::Acknowledged(_) => metrics.directives_acknowledged += 1,
+                    DirectiveStatus::Unacknowledged => metrics.directives_unacknowledged += 1,
+                }
+                metrics.updated_at = Utc::now();
+            }
+            if let Some(tel) = &telemetry {
+                match &status {
+                    DirectiveStatus::Acknowledged(outcome) => {
+                        let _ = tel.event(
+                            "autonomy.master.directive_acknowledged",
+                            json!({ "id": id, "success": outcome.success, "detail": outcome.detail }),
+                        );
+                    }
+                    DirectiveStatus::Unacknowledged => {
+                        let _ = tel.log(
+                            LogLevel::Warn,
+                            "autonomy.master.directive_unacknowledged",
+                            json!({ "id": id, "timeout_ms": timeout.as_millis() }),
+                        );
+                    }
+                }
+            }
+            status
+        }
+    }
+
+    /// Records that the target of directive `id` finished handling it.
+    ///
+    /// Acks for directives not yet tracked are held for the ack timeout so a later
+    /// [`Self::track_directive`] still sees them; stray acks expire after that.
+    pub fn acknowledge(&self, id: Uuid, outcome: DirectiveOutcome) {
+        let mut acks = self.acks.lock();
+        if let Some(AckSlot::Waiting(senders)) = acks.shift_remove(&id) {
+            drop(acks);
+            for tx in senders {
+                let _ = tx.send(outcome.clone());
+            }
+        } else {
+            acks.retain(|_, slot| match slot {
+                AckSlot::Received(_, at) => at.elapsed() < self.ack_timeout,
+                AckSlot::Waiting(senders) => {
+                    senders.retain(|tx| !tx.is_closed());
+                    !senders.is_empty()
+                }
+            });
+            acks.insert(id, AckSlot::Received(outcome, Instant::now()));
+            drop(acks);
+        }
+    }
+
     /// Applies an approved verdict to the module fabric.
+    ///
+    /// Directives deferred by an earlier cycle are retried first, then the verdict's;
+    /// higher priorities are admitted first. Critical directives are always issued.
+    /// Other directives need a free slot under `max_inflight` and a token from their
+    /// priority bucket; otherwise they are deferred to the next cycle (see
+    /// [`Self::deferred`]). The deferred queue holds at most the history capacity;
+    /// on overflow the lowest-priority directives are dropped, oldest first.
     pub async fn apply_verdict(
         &self,
         verdict: &DecisionVerdict,
     ) -> Result<MasterMetrics, AutonomyError> {
-        if verdict.directives.len() > self.max_inflight {
-            return Err(AutonomyError::Internal(format!(
-                "too many directives: {} > {}",
-                verdict.directives.len(),
-                self.max_inflight
-            )));
-        }
-
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 LogLevel::Info,
@@ -107,6 +355,42 @@ impl MasterController {
             );
         }
 
+        let mut candidates: Vec<_> = self.deferred.lock().drain(..).collect();
+        let retried: HashSet<Uuid> = candidates.iter().map(|directive| directive.id).collect();
+        candidates.extend(verdict.directives.iter().cloned());
+        candidates.sort_by_key(|directive| Reverse(directive.priority));
+        let (admitted, deferred) = self.admit(candidates);
+        let issued = admitted.len();
+        let mut queue = self.deferred.lock();
+        queue.extend(deferred.iter().cloned());
+        let dropped = evict_lowest_priority(&mut queue, self.history_capacity);
+        drop(queue);
+        if !deferred.is_empty() {
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.log(
+                    LogLevel::Warn,
+                    "autonomy.master.directives_deferred",
+                    json!({
+                        "deferred": deferred
+                            .iter()
+                            .map(|directive| json!({ "id": directive.id, "priority": directive.priority.to_string() }))
+                            .collect::<Vec<_>>(),
+                        "dropped": dropped
+                            .iter()
+                            .map(|directive| directive.id)
+                            .collect::<Vec<_>>()
+                    }),
+                );
+            }
+        }
+
+        {
+            let mut history = self.history.lock();
+            history.extend(admitted);
+            let overflow = history.len().saturating_sub(self.history_capacity);
+            history.drain(..overflow);
+        }
+
         {
             let mut reliability = self.reliability.write();
             reliability.record(verdict.confidence);
@@ -114,7 +398,11 @@ impl MasterController {
 
         {
             let mut metrics = self.metrics.write();
-            metrics.directives_issued += verdict.directives.len();
+            metrics.directives_issued += issued;
+            metrics.directives_deferred += deferred
+                .iter()
+                .filter(|directive| !retried.contains(&directive.id))
+                .count();
             metrics.avg_confidence = self.reliability.read().score();
             metrics.last_cycle = Some(verdict.hypothesis.summary.clone());
             metrics.modules_active = self.broker.registry().len();
@@ -127,7 +415,8 @@ impl MasterController {
                 LogLevel::Info,
                 "autonomy.master.apply_complete",
                 json!({
-                    "directives": verdict.directives.len(),
+                    "directives": issued,
+                    "deferred": deferred.len(),
                     "avg_confidence": snapshot.avg_confidence,
                     "modules_active": snapshot.modules_active
                 }),
@@ -144,6 +433,71 @@ impl MasterController {
         Ok(snapshot)
     }
 
+    /// Directives waiting to be retried on the next cycle, highest priority first.
+    #[must_use]
+    pub fn deferred(&self) -> Vec<ControlDirective> {
+        self.deferred.lock().iter().cloned().collect()
+    }
+
+    /// Splits `candidates` into directives issued this cycle and deferred ones.
+    fn admit(
+        &self,
+        candidates: Vec<ControlDirective>,
+    ) -> (Vec<ControlDirective>, Vec<ControlDirective>) {
+        let mut buckets = self.buckets.write();
+        let mut admitted = Vec::new();
+        let mut deferred = Vec::new();
+        for directive in candidates {
+            let allowed = directive.priority == DirectivePriority::Critical
+                || (admitted.len() < self.max_inflight
+                    && buckets
+                        .get_mut(&directive.priority)
+                        .is_none_or(TokenBucket::try_take));
+            if allowed {
+                admitted.push(directive);
+            } else {
+                deferred.push(directive);
+            }
+        }
+        drop(buckets);
+        (admitted, deferred)
+    }
+
+    /// Writes the reliability history to `path` so it survives restarts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parent directory cannot be created or the file written.
+    pub fn save_reliability(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let encoded = serde_json::to_vec_pretty(&*self.reliability.read())?;
+        fs::write(path, encoded)?;
+        Ok(())
+    }
+
+    /// Restores reliability history saved by [`Self::save_reliability`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not hold a saved history; the
+    /// current history is kept in that case.
+    pub fn load_reliability(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let calculator: ReliabilityCalculator = serde_json::from_slice(&fs::read(path)?)?;
+        let score = calculator.score();
+        *self.reliability.write() = calculator;
+        self.metrics.write().avg_confidence = score;
+        Ok(())
+    }
+
+    /// Current reliability score derived from recorded confidences.
+    #[must_use]
+    pub fn reliability(&self) -> f32 {
+        self.reliability.read().score()
+    }
+
     /// Returns the latest metrics snapshot.
     #[must_use]
     pub fn metrics(&self) -> MasterMetrics {
@@ -151,6 +505,26 @@ impl MasterController {
     }
 }
 
+/// Trims `queue` (ordered highest priority first, oldest first within a priority) to
+/// `capacity`, removing the oldest directives of the lowest priority present.
+fn evict_lowest_priority(
+    queue: &mut VecDeque<ControlDirective>,
+    capacity: usize,
+) -> Vec<ControlDirective> {
+    let mut evicted = Vec::new();
+    while queue.len() > capacity {
+        let Some(lowest) = queue.back().map(|directive| directive.priority) else {
+            break;
+        };
+        let oldest = queue
+            .iter()
+            .position(|directive| directive.priority == lowest)
+            .unwrap_or(queue.len() - 1);
+        evicted.extend(queue.remove(oldest));
+    }
+    evicted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,6 +549,7 @@ mod tests {
             )
             .with_priority(DirectivePriority::Routine)],
             findings: Vec::new(),
+            penalties: IndexMap::new(),
             confidence: 0.8,
         }
     }
@@ -190,4 +565,254 @@ mod tests {
         assert_eq!(metrics.directives_issued, 1);
         assert_eq!(metrics.modules_active, 1);
     }
+
+    #[tokio::test]
+    async fn reliability_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state/reliability.json");
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker.clone()).build();
+        let mut verdict = sample_verdict();
+        for confidence in [0.9, 0.4, 0.75] {
+            verdict.confidence = confidence;
+            controller.apply_verdict(&verdict).await.unwrap();
+        }
+        controller.save_reliability(&path).unwrap();
+
+        let restarted = MasterController::builder(broker).build();
+        restarted.load_reliability(&path).unwrap();
+        assert!((restarted.reliability() - controller.reliability()).abs() < f32::EPSILON);
+        assert!(
+            (restarted.metrics().avg_confidence - controller.reliability()).abs() < f32::EPSILON
+        );
+    }
+
+    #[tokio::test]
+    async fn critical_directives_bypass_saturated_routine_bucket() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker)
+            .rate_limit(DirectivePriority::Routine, 2, 0.0)
+            .build();
+        let mut verdict = sample_verdict();
+        verdict.directives = (0..3)
+            .map(|idx| {
+                ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), format!("r{idx}"))
+                    .with_priority(DirectivePriority::Routine)
+            })
+            .collect();
+        let metrics = controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(metrics.directives_issued, 2);
+        assert_eq!(metrics.directives_deferred, 1);
+
+        verdict.directives = vec![
+            ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), "r3")
+                .with_priority(DirectivePriority::Routine),
+            ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), "failover")
+                .with_priority(DirectivePriority::Critical),
+        ];
+        let metrics = controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(metrics.directives_issued, 3);
+        // r2 is deferred again but only counted once; r3 is newly deferred.
+        assert_eq!(metrics.directives_deferred, 2);
+        let queued = controller
+            .deferred()
+            .into_iter()
+            .map(|directive| directive.instructions)
+            .collect::<Vec<_>>();
+        assert_eq!(queued, vec!["r2", "r3"]);
+    }
+
+    #[tokio::test]
+    async fn oversized_verdict_issues_critical_and_defers_the_rest() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker).max_inflight(2).build();
+        let mut verdict = sample_verdict();
+        verdict.directives = (0..4)
+            .map(|idx| {
+                ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), format!("r{idx}"))
+                    .with_priority(DirectivePriority::Routine)
+            })
+            .collect();
+        verdict.directives.push(
+            ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), "failover")
+                .with_priority(DirectivePriority::Critical),
+        );
+
+        let metrics = controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(metrics.directives_issued, 2);
+        assert_eq!(metrics.directives_deferred, 3);
+        let issued = controller.directives_for(&ModuleTarget::Kind(ModuleKind::Planner), 8);
+        assert_eq!(issued[0].instructions, "r0");
+        assert_eq!(issued[1].instructions, "failover");
+
+        verdict.directives.clear();
+        let metrics = controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(metrics.directives_issued, 4);
+        assert_eq!(controller.deferred().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn deferred_overflow_drops_the_oldest_lowest_priority_directives() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker)
+            .max_inflight(1)
+            .history_capacity(3)
+            .build();
+        let directive = |name: &str, priority| {
+            ControlDirective::new(ModuleTarget::Kind(ModuleKind::Planner), name)
+                .with_priority(priority)
+        };
+        let queued = |controller: &MasterController| {
+            controller
+                .deferred()
+                .into_iter()
+                .map(|directive| directive.instructions)
+                .collect::<Vec<_>>()
+        };
+        let mut verdict = sample_verdict();
+
+        // The critical directive takes the only in-flight slot every cycle.
+        verdict.directives = vec![
+            directive("c0", DirectivePriority::Critical),
+            directive("r0", DirectivePriority::Routine),
+            directive("e0", DirectivePriority::Elevated),
+            directive("r1", DirectivePriority::Routine),
+            directive("e1", DirectivePriority::Elevated),
+        ];
+        controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(queued(&controller), vec!["e0", "e1", "r1"]);
+
+        verdict.directives = vec![
+            directive("c1", DirectivePriority::Critical),
+            directive("e2", DirectivePriority::Elevated),
+        ];
+        controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(queued(&controller), vec!["e0", "e1", "e2"]);
+
+        verdict.directives = vec![
+            directive("c2", DirectivePriority::Critical),
+            directive("e3", DirectivePriority::Elevated),
+        ];
+        controller.apply_verdict(&verdict).await.unwrap();
+        assert_eq!(queued(&controller), vec!["e1", "e2", "e3"]);
+    }
+
+    #[tokio::test]
+    async fn acknowledged_directive_is_recorded() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker).build();
+        let verdict = sample_verdict();
+        let id = verdict.directives[0].id;
+        controller.apply_verdict(&verdict).await.unwrap();
+
+        let tracked = tokio::spawn(controller.track_directive(id));
+        controller.acknowledge(id, DirectiveOutcome::completed("replanned"));
+        assert_eq!(
+            tracked.await.unwrap(),
+            DirectiveStatus::Acknowledged(DirectiveOutcome::completed("replanned"))
+        );
+        let metrics = controller.metrics();
+        assert_eq!(metrics.directives_acknowledged, 1);
+        assert_eq!(metrics.directives_unacknowledged, 0);
+    }
+
+    #[tokio::test]
+    async fn directive_history_is_queried_by_target() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker)
+            .history_capacity(4)
+            .build();
+        let planner = ModuleTarget::Kind(ModuleKind::Planner);
+        let executor = ModuleTarget::Kind(ModuleKind::Executor);
+        let mut verdict = sample_verdict();
+        for idx in 0..3 {
+            verdict.directives = vec![
+                ControlDirective::new(planner.clone(), format!("plan {idx}")),
+                ControlDirective::new(executor.clone(), format!("run {idx}")),
+            ];
+            controller.apply_verdict(&verdict).await.unwrap();
+        }
+
+        let recent = controller.directives_for(&planner, 5);
+        let instructions: Vec<_> = recent.iter().map(|d| d.instructions.as_str()).collect();
+        assert_eq!(instructions, vec!["plan 2", "plan 1"]);
+        assert!(recent.iter().all(|directive| directive.target == planner));
+        assert_eq!(
+            controller.directives_for(&executor, 1)[0].instructions,
+            "run 2"
+        );
+        assert!(controller.directives_for(&ModuleTarget::All, 5).is_empty());
+    }
+
+    #[tokio::test]
+    async fn silent_directive_times_out_unacknowledged() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker)
+            .ack_timeout(Duration::from_millis(20))
+            .build();
+        let status = controller.track_directive(Uuid::new_v4()).await;
+        assert_eq!(status, DirectiveStatus::Unacknowledged);
+        assert_eq!(controller.metrics().directives_unacknowledged, 1);
+    }
+
+    #[tokio::test]
+    async fn stray_acks_expire_after_the_ack_timeout() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker)
+            .ack_timeout(Duration::from_millis(20))
+            .build();
+        let early = Uuid::new_v4();
+        controller.acknowledge(early, DirectiveOutcome::completed("early"));
+        assert_eq!(
+            controller.track_directive(early).await,
+            DirectiveStatus::Acknowledged(DirectiveOutcome::completed("early"))
+        );
+
+        let stray = Uuid::new_v4();
+        controller.acknowledge(stray, DirectiveOutcome::completed("stray"));
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        controller.acknowledge(Uuid::new_v4(), DirectiveOutcome::completed("stray"));
+        assert_eq!(controller.acks.lock().len(), 1);
+        assert_eq!(
+            controller.track_directive(stray).await,
+            DirectiveStatus::Unacknowledged
+        );
+    }
+
+    #[tokio::test]
+    async fn dropped_trackers_release_their_slot() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker).build();
+        let id = Uuid::new_v4();
+        drop(controller.track_directive(id));
+        assert!(controller.acks.lock().is_empty());
+
+        let first = controller.track_directive(id);
+        let second = tokio::spawn(controller.track_directive(id));
+        drop(first);
+        assert_eq!(controller.acks.lock().len(), 1);
+        controller.acknowledge(id, DirectiveOutcome::completed("done"));
+        assert_eq!(
+            second.await.unwrap(),
+            DirectiveStatus::Acknowledged(DirectiveOutcome::completed("done"))
+        );
+        assert!(controller.acks.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn concurrent_trackers_share_one_ack() {
+        let broker = ModuleBroker::new(ModuleRegistry::default());
+        let controller = MasterController::builder(broker).build();
+        let id = Uuid::new_v4();
+        let first = tokio::spawn(controller.track_directive(id));
+        let second = tokio::spawn(controller.track_directive(id));
+        controller.acknowledge(id, DirectiveOutcome::completed("done"));
+        for tracker in [first, second] {
+            assert_eq!(
+                tracker.await.unwrap(),
+                DirectiveStatus::Acknowledged(DirectiveOutcome::completed("done"))
+            );
+        }
+        assert_eq!(controller.metrics().directives_acknowledged, 2);
+    }
 }
@@ -1,4 +1,11 @@
-use crate::{module::ModuleBroker, telemetry::AutonomyTelemetry};
+use std::time::Duration;
+
+use indexmap::IndexMap;
+
+use crate::{
+    module::{DirectivePriority, ModuleBroker},
+    telemetry::AutonomyTelemetry,
+};
 
 use super::MasterController;
 
@@ -7,6 +14,9 @@ use super::MasterController;
 pub struct MasterControllerBuilder {
     broker: ModuleBroker,
     max_inflight: usize,
+    rate_limits: IndexMap<DirectivePriority, (u16, f32)>,
+    ack_timeout: Option<Duration>,
+    history_capacity: Option<usize>,
     telemetry: Option<AutonomyTelemetry>,
 }
 
@@ -17,6 +27,9 @@ impl MasterControllerBuilder {
         Self {
             broker,
             max_inflight: 8,
+            rate_limits: IndexMap::new(),
+            ack_timeout: None,
+            history_capacity: None,
             telemetry: None,
         }
     }
@@ -28,6 +41,34 @@ impl MasterControllerBuilder {
         self
     }
 
+    /// Limits `priority` to a token bucket of `capacity` directives refilled at
+    /// `refill_per_sec`. Critical directives are never limited.
+    #[must_use]
+    pub fn rate_limit(
+        mut self,
+        priority: DirectivePriority,
+        capacity: u16,
+        refill_per_sec: f32,
+    ) -> Self {
+        self.rate_limits
+            .insert(priority, (capacity, refill_per_sec));
+        self
+    }
+
+    /// Overrides how long tracked directives wait for an acknowledgement.
+    #[must_use]
+    pub const fn ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides how many issued directives are kept for inspection.
+    #[must_use]
+    pub const fn history_capacity(mut self, capacity: usize) -> Self {
+        self.history_capacity = Some(capacity);
+        self
+    }
+
     /// Attaches telemetry used by the master controller.
     #[must_use]
     pub fn telemetry(mut self, telemetry: AutonomyTelemetry) -> Self {
@@ -39,6 +80,15 @@ impl MasterControllerBuilder {
     #[must_use]
     pub fn build(self) -> MasterController {
         let mut controller = MasterController::new(self.broker, self.max_inflight);
+        for (priority, (capacity, refill_per_sec)) in self.rate_limits {
+            controller = controller.with_rate_limit(priority, capacity, refill_per_sec);
+        }
+        if let Some(timeout) = self.ack_timeout {
+            controller = controller.with_ack_timeout(timeout);
+        }
+        if let Some(capacity) = self.history_capacity {
+            controller = controller.with_history_capacity(capacity);
+        }
         if let Some(tel) = self.telemetry {
             controller = controller.with_telemetry(tel);
         }
@@ -1,5 +1,9 @@
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
 /// Tracks rolling reliability for master controller outputs.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ReliabilityCalculator {
     history: Vec<f32>,
     capacity: usize,
@@ -33,6 +37,45 @@ impl ReliabilityCalculator {
     }
 }
 
+/// Token bucket limiting how many directives a priority class may issue.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f32,
+    tokens: f32,
+    refill_per_sec: f32,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket holding `capacity` tokens, refilled at `refill_per_sec`.
+    #[must_use]
+    pub fn new(capacity: u16, refill_per_sec: f32) -> Self {
+        let capacity = f32::from(capacity.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: refill_per_sec.max(0.0),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Takes a token if one is available.
+    pub fn try_take(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f32();
+        self.tokens = elapsed
+            .mul_add(self.refill_per_sec, self.tokens)
+            .min(self.capacity);
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +87,24 @@ mod tests {
         calc.record(0.9);
         assert!(calc.score() > 0.0);
     }
+
+    #[test]
+    fn calculator_round_trips_through_json() {
+        let mut calc = ReliabilityCalculator::default();
+        for sample in [0.2, 0.7, 1.4, 0.55] {
+            calc.record(sample);
+        }
+        let restored: ReliabilityCalculator =
+            serde_json::from_str(&serde_json::to_string(&calc).unwrap()).unwrap();
+        assert_eq!(restored, calc);
+        assert!((restored.score() - calc.score()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bucket_drains_without_refill() {
+        let mut bucket = TokenBucket::new(2, 0.0);
+        assert!(bucket.try_take());
+        assert!(bucket.try_take());
+        assert!(!bucket.try_take());
+    }
 }
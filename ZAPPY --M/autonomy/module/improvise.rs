@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +28,56 @@ impl ImprovisationHint {
     }
 }
 
+/// Fixed-window cap on how many directives may be improvised.
+#[derive(Debug, Clone)]
+pub struct ImprovisationBudget {
+    max_per_window: u32,
+    window: Duration,
+    used: u32,
+    window_started: Instant,
+}
+
+impl ImprovisationBudget {
+    /// Allows `max_per_window` improvisations per `window`.
+    #[must_use]
+    pub fn new(max_per_window: u32, window: Duration) -> Self {
+        Self {
+            max_per_window,
+            window,
+            used: 0,
+            window_started: Instant::now(),
+        }
+    }
+
+    /// Spends one improvisation at `now`, starting a fresh window once `window` has
+    /// elapsed since the current one began.
+    ///
+    /// # Errors
+    ///
+    /// The budget is exhausted when `max_per_window` improvisations were already spent
+    /// in the current window; the error is the wait until that window resets.
+    pub fn try_spend_at(&mut self, now: Instant) -> Result<(), Duration> {
+        if now.duration_since(self.window_started) >= self.window {
+            self.window_started = now;
+            self.used = 0;
+        }
+        if self.used < self.max_per_window {
+            self.used += 1;
+            Ok(())
+        } else {
+            Err(self
+                .window
+                .saturating_sub(now.duration_since(self.window_started)))
+        }
+    }
+
+    /// Improvisations still available in the current window.
+    #[must_use]
+    pub const fn remaining(&self) -> u32 {
+        self.max_per_window.saturating_sub(self.used)
+    }
+}
+
 /// Generates improvisational directives when brittle plans need adaptation.
 #[derive(Debug)]
 pub struct ImprovisationEngine {
@@ -66,6 +118,20 @@ mod tests {
     use super::*;
     use crate::module::ModuleTarget;
 
+    #[test]
+    fn budget_resets_after_window() {
+        let mut budget = ImprovisationBudget::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+        assert!(budget.try_spend_at(start).is_ok());
+        assert!(budget.try_spend_at(start).is_ok());
+        let wait = budget
+            .try_spend_at(start + Duration::from_secs(4))
+            .unwrap_err();
+        assert!(wait <= Duration::from_secs(6) && wait > Duration::from_secs(5));
+        assert!(budget.try_spend_at(start + Duration::from_secs(10)).is_ok());
+        assert_eq!(budget.remaining(), 1);
+    }
+
     #[test]
     fn hint_generates_directive() {
         let mut engine = ImprovisationEngine::default();
@@ -7,11 +7,15 @@ pub mod improvise;
 /// Lightweight neural abstractions.
 pub mod neuron;
 
-use std::{fmt, sync::Arc};
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration as StdDuration, Instant},
+};
 
 use chrono::{DateTime, Duration, Utc};
 use helper::{normalize_scores, SignalSmoother};
-use improvise::{ImprovisationEngine, ImprovisationHint};
+use improvise::{ImprovisationBudget, ImprovisationEngine, ImprovisationHint};
 use indexmap::IndexMap;
 use neuron::{NeuronGraph, NeuronPulse};
 use parking_lot::{Mutex, RwLock};
@@ -32,13 +36,27 @@ pub enum AutonomyError {
     /// There is no module of the requested type.
     #[error("no module registered for kind {0:?}")]
     MissingKind(ModuleKind),
+    /// No module of the requested kind handles the signal scope.
+    #[error("no {kind:?} module handles scope {scope:?}")]
+    OutOfScope {
+        /// Requested module kind.
+        kind: ModuleKind,
+        /// Scope of the unroutable signal.
+        scope: SignalScope,
+    },
+    /// The improvisation budget for the current window is spent.
+    #[error("improvisation throttled; budget resets in {retry_in:?}")]
+    Throttled {
+        /// Time until the budget window resets.
+        retry_in: StdDuration,
+    },
     /// Catch-all for internal issues.
     #[error("internal autonomy error: {0}")]
     Internal(String),
 }
 
 /// Scope associated with an autonomy signal.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum SignalScope {
     /// Global AGI-wide signal.
     Global,
@@ -48,6 +66,18 @@ pub enum SignalScope {
     Local(String),
 }
 
+impl SignalScope {
+    /// Whether a module declaring `handled` scopes should receive this signal.
+    ///
+    /// Global signals reach every module; domain and local signals only reach
+    /// modules that declare the exact same domain or subsystem. See
+    /// [`ModuleSpec::handles`] for modules that declare no scopes.
+    #[must_use]
+    pub fn reaches(&self, handled: &[Self]) -> bool {
+        matches!(self, Self::Global) || handled.contains(self)
+    }
+}
+
 /// Real-time telemetry emitted by modules, planners, or sensors.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutonomySignal {
@@ -211,6 +241,9 @@ pub struct ModuleSpec {
     pub capacity: u32,
     /// Health score between 0 and 1.
     pub health: f32,
+    /// Domain and local scopes the module handles; global signals always reach it.
+    /// A module declaring no scopes handles every scope.
+    pub scopes: Vec<SignalScope>,
 }
 
 impl ModuleSpec {
@@ -223,14 +256,33 @@ impl ModuleSpec {
             kind,
             capacity: 100,
             health: 0.9,
+            scopes: Vec::new(),
         }
     }
+
+    /// Declares an additional scope handled by the module.
+    #[must_use]
+    pub fn with_scope(mut self, scope: SignalScope) -> Self {
+        if !self.scopes.contains(&scope) {
+            self.scopes.push(scope);
+        }
+        self
+    }
+
+    /// Whether signals of `scope` should be routed to this module.
+    ///
+    /// Modules without declared scopes are generalists and handle every signal.
+    #[must_use]
+    pub fn handles(&self, scope: &SignalScope) -> bool {
+        self.scopes.is_empty() || scope.reaches(&self.scopes)
+    }
 }
 
 /// Registry tracking all modules known to the autonomy kernel.
 #[derive(Debug, Default, Clone)]
 pub struct ModuleRegistry {
     inner: Arc<RwLock<IndexMap<ModuleId, ModuleSpec>>>,
+    loads: Arc<RwLock<IndexMap<ModuleId, f32>>>,
 }
 
 impl ModuleRegistry {
@@ -251,6 +303,17 @@ impl ModuleRegistry {
         self.inner.read().values().cloned().collect()
     }
 
+    /// Snapshot of the specs that handle `scope`.
+    #[must_use]
+    pub fn in_scope(&self, scope: &SignalScope) -> Vec<ModuleSpec> {
+        self.inner
+            .read()
+            .values()
+            .filter(|spec| spec.handles(scope))
+            .cloned()
+            .collect()
+    }
+
     /// Fetches a specific module.
     pub fn get(&self, id: &ModuleId) -> Result<ModuleSpec, AutonomyError> {
         self.inner
@@ -262,17 +325,102 @@ impl ModuleRegistry {
 
     /// Returns the healthiest module for the given kind.
     pub fn best_of_kind(&self, kind: &ModuleKind) -> Result<ModuleSpec, AutonomyError> {
-        self.inner
+        self.healthiest(|spec| &spec.kind == kind)
+            .ok_or_else(|| AutonomyError::MissingKind(kind.clone()))
+    }
+
+    /// Records the current normalized load (0-1) of a module.
+    pub fn record_load(&self, id: ModuleId, load: f32) {
+        self.loads.write().insert(id, load.clamp(0.0, 1.0));
+    }
+
+    /// Last recorded load of a module (0 when never reported).
+    #[must_use]
+    pub fn load(&self, id: &ModuleId) -> f32 {
+        self.loads.read().get(id).copied().unwrap_or_default()
+    }
+
+    /// Picks a module of `kind` at random, weighted by health and inverse load, so
+    /// traffic spreads across healthy modules instead of piling onto the best one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AutonomyError::MissingKind`] when no module of `kind` is registered.
+    pub fn select_weighted(&self, kind: &ModuleKind) -> Result<ModuleSpec, AutonomyError> {
+        self.select_weighted_with(kind, &mut thread_rng())
+    }
+
+    /// [`Self::select_weighted`] drawing from the provided RNG.
+    ///
+    /// Each module weighs `health / (1 + load)`; when every weight is zero the
+    /// healthiest module is returned.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`Self::select_weighted`].
+    pub fn select_weighted_with(
+        &self,
+        kind: &ModuleKind,
+        rng: &mut impl Rng,
+    ) -> Result<ModuleSpec, AutonomyError> {
+        let loads = self.loads.read();
+        let candidates = self
+            .inner
             .read()
             .values()
             .filter(|spec| &spec.kind == kind)
+            .map(|spec| {
+                let load = loads.get(&spec.id).copied().unwrap_or_default();
+                (spec.clone(), spec.health.max(0.0) / (1.0 + load))
+            })
+            .collect::<Vec<_>>();
+        drop(loads);
+        let total = candidates.iter().map(|(_, weight)| weight).sum::<f32>();
+        if total <= f32::EPSILON {
+            return self.best_of_kind(kind);
+        }
+        let mut draw = rng.gen_range(0.0..total);
+        let last = candidates.len() - 1;
+        for (idx, (spec, weight)) in candidates.into_iter().enumerate() {
+            if draw < weight || idx == last {
+                return Ok(spec);
+            }
+            draw -= weight;
+        }
+        unreachable!("weighted selection always returns a candidate")
+    }
+
+    /// Returns the healthiest module of the given kind that handles `scope`.
+    ///
+    /// Modules declaring `scope` explicitly are preferred over generalists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AutonomyError::OutOfScope`] when no module of `kind` handles `scope`.
+    pub fn best_in_scope(
+        &self,
+        kind: &ModuleKind,
+        scope: &SignalScope,
+    ) -> Result<ModuleSpec, AutonomyError> {
+        self.healthiest(|spec| &spec.kind == kind && spec.scopes.contains(scope))
+            .or_else(|| self.healthiest(|spec| &spec.kind == kind && spec.handles(scope)))
+            .ok_or_else(|| AutonomyError::OutOfScope {
+                kind: kind.clone(),
+                scope: scope.clone(),
+            })
+    }
+
+    fn healthiest(&self, filter: impl Fn(&ModuleSpec) -> bool) -> Option<ModuleSpec> {
+        self.inner
+            .read()
+            .values()
+            .filter(|spec| filter(spec))
             .max_by(|a, b| {
                 a.health
                     .partial_cmp(&b.health)
                     .unwrap_or(std::cmp::Ordering::Equal)
             })
             .cloned()
-            .ok_or_else(|| AutonomyError::MissingKind(kind.clone()))
     }
 }
 
@@ -292,6 +440,7 @@ pub struct ModulePulse {
 pub struct ModuleBroker {
     registry: ModuleRegistry,
     improvisor: Arc<Mutex<ImprovisationEngine>>,
+    budget: Option<Arc<Mutex<ImprovisationBudget>>>,
     smoother: Arc<Mutex<SignalSmoother>>,
     neurons: Arc<NeuronGraph>,
 }
@@ -303,20 +452,52 @@ impl ModuleBroker {
         Self {
             registry,
             improvisor: Arc::new(Mutex::new(ImprovisationEngine::default())),
+            budget: None,
             smoother: Arc::new(Mutex::new(SignalSmoother::new(8))),
             neurons: Arc::new(NeuronGraph::default()),
         }
     }
 
+    /// Caps [`Self::improvise`] at `max_per_window` directives per `window`.
+    #[must_use]
+    pub fn with_improvisation_budget(mut self, max_per_window: u32, window: StdDuration) -> Self {
+        self.budget = Some(Arc::new(Mutex::new(ImprovisationBudget::new(
+            max_per_window,
+            window,
+        ))));
+        self
+    }
+
+    /// Replaces the neuron graph, e.g. with one restored via [`NeuronGraph::from_json`].
+    #[must_use]
+    pub fn with_neuron_graph(mut self, graph: NeuronGraph) -> Self {
+        self.neurons = Arc::new(graph);
+        self
+    }
+
+    /// Returns the neuron graph used to score pulses.
+    #[must_use]
+    pub fn neuron_graph(&self) -> &NeuronGraph {
+        &self.neurons
+    }
+
     /// Returns the underlying registry.
     #[must_use]
     pub fn registry(&self) -> ModuleRegistry {
         self.registry.clone()
     }
 
-    /// Processes a signal and returns a pulse for the healthiest planner.
+    /// Modules that should receive `signal` according to their declared scopes.
+    #[must_use]
+    pub fn route(&self, signal: &AutonomySignal) -> Vec<ModuleSpec> {
+        self.registry.in_scope(&signal.scope)
+    }
+
+    /// Processes a signal and returns a pulse for the healthiest planner handling its scope.
     pub fn evaluate_signal(&self, signal: &AutonomySignal) -> Result<ModulePulse, AutonomyError> {
-        let planner = self.registry.best_of_kind(&ModuleKind::Planner)?;
+        let planner = self
+            .registry
+            .best_in_scope(&ModuleKind::Planner, &signal.scope)?;
         let normalized = normalize_scores(&signal.metrics);
         let load = normalized.get("load").copied().unwrap_or(0.3) as f32;
         let mut smoother = self.smoother.lock();
@@ -330,6 +511,59 @@ impl ModuleBroker {
         })
     }
 
+    /// Aggregates a burst of signals into a single pulse.
+    ///
+    /// Only signals whose timestamp falls within `window` of the newest signal are
+    /// considered; their metrics are averaged before a single smoothing step so
+    /// bursts do not churn the smoother. The aggregate keeps the scope shared by the
+    /// windowed signals, and windows mixing scopes are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AutonomyError::Internal`] for an empty window or one mixing scopes, and
+    /// [`AutonomyError::OutOfScope`] when no planner handles the window's scope.
+    pub fn evaluate_window(
+        &self,
+        signals: &[AutonomySignal],
+        window: Duration,
+    ) -> Result<ModulePulse, AutonomyError> {
+        let latest = signals
+            .iter()
+            .map(|signal| signal.timestamp)
+            .max()
+            .ok_or_else(|| AutonomyError::Internal("empty signal window".into()))?;
+        let cutoff = latest - window;
+        let windowed: Vec<_> = signals
+            .iter()
+            .filter(|signal| signal.timestamp >= cutoff)
+            .collect();
+        let scope = windowed
+            .first()
+            .map(|signal| signal.scope.clone())
+            .ok_or_else(|| AutonomyError::Internal("empty signal window".into()))?;
+        if windowed.iter().any(|signal| signal.scope != scope) {
+            return Err(AutonomyError::Internal("signal window mixes scopes".into()));
+        }
+
+        let mut sums: IndexMap<String, (f64, u32)> = IndexMap::new();
+        for signal in windowed {
+            for (key, value) in &signal.metrics {
+                let entry = sums.entry(key.clone()).or_insert((0.0, 0));
+                entry.0 += value;
+                entry.1 += 1;
+            }
+        }
+        let averaged = sums
+            .into_iter()
+            .map(|(key, (sum, count))| (key, sum / f64::from(count)))
+            .collect();
+
+        let mut aggregate = AutonomySignal::new(scope, "windowed aggregate");
+        aggregate.timestamp = latest;
+        aggregate.metrics = averaged;
+        self.evaluate_signal(&aggregate)
+    }
+
     /// Issues a deterministic directive with auto-generated instructions.
     #[must_use]
     pub fn issue_directive(
@@ -358,9 +592,19 @@ impl ModuleBroker {
     }
 
     /// Generates an improvisational directive immediately.
-    #[must_use]
-    pub fn improvise(&self, hint: &ImprovisationHint) -> ControlDirective {
-        self.improvisor.lock().propose(hint)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AutonomyError::Throttled`] once the improvisation budget (if any) is
+    /// spent for the current window; `retry_in` is the time until the window resets.
+    pub fn improvise(&self, hint: &ImprovisationHint) -> Result<ControlDirective, AutonomyError> {
+        if let Some(budget) = &self.budget {
+            budget
+                .lock()
+                .try_spend_at(Instant::now())
+                .map_err(|retry_in| AutonomyError::Throttled { retry_in })?;
+        }
+        Ok(self.improvisor.lock().propose(hint))
     }
 
     fn token() -> String {
@@ -385,6 +629,7 @@ mod tests {
             kind: ModuleKind::Planner,
             capacity: 100,
             health: 0.8,
+            scopes: Vec::new(),
         });
         registry.upsert(ModuleSpec {
             id: Uuid::new_v4(),
@@ -392,12 +637,56 @@ mod tests {
             kind: ModuleKind::Planner,
             capacity: 100,
             health: 0.9,
+            scopes: Vec::new(),
         });
 
         let best = registry.best_of_kind(&ModuleKind::Planner).unwrap();
         assert_eq!(best.name, "planner-B");
     }
 
+    #[test]
+    fn weighted_selection_tracks_health_and_load() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let registry = ModuleRegistry::default();
+        let mut ids = Vec::new();
+        for health in [0.9, 0.6, 0.3] {
+            let mut spec = ModuleSpec::new(format!("planner-{health}"), ModuleKind::Planner);
+            spec.health = health;
+            ids.push(spec.id);
+            registry.upsert(spec);
+        }
+        registry.upsert(ModuleSpec::new("sensor", ModuleKind::Sensor));
+
+        let draws = 20_000;
+        let share = |registry: &ModuleRegistry, seed| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            let mut counts = IndexMap::<ModuleId, u32>::new();
+            for _ in 0..draws {
+                let spec = registry
+                    .select_weighted_with(&ModuleKind::Planner, &mut rng)
+                    .unwrap();
+                *counts.entry(spec.id).or_default() += 1;
+            }
+            ids.iter()
+                .map(|id| f64::from(counts.get(id).copied().unwrap_or_default()) / f64::from(draws))
+                .collect::<Vec<_>>()
+        };
+
+        let observed = share(&registry, 7);
+        for (observed, expected) in observed.iter().zip([0.5, 1.0 / 3.0, 1.0 / 6.0]) {
+            assert!(
+                (observed - expected).abs() < 0.02,
+                "{observed} vs {expected}"
+            );
+        }
+
+        // health 0.9 at full load weighs 0.45, half of the idle 0.6 + 0.3 pair.
+        registry.record_load(ids[0], 1.0);
+        let observed = share(&registry, 11);
+        assert!((observed[0] - 1.0 / 3.0).abs() < 0.02, "{}", observed[0]);
+    }
+
     #[test]
     fn broker_generates_directives() {
         let registry = ModuleRegistry::default();
@@ -410,4 +699,111 @@ mod tests {
         );
         assert_eq!(directive.priority, DirectivePriority::Critical);
     }
+
+    #[test]
+    fn improvisation_budget_throttles_until_window_resets() {
+        let broker = ModuleBroker::new(ModuleRegistry::default())
+            .with_improvisation_budget(2, StdDuration::from_millis(50));
+        let hint = broker.hint(ModuleKind::Executor, "stability", 0.5);
+        assert!(broker.improvise(&hint).is_ok());
+        assert!(broker.improvise(&hint).is_ok());
+        assert!(matches!(
+            broker.improvise(&hint),
+            Err(AutonomyError::Throttled { retry_in }) if retry_in <= StdDuration::from_millis(50)
+        ));
+
+        std::thread::sleep(StdDuration::from_millis(60));
+        assert!(broker.improvise(&hint).is_ok());
+    }
+
+    #[test]
+    fn domain_signal_skips_modules_of_other_domains() {
+        let registry = ModuleRegistry::default();
+        registry.upsert(
+            ModuleSpec::new("infra-planner", ModuleKind::Planner)
+                .with_scope(SignalScope::Domain("infra".into())),
+        );
+        let broker = ModuleBroker::new(registry.clone());
+        let signal = AutonomySignal::new(SignalScope::Domain("finance".into()), "market swing")
+            .with_metric("load", 0.4);
+
+        assert!(broker.route(&signal).is_empty());
+        assert!(matches!(
+            broker.evaluate_signal(&signal),
+            Err(AutonomyError::OutOfScope { .. })
+        ));
+
+        registry.upsert(
+            ModuleSpec::new("finance-planner", ModuleKind::Planner)
+                .with_scope(SignalScope::Domain("finance".into())),
+        );
+        let pulse = broker.evaluate_signal(&signal).unwrap();
+        assert_eq!(pulse.spec.name, "finance-planner");
+        let global = AutonomySignal::new(SignalScope::Global, "heartbeat");
+        assert_eq!(broker.route(&global).len(), 2);
+
+        let mut generalist = ModuleSpec::new("generalist", ModuleKind::Planner);
+        generalist.health = 1.0;
+        registry.upsert(generalist);
+        assert_eq!(broker.route(&signal).len(), 2);
+        assert_eq!(
+            broker.evaluate_signal(&signal).unwrap().spec.name,
+            "finance-planner"
+        );
+    }
+
+    #[test]
+    fn window_averages_burst_metrics() {
+        let registry = ModuleRegistry::default();
+        registry.upsert(ModuleSpec::new("planner", ModuleKind::Planner));
+        let broker = ModuleBroker::new(registry);
+        let now = Utc::now();
+        let burst: Vec<_> = [(0.2, 2), (0.4, 1), (0.9, 0), (0.05, 600)]
+            .into_iter()
+            .map(|(load, age_secs)| {
+                let mut signal = AutonomySignal::new(SignalScope::Global, "burst")
+                    .with_metric("load", load)
+                    .with_metric("stability", 1.0 - load);
+                signal.timestamp = now - Duration::seconds(age_secs);
+                signal
+            })
+            .collect();
+
+        let pulse = broker
+            .evaluate_window(&burst, Duration::seconds(10))
+            .unwrap();
+        // The stale sample is dropped and the pulse reflects the window mean,
+        // not the most recent (0.9) reading.
+        assert!((pulse.load - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn window_keeps_the_burst_scope() {
+        let registry = ModuleRegistry::default();
+        registry.upsert(
+            ModuleSpec::new("infra-planner", ModuleKind::Planner)
+                .with_scope(SignalScope::Domain("infra".into())),
+        );
+        let broker = ModuleBroker::new(registry);
+        let finance = |load| {
+            AutonomySignal::new(SignalScope::Domain("finance".into()), "market burst")
+                .with_metric("load", load)
+        };
+
+        let burst = [finance(0.3), finance(0.5)];
+        assert!(matches!(
+            broker.evaluate_window(&burst, Duration::seconds(10)),
+            Err(AutonomyError::OutOfScope { scope, .. })
+                if scope == SignalScope::Domain("finance".into())
+        ));
+
+        let mixed = [
+            finance(0.3),
+            AutonomySignal::new(SignalScope::Global, "heartbeat"),
+        ];
+        assert!(matches!(
+            broker.evaluate_window(&mixed, Duration::seconds(10)),
+            Err(AutonomyError::Internal(_))
+        ));
+    }
 }
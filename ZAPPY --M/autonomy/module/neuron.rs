@@ -1,4 +1,5 @@
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
 /// Snapshot of a neuron's activation state.
 #[derive(Debug, Clone)]
@@ -11,7 +12,7 @@ pub struct NeuronPulse {
     pub commentary: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Neuron {
     name: String,
     weight: f32,
@@ -25,8 +26,17 @@ impl Neuron {
     }
 }
 
+/// Node/edge counts describing the shape of a [`NeuronGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeuronTopology {
+    /// Number of neurons in the graph.
+    pub nodes: usize,
+    /// Number of metric-to-neuron input connections.
+    pub edges: usize,
+}
+
 /// Lightweight neural ensemble that scores module health.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NeuronGraph {
     neurons: Vec<Neuron>,
 }
@@ -67,6 +77,34 @@ impl NeuronGraph {
             })
             .collect()
     }
+
+    /// Returns node/edge counts for inspection.
+    #[must_use]
+    pub const fn topology(&self) -> NeuronTopology {
+        NeuronTopology {
+            nodes: self.neurons.len(),
+            // Every neuron is fed by exactly one metric input.
+            edges: self.neurons.len(),
+        }
+    }
+
+    /// Serializes the graph (names, weights, biases) to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph cannot be serialized.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Restores a graph previously produced by [`NeuronGraph::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is not a serialized graph.
+    pub fn from_json(data: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(data)
+    }
 }
 
 #[cfg(test)]
@@ -82,4 +120,24 @@ mod tests {
         assert_eq!(pulses.len(), 2);
         assert!(pulses.iter().any(|p| p.name == "stability"));
     }
+
+    #[test]
+    fn json_round_trip_preserves_pulses() {
+        let graph = NeuronGraph::default();
+        let restored = NeuronGraph::from_json(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(restored, graph);
+        assert_eq!(restored.topology(), graph.topology());
+
+        let mut metrics = IndexMap::new();
+        metrics.insert("stability".into(), 0.7);
+        metrics.insert("safety".into(), 0.3);
+        let before = graph.pulse(&metrics);
+        let after = restored.pulse(&metrics);
+        assert_eq!(before.len(), after.len());
+        for (a, b) in before.iter().zip(&after) {
+            assert_eq!(a.name, b.name);
+            assert!((a.activation - b.activation).abs() < f32::EPSILON);
+            assert_eq!(a.commentary, b.commentary);
+        }
+    }
 }
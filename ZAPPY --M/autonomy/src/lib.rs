@@ -35,7 +35,7 @@ pub mod orchestration_entry;
 pub use decision::decisionmaking::DecisionInput;
 pub use decision::{DecisionDirector, DecisionVerdict};
 pub use linker::{AutonomyLinker, CycleReport};
-pub use master::{MasterController, MasterMetrics};
+pub use master::{DirectiveOutcome, DirectiveStatus, MasterController, MasterMetrics};
 pub use module::{
     AutonomyError, AutonomySignal, ControlDirective, DirectivePriority, ModuleBroker, ModuleKind,
     ModulePulse, ModuleRegistry, ModuleSpec, ModuleTarget, SignalScope,
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::HashSet, fmt};
 
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
@@ -9,6 +9,9 @@ use uuid::Uuid;
 
 use crate::helpermethod::IdeaTransformer;
 
+/// Namespace for content-derived idea identifiers.
+const IDEA_ID_NAMESPACE: Uuid = Uuid::from_u128(0x6a1f_3c2e_8d4b_4f0a_9e57_2b8c_d1e0_f3a4);
+
 /// Unique identifier assigned to each generated idea.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct CreativeIdeaId(Uuid);
@@ -19,6 +22,15 @@ impl CreativeIdeaId {
     pub fn new() -> Self {
         Self(Uuid::new_v4())
     }
+
+    /// Derives a stable identifier from the idea content and the constraints it was
+    /// shaped under, so identical ideas resolve to the same id across runs.
+    #[must_use]
+    pub fn from_content(title: &str, body: &str, constraints: &CreativeConstraint) -> Self {
+        let constraints = serde_json::to_string(constraints).unwrap_or_default();
+        let name = [title, body, constraints.as_str()].join("\u{1f}");
+        Self(Uuid::new_v5(&IDEA_ID_NAMESPACE, name.as_bytes()))
+    }
 }
 
 impl fmt::Display for CreativeIdeaId {
@@ -82,6 +94,89 @@ impl CreativeConstraint {
         }
         count
     }
+
+    /// Checks a finished idea against every active constraint.
+    #[must_use]
+    pub fn evaluate(&self, idea: &CreativeIdea) -> ConstraintReport {
+        let mut report = ConstraintReport {
+            idea_id: idea.id,
+            satisfied: Vec::new(),
+            violated: Vec::new(),
+            repaired: Vec::new(),
+        };
+        if let Some(max_length) = self.max_length {
+            report.record(
+                format!("max_length <= {max_length}"),
+                idea.body.len() <= max_length,
+            );
+        }
+        for keyword in &self.required_keywords {
+            report.record(format!("requires '{keyword}'"), idea.body.contains(keyword));
+        }
+        for avoid in &self.avoid {
+            report.record(format!("avoids '{avoid}'"), !idea.body.contains(avoid));
+        }
+        if let Some(audience) = &self.audience {
+            report.record(
+                format!("audience '{audience}'"),
+                idea.body.contains(audience),
+            );
+        }
+        report
+    }
+}
+
+/// Per-idea record of which constraints held and which were broken.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConstraintReport {
+    /// Idea the report describes.
+    pub idea_id: CreativeIdeaId,
+    /// Constraints the idea satisfied.
+    pub satisfied: Vec<String>,
+    /// Constraints the idea violated.
+    pub violated: Vec<String>,
+    /// Constraints the raw candidate violated that the engine's repairs fixed.
+    #[serde(default)]
+    pub repaired: Vec<String>,
+}
+
+impl ConstraintReport {
+    /// Returns true when no constraint was violated.
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.violated.is_empty()
+    }
+
+    /// Attributes the evaluation of a repaired idea back to its raw candidate.
+    ///
+    /// Constraints the candidate met on its own stay `satisfied`, those only met after
+    /// repair move to `repaired`, and anything the final idea breaks stays `violated`.
+    fn attribute_repairs(raw: &Self, mut repaired: Self) -> Self {
+        let (fixed, held) = repaired
+            .satisfied
+            .into_iter()
+            .partition(|constraint| raw.violated.contains(constraint));
+        repaired.satisfied = held;
+        repaired.repaired = fixed;
+        repaired
+    }
+
+    fn record(&mut self, constraint: String, held: bool) {
+        if held {
+            self.satisfied.push(constraint);
+        } else {
+            self.violated.push(constraint);
+        }
+    }
+}
+
+/// Idea dropped because it violated one or more constraints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectedIdea {
+    /// The rejected idea.
+    pub idea: CreativeIdea,
+    /// Constraint evaluation explaining the rejection.
+    pub report: ConstraintReport,
 }
 
 /// High-level brief for the creativity engine.
@@ -146,6 +241,9 @@ pub struct CreativeIdea {
     pub score: f32,
     /// Additional metadata for analytics.
     pub metadata: IndexMap<String, serde_json::Value>,
+    /// Names of transform stages applied to this idea, in order.
+    #[serde(default)]
+    pub lineage: Vec<String>,
     /// Creation timestamp.
     pub created_at: DateTime<Utc>,
 }
@@ -165,6 +263,7 @@ impl CreativeIdea {
             dialect,
             score: 0.0,
             metadata: IndexMap::new(),
+            lineage: Vec::new(),
             created_at: Utc::now(),
         }
     }
@@ -176,6 +275,13 @@ impl CreativeIdea {
         self
     }
 
+    /// Replaces the random id with one derived from title, body and `constraints`.
+    #[must_use]
+    pub fn with_content_id(mut self, constraints: &CreativeConstraint) -> Self {
+        self.id = CreativeIdeaId::from_content(&self.title, &self.body, constraints);
+        self
+    }
+
     /// Sets the score.
     #[must_use]
     pub fn with_score(mut self, score: f32) -> Self {
@@ -218,6 +324,69 @@ impl CreativePortfolio {
     pub fn iter(&self) -> impl Iterator<Item = &CreativeIdea> {
         self.ideas.iter()
     }
+
+    /// Compares this portfolio against a `previous` run, matching ideas by id.
+    ///
+    /// Ranks are positions in [`Self::ranked`]; ids are only comparable across runs
+    /// when both were ideated [with deterministic ids](IdeationEngine::with_deterministic_ids).
+    #[must_use]
+    pub fn diff(&self, previous: &Self) -> PortfolioDiff {
+        let ranks = |portfolio: &Self| -> IndexMap<CreativeIdeaId, usize> {
+            portfolio
+                .ranked()
+                .iter()
+                .enumerate()
+                .map(|(rank, idea)| (idea.id, rank))
+                .collect()
+        };
+        let current = ranks(self);
+        let prior = ranks(previous);
+
+        let mut diff = PortfolioDiff::default();
+        for (&id, &to) in &current {
+            match prior.get(&id) {
+                None => diff.added.push(id),
+                Some(&from) if from != to => diff.moved.push(RankChange { id, from, to }),
+                Some(_) => {}
+            }
+        }
+        diff.removed = prior
+            .keys()
+            .filter(|id| !current.contains_key(*id))
+            .copied()
+            .collect();
+        diff
+    }
+}
+
+/// Rank movement of an idea present in both compared portfolios.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RankChange {
+    /// Idea that moved.
+    pub id: CreativeIdeaId,
+    /// Rank in the previous portfolio (0 = best).
+    pub from: usize,
+    /// Rank in the current portfolio.
+    pub to: usize,
+}
+
+/// Differences between two portfolios, as produced by [`CreativePortfolio::diff`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortfolioDiff {
+    /// Ideas only present in the current portfolio, best ranked first.
+    pub added: Vec<CreativeIdeaId>,
+    /// Ideas only present in the previous portfolio, best ranked first.
+    pub removed: Vec<CreativeIdeaId>,
+    /// Ideas present in both whose rank changed.
+    pub moved: Vec<RankChange>,
+}
+
+impl PortfolioDiff {
+    /// Whether the portfolios hold the same ideas in the same order.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
 }
 
 impl From<Vec<CreativeIdea>> for CreativePortfolio {
@@ -246,6 +415,15 @@ pub struct IdeationOutcome {
     pub summary: String,
     /// Steps executed.
     pub steps: Vec<String>,
+    /// Number of near-duplicate ideas folded into a cluster representative.
+    #[serde(default)]
+    pub merged: usize,
+    /// Constraint evaluation for each idea in the portfolio.
+    #[serde(default)]
+    pub explanations: Vec<ConstraintReport>,
+    /// Ideas filtered out for violating constraints.
+    #[serde(default)]
+    pub rejected: Vec<RejectedIdea>,
 }
 
 /// Engine responsible for generating and transforming ideas.
@@ -253,13 +431,21 @@ pub struct IdeationOutcome {
 pub struct IdeationEngine {
     rng: SmallRng,
     transformer: IdeaTransformer,
+    diversity_threshold: Option<f32>,
+    deterministic_ids: bool,
 }
 
+/// Token-set similarity above which [`IdeationEngine::with_diversity`] treats two ideas
+/// as near-duplicates.
+pub const DEFAULT_DIVERSITY_THRESHOLD: f32 = 0.9;
+
 impl Default for IdeationEngine {
     fn default() -> Self {
         Self {
             rng: SmallRng::from_entropy(),
             transformer: IdeaTransformer::default(),
+            diversity_threshold: None,
+            deterministic_ids: false,
         }
     }
 }
@@ -271,9 +457,44 @@ impl IdeationEngine {
         Self {
             rng: SmallRng::seed_from_u64(seed),
             transformer: IdeaTransformer::default(),
+            diversity_threshold: None,
+            deterministic_ids: false,
         }
     }
 
+    /// Replaces the transformer (e.g. with a [`IdeaTransformer::pipeline`]).
+    #[must_use]
+    pub fn with_transformer(mut self, transformer: IdeaTransformer) -> Self {
+        self.transformer = transformer;
+        self
+    }
+
+    /// Collapses near-duplicate ideas using [`DEFAULT_DIVERSITY_THRESHOLD`].
+    ///
+    /// Off by default, so every constraint-satisfying idea reaches the portfolio.
+    #[must_use]
+    pub const fn with_diversity(self) -> Self {
+        self.with_diversity_threshold(DEFAULT_DIVERSITY_THRESHOLD)
+    }
+
+    /// Collapses ideas whose similarity (0-1) reaches `threshold`.
+    ///
+    /// Values of 1.0 or more disable the diversity pass.
+    #[must_use]
+    pub const fn with_diversity_threshold(mut self, threshold: f32) -> Self {
+        self.diversity_threshold = Some(threshold);
+        self
+    }
+
+    /// Assigns content-derived ids instead of random ones.
+    ///
+    /// Off by default so intentionally duplicated ideas stay distinguishable.
+    #[must_use]
+    pub const fn with_deterministic_ids(mut self) -> Self {
+        self.deterministic_ids = true;
+        self
+    }
+
     /// Generates ideas from the provided brief.
     pub fn ideate(&mut self, brief: &CreativeBrief) -> Result<IdeationOutcome, CreativityError> {
         if brief.title.trim().is_empty() || brief.objective.trim().is_empty() {
@@ -283,8 +504,10 @@ impl IdeationEngine {
         }
 
         let raw_ideas = self.generate_raw_ideas(brief);
-        let mut portfolio = CreativePortfolio::default();
+        let mut ideas = Vec::new();
         let mut steps = Vec::new();
+        let mut reports = IndexMap::new();
+        let mut rejected = Vec::new();
 
         for (idx, mut idea) in raw_ideas.into_iter().enumerate() {
             steps.push(format!(
@@ -292,12 +515,41 @@ impl IdeationEngine {
                 idx + 1,
                 idea.dialect.descriptor()
             ));
-            idea = self.apply_constraints(idea, &brief.constraints);
+            let raw_report = brief.constraints.evaluate(&idea);
             idea = self
                 .transformer
                 .polish(idea, brief.dialect.descriptor(), &brief.constraints);
-            portfolio.push(idea);
+            idea = self.transformer.apply(idea);
+            idea = self.apply_constraints(idea, &brief.constraints);
+            if self.deterministic_ids {
+                idea = idea.with_content_id(&brief.constraints);
+            }
+            let report =
+                ConstraintReport::attribute_repairs(&raw_report, brief.constraints.evaluate(&idea));
+            if report.passed() {
+                reports.insert(idea.id, report);
+                ideas.push(idea);
+            } else {
+                rejected.push(RejectedIdea { idea, report });
+            }
         }
+        if !rejected.is_empty() {
+            steps.push(format!(
+                "Rejected {} ideas violating constraints",
+                rejected.len()
+            ));
+        }
+
+        let before = ideas.len();
+        let portfolio = CreativePortfolio::from(self.diversify(ideas));
+        let merged = before - portfolio.len();
+        if merged > 0 {
+            steps.push(format!("Merged {merged} near-duplicate ideas"));
+        }
+        let explanations = portfolio
+            .iter()
+            .filter_map(|idea| reports.shift_remove(&idea.id))
+            .collect();
 
         let summary = format!(
             "Generated {} ideas for '{}' using {} dialect.",
@@ -310,9 +562,49 @@ impl IdeationEngine {
             portfolio,
             summary,
             steps,
+            merged,
+            explanations,
+            rejected,
         })
     }
 
+    /// Clusters ideas by token-set similarity, keeping the best-scored idea per cluster.
+    fn diversify(&self, mut ideas: Vec<CreativeIdea>) -> Vec<CreativeIdea> {
+        let Some(threshold) = self
+            .diversity_threshold
+            .filter(|threshold| *threshold < 1.0)
+        else {
+            return ideas;
+        };
+        ideas.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut representatives: Vec<(CreativeIdea, HashSet<String>, usize)> = Vec::new();
+        for idea in ideas {
+            let tokens = token_set(&idea);
+            if let Some(cluster) = representatives
+                .iter_mut()
+                .find(|(_, rep_tokens, _)| jaccard(rep_tokens, &tokens) >= threshold)
+            {
+                cluster.2 += 1;
+                continue;
+            }
+            representatives.push((idea, tokens, 1));
+        }
+        representatives
+            .into_iter()
+            .map(|(idea, _, size)| {
+                if size > 1 {
+                    idea.with_metadata("cluster_size", serde_json::json!(size))
+                } else {
+                    idea
+                }
+            })
+            .collect()
+    }
+
     fn generate_raw_ideas(&mut self, brief: &CreativeBrief) -> Vec<CreativeIdea> {
         let mut ideas = Vec::new();
         let seeds = if brief.seed_ideas.is_empty() {
@@ -356,33 +648,97 @@ impl IdeationEngine {
         ideas
     }
 
+    /// Repairs an idea so it satisfies `constraints` where possible.
+    ///
+    /// Anchors for required keywords and the audience are reserved before truncating, so
+    /// the repaired body still fits `max_length`.
     fn apply_constraints(
         &self,
         mut idea: CreativeIdea,
         constraints: &CreativeConstraint,
     ) -> CreativeIdea {
-        if let Some(max_length) = constraints.max_length {
-            if idea.body.len() > max_length {
-                idea.body.truncate(max_length);
+        for avoid in &constraints.avoid {
+            if idea.body.contains(avoid) {
+                idea.body = idea.body.replace(avoid, "[redacted]");
             }
         }
 
-        for keyword in &constraints.required_keywords {
-            if !idea.body.contains(keyword) {
-                idea.body.push_str(&format!("\nKey anchor: {}", keyword));
-            }
-        }
+        let anchors: Vec<(&str, String)> = constraints
+            .required_keywords
+            .iter()
+            .map(|keyword| (keyword.as_str(), format!("\nKey anchor: {keyword}")))
+            .chain(constraints.audience.iter().map(|audience| {
+                (
+                    audience.as_str(),
+                    format!("\nAudience resonance: {audience}"),
+                )
+            }))
+            .collect();
+        let missing_in = |body: &str| {
+            anchors
+                .iter()
+                .filter(|(needle, _)| !body.contains(needle))
+                .map(|(_, line)| line.as_str())
+                .collect::<Vec<_>>()
+        };
+        let mut missing = missing_in(&idea.body);
 
-        for avoid in &constraints.avoid {
-            if idea.body.contains(avoid) {
-                idea.body = idea.body.replace(avoid, "[redacted]");
+        if let Some(max_length) = constraints.max_length {
+            // Truncation can cut anchors the body already had, so grow the reservation
+            // until the kept prefix and the anchors it needs agree.
+            loop {
+                let reserved: usize = missing.iter().map(|line| line.len()).sum();
+                let cut = floor_char_boundary(&idea.body, max_length.saturating_sub(reserved));
+                if cut >= idea.body.len() {
+                    break;
+                }
+                let still_missing = missing_in(&idea.body[..cut]);
+                if still_missing.len() == missing.len() {
+                    idea.body.truncate(cut);
+                    break;
+                }
+                missing = still_missing;
             }
         }
 
+        idea.body.push_str(&missing.concat());
         idea
     }
 }
 
+/// Largest char boundary in `text` at or below `index`.
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    if index >= text.len() {
+        return text.len();
+    }
+    (0..=index)
+        .rev()
+        .find(|&idx| text.is_char_boundary(idx))
+        .unwrap_or(0)
+}
+
+fn token_set(idea: &CreativeIdea) -> HashSet<String> {
+    idea.title
+        .split_whitespace()
+        .chain(idea.body.split_whitespace())
+        .map(|token| {
+            token
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -410,4 +766,186 @@ mod tests {
         let outcome = engine.ideate(&brief).unwrap();
         assert!(outcome.portfolio.ranked()[0].body.len() <= 40);
     }
+
+    #[test]
+    fn constraint_outcomes_are_explained() {
+        let mut engine = IdeationEngine::seeded(11);
+        let brief = CreativeBrief::new("Nova", "Reframe finance", CreativityDialect::Analytical)
+            .with_seed("Launch solar co-ops")
+            .with_seed("Plant rooftop gardens")
+            .with_constraint(CreativeConstraint {
+                max_length: Some(40),
+                required_keywords: vec!["solar".into()],
+                ..CreativeConstraint::default()
+            });
+        let outcome = engine.ideate(&brief).unwrap();
+
+        assert_eq!(outcome.portfolio.len(), 2);
+        assert!(outcome.rejected.is_empty());
+        for idea in outcome.portfolio.iter() {
+            assert!(idea.body.len() <= 40);
+            assert!(idea.body.contains("solar"));
+        }
+        let explanation = |prefix: &str| {
+            let idea = outcome
+                .portfolio
+                .iter()
+                .find(|idea| idea.body.starts_with(prefix))
+                .unwrap();
+            outcome
+                .explanations
+                .iter()
+                .find(|report| report.idea_id == idea.id)
+                .unwrap()
+        };
+        let solar = explanation("Launch solar co-ops");
+        assert_eq!(solar.satisfied, vec!["requires 'solar'"]);
+        assert_eq!(solar.repaired, vec!["max_length <= 40"]);
+        let gardens = explanation("Plant rooftop");
+        assert!(gardens.satisfied.is_empty());
+        assert_eq!(
+            gardens.repaired,
+            vec!["max_length <= 40", "requires 'solar'"]
+        );
+    }
+
+    #[test]
+    fn repair_reserves_room_for_audience_and_multibyte_text() {
+        let mut engine = IdeationEngine::seeded(3);
+        let brief = CreativeBrief::new(
+            "Nova",
+            "Réinventer la finance",
+            CreativityDialect::Analytical,
+        )
+        .with_seed("Énergie solaire partagée — coopératives")
+        .with_constraint(CreativeConstraint {
+            max_length: Some(70),
+            required_keywords: vec!["solaire".into()],
+            audience: Some("citoyens".into()),
+            ..CreativeConstraint::default()
+        });
+        let outcome = engine.ideate(&brief).unwrap();
+
+        assert_eq!(outcome.portfolio.len(), 1);
+        let idea = outcome.portfolio.iter().next().unwrap();
+        assert!(idea.body.len() <= 70);
+        assert!(idea.body.contains("citoyens"));
+    }
+
+    #[test]
+    fn unrepairable_ideas_are_rejected() {
+        let mut engine = IdeationEngine::seeded(11);
+        let brief = CreativeBrief::new("Nova", "Reframe finance", CreativityDialect::Analytical)
+            .with_seed("Plant rooftop gardens")
+            .with_constraint(CreativeConstraint {
+                max_length: Some(10),
+                required_keywords: vec!["solar".into()],
+                ..CreativeConstraint::default()
+            });
+        let outcome = engine.ideate(&brief).unwrap();
+
+        assert_eq!(outcome.portfolio.len(), 0);
+        assert_eq!(outcome.rejected.len(), 1);
+        let rejected = &outcome.rejected[0];
+        assert!(rejected.report.satisfied.is_empty());
+        assert_eq!(rejected.report.repaired, vec!["requires 'solar'"]);
+        assert_eq!(rejected.report.violated, vec!["max_length <= 10"]);
+    }
+
+    #[test]
+    fn identical_ideas_share_deterministic_id() {
+        let constraints = CreativeConstraint {
+            max_length: Some(80),
+            ..CreativeConstraint::default()
+        };
+        let idea = || CreativeIdea::new("Tide", "Harvest wave power", CreativityDialect::Poetic);
+        assert_ne!(idea().id, idea().id);
+        assert_eq!(
+            idea().with_content_id(&constraints).id,
+            idea().with_content_id(&constraints).id
+        );
+        assert_ne!(
+            idea().with_content_id(&constraints).id,
+            idea().with_content_id(&CreativeConstraint::default()).id
+        );
+
+        let brief = CreativeBrief::new("Tide", "Reimagine energy", CreativityDialect::Poetic)
+            .with_seed("Harvest wave power");
+        let run = || {
+            IdeationEngine::seeded(5)
+                .with_deterministic_ids()
+                .ideate(&brief)
+                .unwrap()
+                .portfolio
+                .iter()
+                .map(|idea| idea.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn portfolio_diff_categorizes_changes() {
+        let constraints = CreativeConstraint::default();
+        let idea = |title: &str, score: f32| {
+            CreativeIdea::new(title, "Harvest wave power", CreativityDialect::Poetic)
+                .with_content_id(&constraints)
+                .with_score(score)
+        };
+        let previous = CreativePortfolio::from(vec![
+            idea("Tide", 0.9),
+            idea("Reef", 0.6),
+            idea("Kelp", 0.3),
+        ]);
+        let current = CreativePortfolio::from(vec![
+            idea("Tide", 0.5),
+            idea("Reef", 0.8),
+            idea("Gull", 0.7),
+        ]);
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.added, vec![idea("Gull", 0.0).id]);
+        assert_eq!(diff.removed, vec![idea("Kelp", 0.0).id]);
+        assert_eq!(
+            diff.moved,
+            vec![
+                RankChange {
+                    id: idea("Reef", 0.0).id,
+                    from: 1,
+                    to: 0
+                },
+                RankChange {
+                    id: idea("Tide", 0.0).id,
+                    from: 0,
+                    to: 2
+                },
+            ]
+        );
+        assert!(previous.diff(&previous).is_empty());
+    }
+
+    #[test]
+    fn diversity_pass_collapses_near_duplicates() {
+        let brief = CreativeBrief::new(
+            "Aurora Grid",
+            "Inspire climate action",
+            CreativityDialect::Experimental,
+        )
+        .with_seed("Design a lunar-powered community ritual")
+        .with_seed("Design a lunar-powered community ritual")
+        .with_seed("Design a lunar powered community ritual!")
+        .with_seed("Map every rooftop garden into a citizen-run carbon ledger");
+        let untouched = IdeationEngine::seeded(42).ideate(&brief).unwrap();
+        assert_eq!(untouched.portfolio.len(), 4);
+        assert_eq!(untouched.merged, 0);
+
+        let mut engine = IdeationEngine::seeded(42).with_diversity_threshold(0.6);
+        let outcome = engine.ideate(&brief).unwrap();
+        assert_eq!(outcome.portfolio.len(), 2);
+        assert_eq!(outcome.merged, 2);
+        assert!(outcome
+            .portfolio
+            .iter()
+            .any(|idea| idea.metadata.get("cluster_size") == Some(&serde_json::json!(3))));
+    }
 }
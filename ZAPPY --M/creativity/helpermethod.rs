@@ -1,24 +1,108 @@
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::create::{CreativeConstraint, CreativeIdea};
 
-/// Utility that polishes and transforms ideas.
+/// Single stage in an [`IdeaTransformer`] pipeline.
+pub trait IdeaTransform: Send + Sync {
+    /// Stage name recorded in the idea lineage.
+    fn name(&self) -> &str;
+    /// Transforms the idea, feeding the next stage.
+    fn transform(&self, idea: CreativeIdea) -> CreativeIdea;
+}
+
+/// Amplifies an idea by appending a bolder call to action.
+#[derive(Debug, Clone)]
+pub struct AmplifyTransform {
+    /// Phrase appended to the body.
+    pub emphasis: String,
+}
+
+impl IdeaTransform for AmplifyTransform {
+    fn name(&self) -> &'static str {
+        "amplify"
+    }
+
+    fn transform(&self, mut idea: CreativeIdea) -> CreativeIdea {
+        idea.body.push_str("\nAmplified: ");
+        idea.body.push_str(&self.emphasis);
+        idea
+    }
+}
+
+/// Reframes an idea through a named lens.
 #[derive(Debug, Clone)]
+pub struct ReframeTransform {
+    /// Lens the idea is viewed through.
+    pub lens: String,
+}
+
+impl IdeaTransform for ReframeTransform {
+    fn name(&self) -> &'static str {
+        "reframe"
+    }
+
+    fn transform(&self, mut idea: CreativeIdea) -> CreativeIdea {
+        idea.title = format!("{} ({} lens)", idea.title, self.lens);
+        idea
+    }
+}
+
+/// Utility that polishes and transforms ideas.
+#[derive(Clone)]
 pub struct IdeaTransformer {
     emphasis_regex: Regex,
+    stages: Vec<Arc<dyn IdeaTransform>>,
+}
+
+impl std::fmt::Debug for IdeaTransformer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IdeaTransformer")
+            .field("emphasis_regex", &self.emphasis_regex.as_str())
+            .field(
+                "stages",
+                &self
+                    .stages
+                    .iter()
+                    .map(|stage| stage.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
 }
 
 impl Default for IdeaTransformer {
     fn default() -> Self {
         Self {
             emphasis_regex: Regex::new(r"(?i)innovation").unwrap(),
+            stages: Vec::new(),
         }
     }
 }
 
 impl IdeaTransformer {
+    /// Builds a transformer that runs the given stages in order after polishing.
+    #[must_use]
+    pub fn pipeline(stages: Vec<Box<dyn IdeaTransform>>) -> Self {
+        Self {
+            stages: stages.into_iter().map(Arc::from).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Runs every pipeline stage in order, recording each in the idea lineage.
+    #[must_use]
+    pub fn apply(&self, mut idea: CreativeIdea) -> CreativeIdea {
+        for stage in &self.stages {
+            idea = stage.transform(idea);
+            idea.lineage.push(stage.name().to_string());
+        }
+        idea
+    }
+
     /// Applies light-weight polishing to align with the requested dialect.
     #[must_use]
     pub fn polish(
@@ -98,6 +182,23 @@ mod tests {
         assert!(polished.metadata.contains_key("dialect"));
     }
 
+    #[test]
+    fn pipeline_applies_stages_in_order() {
+        let transformer = IdeaTransformer::pipeline(vec![
+            Box::new(ReframeTransform {
+                lens: "civic".into(),
+            }),
+            Box::new(AmplifyTransform {
+                emphasis: "launch city-wide".into(),
+            }),
+        ]);
+        let idea = CreativeIdea::new("Grid", "solar commons", CreativityDialect::Analytical);
+        let result = transformer.apply(idea);
+        assert_eq!(result.lineage, vec!["reframe", "amplify"]);
+        assert_eq!(result.title, "Grid (civic lens)");
+        assert!(result.body.ends_with("Amplified: launch city-wide"));
+    }
+
     #[test]
     fn narrative_weaver_threads_titles() {
         let ideas = vec![CreativeIdea::new(
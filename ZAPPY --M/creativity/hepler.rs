@@ -1,9 +1,23 @@
 use std::sync::Arc;
 
+use anyhow::Result;
+use async_trait::async_trait;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{seq::SliceRandom, thread_rng};
 
+use crate::create::CreativeBrief;
+
+/// Asynchronous provider of inspiration (e.g. fetched over the network) used to enrich
+/// briefs before ideation.
+#[async_trait]
+pub trait InspirationSource: Send + Sync {
+    /// Source name used in telemetry.
+    fn name(&self) -> &str;
+    /// Returns inspiration snippets for `brief`; each becomes an additional seed idea.
+    async fn fetch(&self, brief: &CreativeBrief) -> Result<Vec<String>>;
+}
+
 /// Caches inspiration snippets gathered from previous sessions.
 #[derive(Debug, Default, Clone)]
 pub struct InspirationCache {
@@ -1,11 +1,13 @@
 //! High-level orchestration utilities for the creativity stack.
 
+use std::{fmt, sync::Arc};
+
 use anyhow::Result;
 
 use crate::{
-    create::{CreativeBrief, CreativePortfolio, CreativityDialect, IdeationEngine},
+    create::{CreativeBrief, CreativeIdea, CreativePortfolio, CreativityDialect, IdeationEngine},
     helpermethod::NarrativeWeaver,
-    hepler::InspirationCache,
+    hepler::{InspirationCache, InspirationSource},
     reviewer::CreativeReviewBoard,
     telemetry::CreativityTelemetry,
 };
@@ -13,13 +15,29 @@ use serde_json::json;
 use shared_logging::LogLevel;
 
 /// Runtime that owns stateful engines used for creativity workflows.
-#[derive(Debug)]
 pub struct CreativityRuntime {
     ideation: IdeationEngine,
     weaver: NarrativeWeaver,
     cache: InspirationCache,
     reviewers: CreativeReviewBoard,
     telemetry: Option<CreativityTelemetry>,
+    inspiration: Option<Arc<dyn InspirationSource>>,
+}
+
+impl fmt::Debug for CreativityRuntime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreativityRuntime")
+            .field("ideation", &self.ideation)
+            .field("weaver", &self.weaver)
+            .field("cache", &self.cache)
+            .field("reviewers", &self.reviewers)
+            .field("telemetry", &self.telemetry)
+            .field(
+                "inspiration",
+                &self.inspiration.as_ref().map(|source| source.name()),
+            )
+            .finish()
+    }
 }
 
 impl Default for CreativityRuntime {
@@ -30,13 +48,22 @@ impl Default for CreativityRuntime {
             cache: InspirationCache::default(),
             reviewers: CreativeReviewBoard::default(),
             telemetry: None,
+            inspiration: None,
         }
     }
 }
 
 impl CreativityRuntime {
     /// Executes the full pipeline for the supplied brief.
-    pub fn execute(&mut self, mut brief: CreativeBrief) -> Result<CreativePortfolio> {
+    pub fn execute(&mut self, brief: CreativeBrief) -> Result<CreativePortfolio> {
+        let (title, ranked) = self.ideate(brief)?;
+        let reviewed = self.reviewers.evaluate(ranked);
+        self.record_review(&title, &reviewed);
+        Ok(reviewed)
+    }
+
+    /// Runs ideation and narrative weaving, returning the brief title and ranked ideas.
+    fn ideate(&mut self, mut brief: CreativeBrief) -> Result<(String, Vec<CreativeIdea>)> {
         let brief_title = brief.title.clone();
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
@@ -71,22 +98,71 @@ impl CreativityRuntime {
             .weaver
             .weave(&outcome.portfolio.ranked(), brief.title.clone());
         self.cache.push(arc.title);
+        Ok((brief.title, outcome.portfolio.ranked()))
+    }
 
-        let reviewed = self.reviewers.evaluate(outcome.portfolio.ranked());
+    fn record_review(&self, title: &str, reviewed: &CreativePortfolio) {
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 LogLevel::Info,
                 "creativity.review.completed",
-                json!({ "ideas": reviewed.len(), "title": brief.title }),
+                json!({ "ideas": reviewed.len(), "title": title }),
             );
             let _ = tel.event(
                 "creativity.portfolio.completed",
-                json!({ "title": brief.title, "ideas": reviewed.len() }),
+                json!({ "title": title, "ideas": reviewed.len() }),
             );
         }
+    }
+
+    /// Enriches the brief from the configured [`InspirationSource`], then runs the
+    /// synchronous ideation core and reviews on the caller's runtime.
+    ///
+    /// A failing source is logged and skipped; the brief is executed as given.
+    ///
+    /// # Errors
+    ///
+    /// Returns any ideation error from the enriched brief; inspiration failures never
+    /// surface here.
+    pub async fn execute_async(&mut self, mut brief: CreativeBrief) -> Result<CreativePortfolio> {
+        if let Some(source) = self.inspiration.clone() {
+            match source.fetch(&brief).await {
+                Ok(snippets) => {
+                    if let Some(tel) = &self.telemetry {
+                        let _ = tel.log(
+                            LogLevel::Info,
+                            "creativity.inspiration.fetched",
+                            json!({ "source": source.name(), "snippets": snippets.len() }),
+                        );
+                    }
+                    for snippet in snippets {
+                        brief = brief.with_seed(snippet);
+                    }
+                }
+                Err(err) => {
+                    if let Some(tel) = &self.telemetry {
+                        let _ = tel.log(
+                            LogLevel::Warn,
+                            "creativity.inspiration.failed",
+                            json!({ "source": source.name(), "error": err.to_string() }),
+                        );
+                    }
+                }
+            }
+        }
+        let (title, ranked) = self.ideate(brief)?;
+        let reviewed = self.reviewers.evaluate_async(ranked).await;
+        self.record_review(&title, &reviewed);
         Ok(reviewed)
     }
 
+    /// Fetches inspiration from `source` in [`Self::execute_async`].
+    #[must_use]
+    pub fn with_inspiration_source(mut self, source: Arc<dyn InspirationSource>) -> Self {
+        self.inspiration = Some(source);
+        self
+    }
+
     /// Attaches telemetry sinks for observability.
     #[must_use]
     pub fn with_telemetry(mut self, telemetry: CreativityTelemetry) -> Self {
@@ -127,4 +203,34 @@ mod tests {
         let portfolio = sample_run().unwrap();
         assert!(portfolio.len() > 0);
     }
+
+    struct TideArchive;
+
+    #[async_trait::async_trait]
+    impl InspirationSource for TideArchive {
+        fn name(&self) -> &'static str {
+            "tide-archive"
+        }
+
+        async fn fetch(&self, brief: &CreativeBrief) -> Result<Vec<String>> {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            Ok(vec![format!("Tidal choir archive for {}", brief.title)])
+        }
+    }
+
+    #[tokio::test]
+    async fn fetched_inspiration_seeds_the_portfolio() {
+        let mut runtime =
+            CreativityRuntime::default().with_inspiration_source(Arc::new(TideArchive));
+        let brief = CreativeBrief::new(
+            "Harbor Nights",
+            "Reimagine evening ferry rides",
+            CreativityDialect::Poetic,
+        );
+        let portfolio = runtime.execute_async(brief).await.unwrap();
+        assert!(portfolio
+            .ranked()
+            .iter()
+            .any(|idea| idea.body.contains("Tidal choir archive for Harbor Nights")));
+    }
 }
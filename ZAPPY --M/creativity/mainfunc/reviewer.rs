@@ -67,10 +67,10 @@ impl CreativeReviewer for ImpactReviewer {
     }
 }
 
-/// Aggregates reviewers and applies averaged scores.
+/// Aggregates reviewers and applies weighted composite scores.
 #[derive(Clone)]
 pub struct CreativeReviewBoard {
-    reviewers: Vec<Arc<dyn CreativeReviewer>>,
+    reviewers: Vec<(Arc<dyn CreativeReviewer>, f32)>,
 }
 
 impl std::fmt::Debug for CreativeReviewBoard {
@@ -84,15 +84,37 @@ impl std::fmt::Debug for CreativeReviewBoard {
 impl Default for CreativeReviewBoard {
     fn default() -> Self {
         Self {
-            reviewers: vec![Arc::new(OriginalityReviewer), Arc::new(ImpactReviewer)],
+            reviewers: vec![
+                (Arc::new(OriginalityReviewer), 1.0),
+                (Arc::new(ImpactReviewer), 1.0),
+            ],
         }
     }
 }
 
 impl CreativeReviewBoard {
-    /// Adds a reviewer.
-    pub fn with_reviewer(mut self, reviewer: Arc<dyn CreativeReviewer>) -> Self {
-        self.reviewers.push(reviewer);
+    /// Creates a board without any reviewers.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            reviewers: Vec::new(),
+        }
+    }
+
+    /// Adds a reviewer with unit weight.
+    #[must_use]
+    pub fn with_reviewer(self, reviewer: Arc<dyn CreativeReviewer>) -> Self {
+        self.with_weighted_reviewer(reviewer, 1.0)
+    }
+
+    /// Adds a reviewer whose score contributes proportionally to `weight`.
+    #[must_use]
+    pub fn with_weighted_reviewer(
+        mut self,
+        reviewer: Arc<dyn CreativeReviewer>,
+        weight: f32,
+    ) -> Self {
+        self.reviewers.push((reviewer, weight.max(0.0)));
         self
     }
 
@@ -105,23 +127,39 @@ impl CreativeReviewBoard {
             .block_on(self.evaluate_async(ideas))
     }
 
-    async fn evaluate_async(&self, ideas: Vec<CreativeIdea>) -> CreativePortfolio {
+    /// Evaluates ranked ideas on the caller's async runtime.
+    pub async fn evaluate_async(&self, ideas: Vec<CreativeIdea>) -> CreativePortfolio {
         let mut portfolio = CreativePortfolio::default();
+        let total_weight: f32 = self.reviewers.iter().map(|(_, weight)| weight).sum();
         for mut idea in ideas {
-            let mut total = 0.0;
-            for reviewer in &self.reviewers {
+            let mut weighted = 0.0;
+            let mut breakdown = serde_json::Map::new();
+            for (reviewer, weight) in &self.reviewers {
                 let finding = reviewer.evaluate(&idea).await;
-                total += finding.score;
+                weighted += finding.score * weight;
+                breakdown.insert(
+                    finding.reviewer.clone(),
+                    json!({ "score": finding.score, "weight": weight }),
+                );
                 idea = idea.with_metadata(
                     format!("review:{}", finding.reviewer),
                     json!({
                         "score": finding.score,
+                        "weight": weight,
                         "notes": finding.notes
                     }),
                 );
             }
-            let avg = total / self.reviewers.len() as f32;
-            portfolio.push(idea.with_score(avg));
+            let composite = if total_weight > 0.0 {
+                weighted / total_weight
+            } else {
+                0.0
+            };
+            idea = idea.with_metadata(
+                "review_breakdown",
+                json!({ "composite": composite, "reviewers": breakdown }),
+            );
+            portfolio.push(idea.with_score(composite));
         }
         portfolio
     }
@@ -144,4 +182,36 @@ mod tests {
         assert_eq!(portfolio.len(), 1);
         assert!(portfolio.ranked()[0].score > 0.0);
     }
+
+    struct FixedReviewer(&'static str, f32);
+
+    #[async_trait]
+    impl CreativeReviewer for FixedReviewer {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn evaluate(&self, _idea: &CreativeIdea) -> ReviewFinding {
+            ReviewFinding {
+                reviewer: self.0.into(),
+                score: self.1,
+                notes: "fixed".into(),
+            }
+        }
+    }
+
+    #[test]
+    fn weighted_board_computes_composite() {
+        let board = CreativeReviewBoard::empty()
+            .with_weighted_reviewer(Arc::new(FixedReviewer("novelty", 0.9)), 3.0)
+            .with_weighted_reviewer(Arc::new(FixedReviewer("feasibility", 0.3)), 1.0);
+        let idea = CreativeIdea::new("title", "body", CreativityDialect::Analytical);
+        let reviewed = board.evaluate(vec![idea]).ranked().remove(0);
+
+        let expected = 0.9_f32.mul_add(3.0, 0.3) / 4.0;
+        assert!((reviewed.score - expected).abs() < 1e-6);
+        let breakdown = &reviewed.metadata["review_breakdown"]["reviewers"];
+        assert_eq!(breakdown["novelty"]["weight"], json!(3.0));
+        assert_eq!(breakdown["feasibility"]["weight"], json!(1.0));
+    }
 }
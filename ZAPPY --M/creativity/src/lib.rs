@@ -45,9 +45,13 @@ pub mod reviewer;
 pub mod telemetry;
 
 pub use create::{
-    CreativeBrief, CreativeConstraint, CreativeIdea, CreativeIdeaId, CreativePortfolio,
-    CreativityDialect, IdeationEngine, IdeationOutcome,
+    ConstraintReport, CreativeBrief, CreativeConstraint, CreativeIdea, CreativeIdeaId,
+    CreativePortfolio, CreativityDialect, IdeationEngine, IdeationOutcome, PortfolioDiff,
+    RankChange, RejectedIdea, DEFAULT_DIVERSITY_THRESHOLD,
 };
-pub use helpermethod::{IdeaTransformer, NarrativeWeaver};
+pub use helpermethod::{
+    AmplifyTransform, IdeaTransform, IdeaTransformer, NarrativeWeaver, ReframeTransform,
+};
+pub use hepler::{InspirationCache, InspirationSource};
 pub use mainfunc::CreativityKernel;
 pub use telemetry::{CreativityTelemetry, CreativityTelemetryBuilder};
@@ -145,6 +145,7 @@ impl CreativityTelemetry {
                 event_type: event_type.into(),
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 payload,
+                sequence: 0,
             };
             handle.publish(record)?;
         }
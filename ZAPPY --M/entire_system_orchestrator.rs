@@ -54,6 +54,10 @@ use zappy_simulationengine::{
 };
 use zappy_world::{AssimilationJob, WorldRuntime};
 
+mod workflow_orchestrator;
+
+use workflow_orchestrator::WorkflowOrchestrator;
+
 /// High-level orchestrator controlling every major runtime.
 pub struct EntireSystemOrchestrator {
     planning: PlanningRuntime,
@@ -197,6 +201,7 @@ impl EntireSystemOrchestrator {
                 "simulate" => self.handle_simulation(args).await?,
                 "upgrade" => self.handle_upgrade(args).await?,
                 "reflect" => self.handle_reflect(args).await?,
+                "workflow" => self.handle_workflow(args).await?,
                 "execute" => self.handle_execute(args).await?,
                 "autonomy" => self.handle_autonomy(args).await?,
                 "knowledge" => self.handle_knowledge(args).await?,
@@ -242,6 +247,30 @@ impl EntireSystemOrchestrator {
         Ok(())
     }
 
+    async fn handle_workflow(&mut self, goal: &str) -> Result<()> {
+        if goal.is_empty() {
+            println!("Usage: workflow <goal>");
+            return Ok(());
+        }
+        let report = WorkflowOrchestrator::new(
+            &self.knowledge,
+            &self.reasoning,
+            &mut self.planning,
+            &self.actions,
+            &self.experience_hub,
+        )
+        .run(goal)
+        .await?;
+        println!(
+            "Workflow {} submitted {} actions ({} succeeded) for {}.",
+            report.trace_id,
+            report.submitted.len(),
+            report.outcomes.iter().filter(|o| o.is_success()).count(),
+            report.objective.as_deref().unwrap_or("no plan")
+        );
+        Ok(())
+    }
+
     async fn handle_schedule(&mut self) -> Result<()> {
         if let Some(plan) = &self.last_plan {
             let schedule = self.planning.build_tactical_schedule(plan)?;
@@ -358,6 +387,7 @@ impl EntireSystemOrchestrator {
   knowledge ...       - Knowledge ops (sync/search/ingest)
   natural <text>      - Natural language to IR classification
   reflect <text>      - Trigger metacognition reflection
+  workflow <goal>     - Run knowledge -> reasoning -> planning -> actions
   experience [n]      - Show latest ExperienceHub entries
   policy              - Print active security/ops policies
   status              - Print orchestrator state
@@ -0,0 +1,207 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Elements removed with their content. The regex crate has no backreferences, so each
+/// tag gets its own alternative to make sure a block only ends at its own closing tag.
+const DROPPED_TAGS: [&str; 8] = [
+    "script", "style", "head", "nav", "header", "footer", "aside", "noscript",
+];
+
+static DROPPED_BLOCKS: LazyLock<Regex> = LazyLock::new(|| {
+    let blocks: Vec<String> = DROPPED_TAGS
+        .iter()
+        .map(|tag| format!(r"<{tag}\b.*?</\s*{tag}\s*>"))
+        .collect();
+    Regex::new(&format!(r"(?is)<!--.*?-->|{}", blocks.join("|"))).unwrap()
+});
+static MAIN_BLOCK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?is)<main\b[^>]*>(.*?)</\s*main\s*>|<article\b[^>]*>(.*?)</\s*article\s*>")
+        .unwrap()
+});
+static BLOCK_BREAKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)<\s*(br|/?p|/?div|/?li|/?ul|/?ol|/?tr|/?td|/?th|/?h[1-6]|/?section|/?blockquote|/?pre)\b[^>]*>",
+    )
+    .unwrap()
+});
+static TAGS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+static HTML_SNIFF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^\s*(<!doctype html|<html\b)").unwrap());
+static MARKDOWN_SNIFF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^(#{1,6} |[-*+] |\d+\. |```|> )").unwrap());
+
+/// Format of an artifact body, used to normalize it before storage.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    /// Unstructured text, stored as received.
+    PlainText,
+    /// HTML markup; tags, scripts, and page chrome are stripped.
+    Html,
+    /// Markdown; line endings and blank-line runs are normalized, line content is kept.
+    Markdown,
+}
+
+impl ContentType {
+    /// Maps a MIME type (parameters such as `charset` are ignored) or short label.
+    #[must_use]
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        let essence = mime.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "text/html" | "application/xhtml+xml" | "html" => Some(Self::Html),
+            "text/markdown" | "text/x-markdown" | "markdown" | "md" => Some(Self::Markdown),
+            "text/plain" | "plain" | "text" => Some(Self::PlainText),
+            _ => None,
+        }
+    }
+
+    /// Guesses the content type from the body itself.
+    ///
+    /// Only documents starting with a doctype or `<html>` are treated as HTML; fragments
+    /// need a declared type so prose quoting markup is not stripped.
+    #[must_use]
+    pub fn sniff(body: &str) -> Self {
+        if HTML_SNIFF.is_match(body) {
+            Self::Html
+        } else if MARKDOWN_SNIFF.is_match(body) {
+            Self::Markdown
+        } else {
+            Self::PlainText
+        }
+    }
+
+    /// Uses the declared MIME type when recognized, otherwise sniffs the body.
+    #[must_use]
+    pub fn detect(declared: Option<&str>, body: &str) -> Self {
+        declared
+            .and_then(Self::from_mime)
+            .unwrap_or_else(|| Self::sniff(body))
+    }
+
+    /// Short label recorded in record metadata.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::PlainText => "text/plain",
+            Self::Html => "text/html",
+            Self::Markdown => "text/markdown",
+        }
+    }
+
+    /// Converts a raw body into the text stored on the knowledge record.
+    #[must_use]
+    pub fn parse(self, raw: &str) -> String {
+        match self {
+            Self::PlainText => raw.to_string(),
+            Self::Html => html_to_text(raw),
+            Self::Markdown => normalize_markdown(raw),
+        }
+    }
+}
+
+/// Extracts readable text from HTML, preferring `<main>`/`<article>` content when present.
+fn html_to_text(raw: &str) -> String {
+    let cleaned = DROPPED_BLOCKS.replace_all(raw, " ");
+    let main = MAIN_BLOCK
+        .captures(&cleaned)
+        .and_then(|captures| captures.get(1).or_else(|| captures.get(2)))
+        .map_or_else(|| cleaned.as_ref(), |inner| inner.as_str());
+    let broken = BLOCK_BREAKS.replace_all(main, "\n");
+    let text = decode_entities(&TAGS.replace_all(&broken, ""));
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Normalizes line endings and runs of blank lines, dropping leading and trailing blank
+/// lines. Indentation and hard line breaks are significant, so line content is kept.
+fn normalize_markdown(raw: &str) -> String {
+    let mut out = Vec::new();
+    let mut blank_run = 0;
+    for line in raw.lines() {
+        if line.trim().is_empty() {
+            if out.is_empty() {
+                continue;
+            }
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        out.push(line);
+    }
+    while out.last().is_some_and(|line| line.trim().is_empty()) {
+        out.pop();
+    }
+    out.join("\n")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_declared_and_sniffed_types() {
+        assert_eq!(
+            ContentType::from_mime("text/html; charset=utf-8"),
+            Some(ContentType::Html)
+        );
+        assert_eq!(
+            ContentType::detect(None, "# Title\n\n- item"),
+            ContentType::Markdown
+        );
+        assert_eq!(
+            ContentType::detect(Some("text/plain"), "<p>literal</p>"),
+            ContentType::PlainText
+        );
+    }
+
+    #[test]
+    fn markdown_keeps_structure() {
+        let parsed = ContentType::Markdown.parse("# Title\r\n\r\n\r\n- one\r\n- two\r\n");
+        assert_eq!(parsed, "# Title\n\n- one\n- two");
+    }
+
+    #[test]
+    fn markdown_keeps_indentation_and_hard_breaks() {
+        let raw = "\n    let x = 1;\n\nfirst line  \nsecond line\n\n";
+        assert_eq!(
+            ContentType::Markdown.parse(raw),
+            "    let x = 1;\n\nfirst line  \nsecond line"
+        );
+    }
+
+    #[test]
+    fn prose_mentioning_tags_is_not_sniffed_as_html() {
+        assert_eq!(
+            ContentType::sniff("Wrap each paragraph in a <p> element."),
+            ContentType::PlainText
+        );
+        assert_eq!(
+            ContentType::sniff("  <!DOCTYPE html><html><body>hi</body></html>"),
+            ContentType::Html
+        );
+    }
+
+    #[test]
+    fn dropped_blocks_end_at_their_own_closing_tag() {
+        let raw = "<nav><script>track()</script>Menu</nav><article><p>Body</p></article>";
+        assert_eq!(ContentType::Html.parse(raw), "Body");
+    }
+}
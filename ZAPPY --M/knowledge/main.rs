@@ -1,12 +1,14 @@
 //! High-level orchestration for the knowledge pipeline.
 
 use anyhow::Result;
-use std::sync::Arc;
+use indexmap::IndexMap;
+use std::{sync::Arc, time::Duration};
 
 use crate::{
     editor::editor::{EditOperation, KnowledgeEditor},
     receiver::{KnowledgeArtifact, KnowledgeReceiver},
     saver::{KnowledgeRecord, KnowledgeStore},
+    scheduler::{self, EnrichmentHandle},
     security::{KnowledgeGuard, SecurityPolicy},
     seeker::{KnowledgeQuery, KnowledgeSeeker},
     telemetry::KnowledgeTelemetry,
@@ -76,11 +78,12 @@ impl KnowledgeRuntime {
     pub fn search(&self, query: KnowledgeQuery) -> Vec<crate::seeker::KnowledgeSnippet> {
         let text = query.text.clone();
         let domain = query.domain.clone();
+        let category = query.category.clone();
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 LogLevel::Debug,
                 "knowledge.search.start",
-                json!({ "query": text.clone(), "domain": domain }),
+                json!({ "query": text.clone(), "domain": domain, "category": category }),
             );
         }
         let results = self.seeker.search(query);
@@ -94,6 +97,12 @@ impl KnowledgeRuntime {
         results
     }
 
+    /// Returns record counts per category for faceted search.
+    #[must_use]
+    pub fn facets(&self) -> IndexMap<String, usize> {
+        self.seeker.facets()
+    }
+
     /// Applies an edit to a record.
     pub fn edit(&self, operation: EditOperation) -> Result<KnowledgeRecord> {
         if let Some(tel) = &self.telemetry {
@@ -158,6 +167,38 @@ impl KnowledgeRuntime {
         Ok(ingested)
     }
 
+    /// Spawns a background task enriching `queries` from the web every `interval`.
+    ///
+    /// Must be called within a tokio runtime; stop it through the returned handle.
+    #[must_use]
+    pub fn start_enrichment_scheduler(
+        &self,
+        queries: Vec<String>,
+        interval: Duration,
+    ) -> EnrichmentHandle {
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(
+                LogLevel::Info,
+                "knowledge.enrichment.scheduled",
+                json!({ "queries": queries, "interval_ms": interval.as_millis() }),
+            );
+        }
+        scheduler::spawn(self.clone(), queries, interval)
+    }
+
+    pub(crate) fn emit(&self, event_type: &str, payload: serde_json::Value) {
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(LogLevel::Info, event_type, payload.clone());
+            let _ = tel.event(event_type, payload);
+        }
+    }
+
+    pub(crate) fn warn(&self, message: &str, payload: serde_json::Value) {
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(LogLevel::Warn, message, payload);
+        }
+    }
+
     /// Attaches telemetry sinks.
     #[must_use]
     pub fn with_telemetry(mut self, telemetry: KnowledgeTelemetry) -> Self {
@@ -1,13 +1,27 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::PathBuf,
+};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::{
+    content::ContentType,
     saver::{KnowledgeRecord, KnowledgeStore},
-    security::KnowledgeGuard,
+    security::{InspectionScan, KnowledgeGuard},
 };
 
+/// Chunk size used when streaming artifact bodies.
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+/// Bytes carried between chunks so patterns spanning a boundary are still inspected.
+const STREAM_OVERLAP_BYTES: usize = 64;
+/// Maximum number of bytes kept in memory as the record body for streamed artifacts.
+const STREAM_PREVIEW_BYTES: usize = 4 * 1024;
+
 /// Incoming artifact before normalization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnowledgeArtifact {
@@ -23,6 +37,9 @@ pub struct KnowledgeArtifact {
     pub category: Option<String>,
     /// Collected timestamp.
     pub collected_at: DateTime<Utc>,
+    /// Declared MIME type of `content`; sniffed from the body when absent.
+    #[serde(default)]
+    pub content_type: Option<String>,
 }
 
 impl KnowledgeArtifact {
@@ -40,8 +57,16 @@ impl KnowledgeArtifact {
             content: content.into(),
             category: None,
             collected_at: Utc::now(),
+            content_type: None,
         }
     }
+
+    /// Declares the MIME type of the content (e.g. `text/html`).
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
 }
 
 /// Errors emitted while receiving artifacts.
@@ -53,6 +78,9 @@ pub enum KnowledgeReceiverError {
     /// Security policy rejected the artifact.
     #[error("security rejection: {0}")]
     Security(String),
+    /// Reading or spooling a streamed body failed.
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
 }
 
 /// Receives artifacts, validates, and persists them.
@@ -60,13 +88,25 @@ pub enum KnowledgeReceiverError {
 pub struct KnowledgeReceiver {
     store: KnowledgeStore,
     guard: KnowledgeGuard,
+    spool_dir: Option<PathBuf>,
 }
 
 impl KnowledgeReceiver {
     /// Creates a new receiver.
     #[must_use]
     pub fn new(store: KnowledgeStore, guard: KnowledgeGuard) -> Self {
-        Self { store, guard }
+        Self {
+            store,
+            guard,
+            spool_dir: None,
+        }
+    }
+
+    /// Spools full streamed bodies into `dir` instead of discarding everything past the preview.
+    #[must_use]
+    pub fn with_spool_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.spool_dir = Some(dir.into());
+        self
     }
 
     /// Processes the artifact, returning persisted record.
@@ -79,13 +119,126 @@ impl KnowledgeReceiver {
             .enforce(&artifact)
             .map_err(KnowledgeReceiverError::Security)?;
 
-        let record = KnowledgeRecord::new(&artifact.source, &artifact.title, &artifact.content)
+        let content_type = ContentType::detect(artifact.content_type.as_deref(), &artifact.content);
+        let body = content_type.parse(&artifact.content);
+        let mut record = KnowledgeRecord::new(&artifact.source, &artifact.title, &body)
             .with_metadata(
                 "collected_at",
                 serde_json::json!(artifact.collected_at.to_rfc3339()),
             )
             .with_metadata("category", serde_json::json!(artifact.category))
+            .with_metadata("content_type", serde_json::json!(content_type.label()))
             .with_external_ref(&artifact.external_id);
+        if body != artifact.content {
+            record = record.with_raw_body(artifact.content);
+        }
+
+        self.store.insert(record.clone());
+        Ok(record)
+    }
+
+    /// Streams a large artifact body from `reader`, inspecting it chunk by chunk.
+    ///
+    /// `metadata` supplies source, title, and category; its `content` is ignored. Only a
+    /// bounded preview is kept as the record body, while the `length` metadata reports the
+    /// full byte count. When a spool directory is configured the complete body is written
+    /// there and referenced via the `content_path` metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnowledgeReceiverError::Validation`] for an empty title or short body,
+    /// [`KnowledgeReceiverError::Security`] when the guard rejects the streamed findings,
+    /// and [`KnowledgeReceiverError::Io`] if reading or spooling fails.
+    pub fn receive_reader(
+        &self,
+        metadata: &KnowledgeArtifact,
+        mut reader: impl Read,
+    ) -> Result<KnowledgeRecord, KnowledgeReceiverError> {
+        if metadata.title.trim().is_empty() {
+            return Err(KnowledgeReceiverError::Validation(
+                "title cannot be empty".into(),
+            ));
+        }
+
+        let record_id = Uuid::new_v4();
+        let spool_path = self
+            .spool_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{record_id}.txt")));
+        let mut spool = match &spool_path {
+            Some(path) => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                Some(BufWriter::new(File::create(path)?))
+            }
+            None => None,
+        };
+
+        let mut chunk = vec![0_u8; STREAM_CHUNK_BYTES];
+        let mut window = Vec::with_capacity(STREAM_CHUNK_BYTES + STREAM_OVERLAP_BYTES);
+        let mut preview = Vec::with_capacity(STREAM_PREVIEW_BYTES);
+        let mut scan = InspectionScan::default();
+        let mut length = 0_usize;
+        loop {
+            let read = reader.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            let bytes = &chunk[..read];
+            length += read;
+            if let Some(spool) = spool.as_mut() {
+                spool.write_all(bytes)?;
+            }
+            if preview.len() < STREAM_PREVIEW_BYTES {
+                let take = (STREAM_PREVIEW_BYTES - preview.len()).min(read);
+                preview.extend_from_slice(&bytes[..take]);
+            }
+
+            window.extend_from_slice(bytes);
+            self.guard
+                .inspector()
+                .scan(&mut scan, &String::from_utf8_lossy(&window));
+            let keep_from = window.len().saturating_sub(STREAM_OVERLAP_BYTES);
+            window.drain(..keep_from);
+        }
+        if let Some(mut spool) = spool {
+            spool.flush()?;
+        }
+
+        let rejection = if length < 15 {
+            Some(KnowledgeReceiverError::Validation(
+                "content too short".into(),
+            ))
+        } else {
+            self.guard
+                .enforce_findings(metadata, &scan.findings())
+                .err()
+                .map(KnowledgeReceiverError::Security)
+        };
+        if let Some(err) = rejection {
+            if let Some(path) = &spool_path {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(err);
+        }
+
+        let body = String::from_utf8_lossy(&preview)
+            .trim_end_matches('\u{fffd}')
+            .to_string();
+        let mut record = KnowledgeRecord::new(&metadata.source, &metadata.title, body)
+            .with_metadata(
+                "collected_at",
+                serde_json::json!(metadata.collected_at.to_rfc3339()),
+            )
+            .with_metadata("category", serde_json::json!(metadata.category))
+            .with_metadata("length", serde_json::json!(length))
+            .with_metadata("truncated", serde_json::json!(length > preview.len()))
+            .with_external_ref(&metadata.external_id);
+        record.id = record_id;
+        if let Some(path) = spool_path {
+            record = record.with_metadata("content_path", serde_json::json!(path));
+        }
 
         self.store.insert(record.clone());
         Ok(record)
@@ -122,4 +275,82 @@ mod tests {
         assert_eq!(record.title, "Test Title");
         assert_eq!(store.len(), 1);
     }
+
+    #[test]
+    fn receiver_strips_html_and_keeps_raw_body() {
+        let store = KnowledgeStore::default();
+        let guard = KnowledgeGuard::new(SecurityPolicy::default());
+        let receiver = KnowledgeReceiver::new(store.clone(), guard);
+        let html = "<html><head><title>Grid</title><style>p { color: red; }</style></head>\
+            <body><nav><a href=\"/\">Home</a></nav><main><h1>Grid report</h1>\
+            <p>Load rose by <b>12%</b> &amp; held steady.</p>\
+            <script>track();</script></main></body></html>";
+        let artifact = KnowledgeArtifact::new("web", "Grid Report", html)
+            .with_content_type("text/html; charset=utf-8");
+
+        let record = receiver.receive(artifact).unwrap();
+        assert_eq!(record.body, "Grid report\nLoad rose by 12% & held steady.");
+        assert_eq!(record.raw(), html);
+        assert_eq!(record.metadata["content_type"], "text/html");
+        assert_eq!(
+            store.get(&record.id).unwrap().raw_body.as_deref(),
+            Some(html)
+        );
+    }
+
+    #[test]
+    fn receiver_streams_large_reader() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = KnowledgeStore::default();
+        let guard = KnowledgeGuard::new(SecurityPolicy::default());
+        let receiver = KnowledgeReceiver::new(store.clone(), guard).with_spool_dir(dir.path());
+        let line = "Streaming ingestion keeps memory bounded for large documents.\n";
+        let body = line.repeat(60_000);
+        let metadata = KnowledgeArtifact::new("archive", "Large Doc", String::new());
+
+        let record = receiver
+            .receive_reader(&metadata, io::Cursor::new(body.as_bytes()))
+            .unwrap();
+        assert_eq!(record.metadata["length"], serde_json::json!(body.len()));
+        assert!(record.body.len() <= STREAM_PREVIEW_BYTES);
+        let spooled = record.metadata["content_path"].as_str().unwrap();
+        assert_eq!(
+            std::fs::metadata(spooled).unwrap().len(),
+            u64::try_from(body.len()).unwrap()
+        );
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn receiver_stream_detects_pattern_across_chunks() {
+        let store = KnowledgeStore::default();
+        let guard = KnowledgeGuard::new(SecurityPolicy::default());
+        let receiver = KnowledgeReceiver::new(store.clone(), guard);
+        let mut body = "a".repeat(STREAM_CHUNK_BYTES - 5);
+        body.push_str(" top secret appendix");
+        let metadata = KnowledgeArtifact::new("archive", "Leaky Doc", String::new());
+
+        let result = receiver.receive_reader(&metadata, body.as_bytes());
+        assert!(matches!(result, Err(KnowledgeReceiverError::Security(_))));
+        assert_eq!(store.len(), 0);
+    }
+
+    #[test]
+    fn streamed_risk_matches_whole_document_risk() {
+        let guard = KnowledgeGuard::new(SecurityPolicy::default());
+        let receiver = KnowledgeReceiver::new(KnowledgeStore::default(), guard.clone());
+        let mut body = String::from("ssn 123-45-6789 ");
+        body.push_str(&"a".repeat(3 * STREAM_CHUNK_BYTES));
+        body.push_str(" top secret appendix, ssn 987-65-4321");
+        let artifact = KnowledgeArtifact::new("archive", "Leaky Doc", body.clone());
+
+        let whole = guard.enforce(&artifact).unwrap_err();
+        let streamed = receiver
+            .receive_reader(&artifact, body.as_bytes())
+            .unwrap_err();
+        assert!(
+            matches!(streamed, KnowledgeReceiverError::Security(reason) if reason == whole),
+            "{whole}"
+        );
+    }
 }
@@ -1,3 +1,6 @@
+use std::io::{BufRead, Write};
+
+use anyhow::Result;
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
@@ -5,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Persistent record stored in the knowledge base.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KnowledgeRecord {
     /// Unique identifier.
     pub id: Uuid,
@@ -17,6 +20,9 @@ pub struct KnowledgeRecord {
     pub title: String,
     /// Body text.
     pub body: String,
+    /// Original body before content-type parsing, when it differs from `body`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_body: Option<String>,
     /// Structured metadata for analytics.
     pub metadata: IndexMap<String, serde_json::Value>,
     /// Creation timestamp.
@@ -37,11 +43,25 @@ impl KnowledgeRecord {
             source: source.into(),
             title: title.into(),
             body: body.into(),
+            raw_body: None,
             metadata: IndexMap::new(),
             created_at: Utc::now(),
         }
     }
 
+    /// Keeps the unparsed body alongside the stored text.
+    #[must_use]
+    pub fn with_raw_body(mut self, raw: impl Into<String>) -> Self {
+        self.raw_body = Some(raw.into());
+        self
+    }
+
+    /// Original body as ingested, falling back to `body` when no parsing was applied.
+    #[must_use]
+    pub fn raw(&self) -> &str {
+        self.raw_body.as_deref().unwrap_or(&self.body)
+    }
+
     /// Attaches an external reference identifier.
     #[must_use]
     pub fn with_external_ref(mut self, reference: impl Into<String>) -> Self {
@@ -133,6 +153,57 @@ impl KnowledgeStore {
     pub fn all(&self) -> Vec<KnowledgeRecord> {
         self.records.read().clone()
     }
+
+    /// Writes every record as one JSON object per line, returning the count written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be serialised or the writer fails.
+    pub fn export<W: Write>(&self, mut writer: W) -> Result<usize> {
+        let records = self.all();
+        for record in &records {
+            serde_json::to_writer(&mut writer, record)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(records.len())
+    }
+
+    /// Ingests JSONL records produced by [`KnowledgeStore::export`], keeping their ids.
+    ///
+    /// Records whose id or external reference already exists are skipped. Returns the
+    /// number of records imported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or any line is not a valid record; nothing is
+    /// imported in that case.
+    pub fn import<R: BufRead>(&self, reader: R) -> Result<usize> {
+        let mut incoming = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            incoming.push(serde_json::from_str::<KnowledgeRecord>(&line)?);
+        }
+
+        let mut imported = 0;
+        let mut guard = self.records.write();
+        for record in incoming {
+            let duplicate = guard.iter().any(|existing| {
+                existing.id == record.id
+                    || (record.external_ref.is_some()
+                        && existing.external_ref == record.external_ref)
+            });
+            if !duplicate {
+                guard.push(record);
+                imported += 1;
+            }
+        }
+        drop(guard);
+        Ok(imported)
+    }
 }
 
 #[cfg(test)]
@@ -147,4 +218,26 @@ mod tests {
         let results = store.find_by_keyword("rust");
         assert_eq!(results.len(), 1);
     }
+
+    #[test]
+    fn export_import_round_trip() {
+        let source = KnowledgeStore::default();
+        source.insert(
+            KnowledgeRecord::new("web", "Rust", "Ownership model")
+                .with_external_ref("web::rust")
+                .with_metadata("category", serde_json::json!("web")),
+        );
+        source.insert(KnowledgeRecord::new("internal", "Python", "Interpreter"));
+
+        let mut buffer = Vec::new();
+        assert_eq!(source.export(&mut buffer).unwrap(), 2);
+
+        let target = KnowledgeStore::default();
+        assert_eq!(target.import(buffer.as_slice()).unwrap(), 2);
+        assert_eq!(target.all(), source.all());
+
+        // Re-importing the same dump is a no-op.
+        assert_eq!(target.import(buffer.as_slice()).unwrap(), 0);
+        assert_eq!(target.len(), 2);
+    }
 }
@@ -0,0 +1,117 @@
+//! Background scheduler that periodically enriches the store from the web.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::{sync::oneshot, task::JoinHandle, time::interval};
+
+use crate::orchestration_entry::KnowledgeRuntime;
+
+/// Totals accumulated by an enrichment scheduler.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnrichmentSummary {
+    /// Completed enrichment cycles.
+    pub cycles: u64,
+    /// Records ingested across all cycles.
+    pub records: u64,
+}
+
+/// Handle controlling a running enrichment scheduler.
+#[derive(Debug)]
+pub struct EnrichmentHandle {
+    stop: oneshot::Sender<()>,
+    task: JoinHandle<EnrichmentSummary>,
+    cycles: Arc<AtomicU64>,
+}
+
+impl EnrichmentHandle {
+    /// Number of cycles completed so far.
+    #[must_use]
+    pub fn cycles(&self) -> u64 {
+        self.cycles.load(Ordering::Relaxed)
+    }
+
+    /// Stops the scheduler after the in-flight cycle and returns its totals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheduler task panicked or was aborted.
+    pub async fn stop(self) -> Result<EnrichmentSummary> {
+        let _ = self.stop.send(());
+        Ok(self.task.await?)
+    }
+}
+
+pub(crate) fn spawn(
+    runtime: KnowledgeRuntime,
+    queries: Vec<String>,
+    period: Duration,
+) -> EnrichmentHandle {
+    let (stop, mut stopped) = oneshot::channel();
+    let cycles = Arc::new(AtomicU64::new(0));
+    let counter = Arc::clone(&cycles);
+    let task = tokio::spawn(async move {
+        let mut summary = EnrichmentSummary::default();
+        let mut ticker = interval(period);
+        loop {
+            tokio::select! {
+                _ = &mut stopped => break,
+                _ = ticker.tick() => {
+                    summary.records += run_cycle(&runtime, &queries).await;
+                    summary.cycles = counter.fetch_add(1, Ordering::Relaxed) + 1;
+                    runtime.emit(
+                        "knowledge.enrichment.cycle",
+                        json!({
+                            "cycle": summary.cycles,
+                            "queries": queries.len(),
+                            "records_total": summary.records
+                        }),
+                    );
+                }
+            }
+        }
+        summary
+    });
+    EnrichmentHandle { stop, task, cycles }
+}
+
+async fn run_cycle(runtime: &KnowledgeRuntime, queries: &[String]) -> u64 {
+    let mut ingested = 0;
+    for query in queries {
+        match runtime.enrich_from_web(query).await {
+            Ok(records) => ingested += records.len() as u64,
+            Err(err) => runtime.warn(
+                "knowledge.enrichment.query_failed",
+                json!({ "query": query, "error": err.to_string() }),
+            ),
+        }
+    }
+    ingested
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seeker::KnowledgeQuery;
+
+    #[tokio::test]
+    async fn scheduler_runs_cycles_until_stopped() {
+        let runtime = KnowledgeRuntime::bootstrap();
+        let handle =
+            runtime.start_enrichment_scheduler(vec!["zappy".into()], Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        let summary = handle.stop().await.unwrap();
+        assert!(summary.cycles >= 1);
+        assert!(summary.records >= 1);
+        let web = runtime.search(KnowledgeQuery::new("zappy").with_category(Some("web".into())));
+        assert_eq!(web.len() as u64, summary.records);
+    }
+}
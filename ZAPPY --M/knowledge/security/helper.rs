@@ -32,15 +32,39 @@ impl ContentInspector {
     /// Runs inspection routines and returns findings.
     #[must_use]
     pub fn inspect(&self, content: &str) -> Vec<InspectionFinding> {
+        let mut scan = InspectionScan::default();
+        self.scan(&mut scan, content);
+        scan.findings()
+    }
+
+    /// Folds `content` into `scan`, so a document inspected piecewise yields the same
+    /// findings as [`Self::inspect`] on the whole text.
+    pub fn scan(&self, scan: &mut InspectionScan, content: &str) {
+        scan.sensitive |= self.sensitive_regex.is_match(content);
+        scan.pii |= self.pii_regex.is_match(content);
+    }
+}
+
+/// Rules matched so far while inspecting a document in pieces.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InspectionScan {
+    sensitive: bool,
+    pii: bool,
+}
+
+impl InspectionScan {
+    /// Returns one finding per matched rule, in the order [`ContentInspector::inspect`] uses.
+    #[must_use]
+    pub fn findings(&self) -> Vec<InspectionFinding> {
         let mut findings = Vec::new();
-        if self.sensitive_regex.is_match(content) {
+        if self.sensitive {
             findings.push(InspectionFinding {
                 label: "sensitive_phrase".into(),
                 notes: "Sensitive phrase detected".into(),
                 severity: 0.7,
             });
         }
-        if self.pii_regex.is_match(content) {
+        if self.pii {
             findings.push(InspectionFinding {
                 label: "pii".into(),
                 notes: "Possible PII detected".into(),
@@ -7,6 +7,6 @@ pub mod methods;
 /// Guard and policy enforcement.
 pub mod security;
 
-pub use helper::{ContentInspector, InspectionFinding};
+pub use helper::{ContentInspector, InspectionFinding, InspectionScan};
 pub use methods::{RiskComputation, RiskProfile};
 pub use security::{KnowledgeGuard, SecurityPolicy};
@@ -1,16 +1,23 @@
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::receiver::KnowledgeArtifact;
 
-use super::{helper::ContentInspector, methods::RiskComputation};
+use super::{
+    helper::{ContentInspector, InspectionFinding},
+    methods::RiskComputation,
+};
 
 /// Security policy thresholds.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityPolicy {
-    /// Maximum acceptable risk score.
+    /// Maximum acceptable risk score for categories without an override.
     pub max_risk: f32,
     /// Whether to reject artifacts missing source metadata.
     pub require_source: bool,
+    /// Per-category risk ceilings overriding `max_risk`.
+    #[serde(default)]
+    pub category_thresholds: IndexMap<String, f32>,
 }
 
 impl Default for SecurityPolicy {
@@ -18,10 +25,29 @@ impl Default for SecurityPolicy {
         Self {
             max_risk: 0.6,
             require_source: true,
+            category_thresholds: IndexMap::new(),
         }
     }
 }
 
+impl SecurityPolicy {
+    /// Sets the risk ceiling applied to artifacts of the given category.
+    #[must_use]
+    pub fn with_category_threshold(mut self, category: impl Into<String>, max_risk: f32) -> Self {
+        self.category_thresholds.insert(category.into(), max_risk);
+        self
+    }
+
+    /// Returns the risk ceiling for a category, falling back to `max_risk`.
+    #[must_use]
+    pub fn threshold_for(&self, category: Option<&str>) -> f32 {
+        category
+            .and_then(|category| self.category_thresholds.get(category))
+            .copied()
+            .unwrap_or(self.max_risk)
+    }
+}
+
 /// Enforces policy against incoming artifacts.
 #[derive(Debug, Clone)]
 pub struct KnowledgeGuard {
@@ -41,17 +67,38 @@ impl KnowledgeGuard {
         }
     }
 
+    /// Returns the inspector used to scan content.
+    #[must_use]
+    pub const fn inspector(&self) -> &ContentInspector {
+        &self.inspector
+    }
+
     /// Enforces the policy on the artifact.
     pub fn enforce(&self, artifact: &KnowledgeArtifact) -> Result<(), String> {
+        let findings = self.inspector.inspect(&artifact.content);
+        self.enforce_findings(artifact, &findings)
+    }
+
+    /// Enforces the policy using findings gathered ahead of time (e.g. while streaming).
+    ///
+    /// # Errors
+    ///
+    /// Returns a rejection reason when a required source is missing or the findings' risk
+    /// score exceeds the category threshold.
+    pub fn enforce_findings(
+        &self,
+        artifact: &KnowledgeArtifact,
+        findings: &[InspectionFinding],
+    ) -> Result<(), String> {
         if self.policy.require_source && artifact.source.trim().is_empty() {
             return Err("missing source".into());
         }
 
-        let findings = self.inspector.inspect(&artifact.content);
-        let profile = self.risk.profile(&findings);
-        if profile.score > self.policy.max_risk {
+        let profile = self.risk.profile(findings);
+        let threshold = self.policy.threshold_for(artifact.category.as_deref());
+        if profile.score > threshold {
             return Err(format!(
-                "risk {:.2} exceeds threshold: {:?}",
+                "risk {:.2} exceeds threshold {threshold:.2}: {:?}",
                 profile.score, profile.labels
             ));
         }
@@ -70,4 +117,22 @@ mod tests {
         let artifact = KnowledgeArtifact::new("src", "title", "This contains top secret info.");
         assert!(guard.enforce(&artifact).is_err());
     }
+
+    #[test]
+    fn guard_applies_category_thresholds() {
+        let policy = SecurityPolicy::default()
+            .with_category_threshold("web", 0.5)
+            .with_category_threshold("internal", 0.8);
+        let guard = KnowledgeGuard::new(policy);
+        let borderline = |category: Option<&str>| {
+            let mut artifact = KnowledgeArtifact::new("src", "title", "Marked internal use only.");
+            artifact.category = category.map(Into::into);
+            artifact
+        };
+
+        assert!(guard.enforce(&borderline(Some("internal"))).is_ok());
+        assert!(guard.enforce(&borderline(Some("web"))).is_err());
+        // Unknown categories fall back to the default ceiling (0.6).
+        assert!(guard.enforce(&borderline(Some("partner"))).is_err());
+    }
 }
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::saver::{KnowledgeRecord, KnowledgeStore};
@@ -10,6 +11,9 @@ pub struct KnowledgeQuery {
     pub text: String,
     /// Optional domain hint.
     pub domain: Option<String>,
+    /// Optional exact-match category facet.
+    #[serde(default)]
+    pub category: Option<String>,
 }
 
 impl KnowledgeQuery {
@@ -19,8 +23,16 @@ impl KnowledgeQuery {
         Self {
             text: text.into(),
             domain: None,
+            category: None,
         }
     }
+
+    /// Restricts results to records ingested under the given category.
+    #[must_use]
+    pub fn with_category(mut self, category: Option<String>) -> Self {
+        self.category = category;
+        self
+    }
 }
 
 /// Short snippet returned to callers.
@@ -34,6 +46,27 @@ pub struct KnowledgeSnippet {
     pub score: f32,
     /// Timestamp when snippet was produced.
     pub generated_at: DateTime<Utc>,
+    /// Byte ranges (`start..end`) of `excerpt` matching a query term, in order.
+    #[serde(default)]
+    pub highlights: Vec<(usize, usize)>,
+}
+
+impl KnowledgeSnippet {
+    /// Excerpt with every highlighted range wrapped in `**`.
+    #[must_use]
+    pub fn highlighted(&self) -> String {
+        let mut out = String::with_capacity(self.excerpt.len() + self.highlights.len() * 4);
+        let mut cursor = 0;
+        for &(start, end) in &self.highlights {
+            out.push_str(&self.excerpt[cursor..start]);
+            out.push_str("**");
+            out.push_str(&self.excerpt[start..end]);
+            out.push_str("**");
+            cursor = end;
+        }
+        out.push_str(&self.excerpt[cursor..]);
+        out
+    }
 }
 
 /// Seeker that queries the knowledge store.
@@ -51,9 +84,14 @@ impl KnowledgeSeeker {
 
     /// Executes the query and returns snippets.
     pub fn search(&self, query: KnowledgeQuery) -> Vec<KnowledgeSnippet> {
+        let category = query.category.as_deref();
         let mut records = self.store.find_by_keyword(&query.text);
+        records.retain(|record| matches_category(record, category));
         if records.is_empty() {
-            records = self.store.latest(3);
+            records = self.store.all();
+            records.retain(|record| matches_category(record, category));
+            records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+            records.truncate(3);
         }
 
         let mut snippets = Vec::new();
@@ -61,6 +99,7 @@ impl KnowledgeSeeker {
             let excerpt = extract_excerpt(&record.body, &query.text);
             snippets.push(KnowledgeSnippet {
                 record_id: record.id,
+                highlights: highlight_ranges(&excerpt, &query.text),
                 excerpt,
                 score: score_record(&record, &query),
                 generated_at: Utc::now(),
@@ -68,6 +107,26 @@ impl KnowledgeSeeker {
         }
         snippets
     }
+
+    /// Counts records per category so callers can build filter menus.
+    #[must_use]
+    pub fn facets(&self) -> IndexMap<String, usize> {
+        let mut counts = IndexMap::new();
+        for record in self.store.all() {
+            if let Some(category) = record_category(&record) {
+                *counts.entry(category.to_string()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+}
+
+fn record_category(record: &KnowledgeRecord) -> Option<&str> {
+    record.metadata.get("category").and_then(|v| v.as_str())
+}
+
+fn matches_category(record: &KnowledgeRecord, category: Option<&str>) -> bool {
+    category.map_or(true, |wanted| record_category(record) == Some(wanted))
 }
 
 fn extract_excerpt(body: &str, needle: &str) -> String {
@@ -91,6 +150,50 @@ fn extract_excerpt(body: &str, needle: &str) -> String {
     body.split('.').next().unwrap_or(body).to_string()
 }
 
+/// Non-overlapping byte ranges of `text` matching any whitespace-separated term of
+/// `query`, compared case-insensitively char by char so offsets stay on UTF-8 boundaries.
+fn highlight_ranges(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let terms: Vec<Vec<char>> = query
+        .split_whitespace()
+        .map(|term| term.chars().flat_map(char::to_lowercase).collect())
+        .collect();
+    let mut ranges = Vec::new();
+    let mut covered = 0;
+    for (start, _) in text.char_indices() {
+        if start < covered {
+            continue;
+        }
+        let longest = terms
+            .iter()
+            .filter_map(|term| match_at(&text[start..], term))
+            .max();
+        if let Some(len) = longest {
+            ranges.push((start, start + len));
+            covered = start + len;
+        }
+    }
+    ranges
+}
+
+/// Byte length of the prefix of `text` whose lowercase form equals `term`.
+fn match_at(text: &str, term: &[char]) -> Option<usize> {
+    if term.is_empty() {
+        return None;
+    }
+    let mut pending = term.iter();
+    for (offset, ch) in text.char_indices() {
+        for lower in ch.to_lowercase() {
+            if pending.next() != Some(&lower) {
+                return None;
+            }
+        }
+        if pending.len() == 0 {
+            return Some(offset + ch.len_utf8());
+        }
+    }
+    None
+}
+
 fn score_record(record: &KnowledgeRecord, query: &KnowledgeQuery) -> f32 {
     let mut score: f32 = 0.5;
     if record
@@ -101,12 +204,7 @@ fn score_record(record: &KnowledgeRecord, query: &KnowledgeQuery) -> f32 {
         score += 0.3;
     }
     if let Some(domain) = &query.domain {
-        if record
-            .metadata
-            .get("category")
-            .and_then(|v| v.as_str())
-            .map_or(false, |cat| cat == domain)
-        {
+        if record_category(record) == Some(domain.as_str()) {
             score += 0.2;
         }
     }
@@ -133,4 +231,53 @@ mod tests {
         let snippets = seeker.search(KnowledgeQuery::new("ownership"));
         assert!(!snippets.is_empty());
     }
+
+    #[test]
+    fn highlights_slice_to_matched_terms() {
+        let store = KnowledgeStore::default();
+        store.insert(KnowledgeRecord::new(
+            "src",
+            "Crème notes",
+            "Crème brûlée needs ÉCLAIR-grade patience; every éclair does.",
+        ));
+        let seeker = KnowledgeSeeker::new(store);
+        let snippet = seeker.search(KnowledgeQuery::new("éclair")).remove(0);
+
+        let matched: Vec<_> = snippet
+            .highlights
+            .iter()
+            .map(|&(start, end)| &snippet.excerpt[start..end])
+            .collect();
+        assert_eq!(matched, vec!["ÉCLAIR", "éclair"]);
+        assert_eq!(
+            snippet.highlighted(),
+            "Crème brûlée needs **ÉCLAIR**-grade patience; every **éclair** does."
+        );
+    }
+
+    #[test]
+    fn category_facet_filters_results() {
+        let store = KnowledgeStore::default();
+        for (title, category) in [
+            ("Rust web guide", "web"),
+            ("Rust internal notes", "internal"),
+            ("Rust web tutorial", "web"),
+        ] {
+            store.insert(
+                KnowledgeRecord::new("src", title, "Rust ownership and borrowing")
+                    .with_metadata("category", serde_json::json!(category)),
+            );
+        }
+        let seeker = KnowledgeSeeker::new(store.clone());
+        let snippets = seeker.search(KnowledgeQuery::new("rust").with_category(Some("web".into())));
+        assert_eq!(snippets.len(), 2);
+        for snippet in &snippets {
+            let record = store.get(&snippet.record_id).unwrap();
+            assert_eq!(record.metadata["category"], serde_json::json!("web"));
+        }
+
+        let facets = seeker.facets();
+        assert_eq!(facets.get("web"), Some(&2));
+        assert_eq!(facets.get("internal"), Some(&1));
+    }
 }
@@ -8,6 +8,10 @@
 
 //! Zappy Tier-9 knowledge ingestion and curation stack.
 
+/// Content-type detection and parsing for artifact bodies.
+#[path = "../content.rs"]
+pub mod content;
+
 /// Knowledge receivers that normalize inbound artifacts.
 #[path = "../receiver.rs"]
 pub mod receiver;
@@ -32,6 +36,10 @@ pub mod editor;
 #[path = "../security/main.rs"]
 pub mod security;
 
+/// Background web enrichment scheduler.
+#[path = "../scheduler.rs"]
+pub mod scheduler;
+
 /// Telemetry helpers.
 #[path = "../telemetry.rs"]
 pub mod telemetry;
@@ -42,10 +50,12 @@ pub mod telemetry;
 #[path = "../main.rs"]
 pub mod orchestration_entry;
 
+pub use content::ContentType;
 pub use editor::editor::{EditOperation, KnowledgeEditor};
 pub use orchestration_entry::KnowledgeRuntime;
 pub use receiver::{KnowledgeArtifact, KnowledgeReceiver};
 pub use saver::{KnowledgeRecord, KnowledgeStore};
+pub use scheduler::{EnrichmentHandle, EnrichmentSummary};
 pub use security::{
     ContentInspector, KnowledgeGuard, RiskComputation, RiskProfile, SecurityPolicy,
 };
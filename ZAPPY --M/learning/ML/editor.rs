@@ -1,6 +1,7 @@
 use rand::SeedableRng;
 use rand::{rngs::SmallRng, Rng};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Single training data point.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +12,38 @@ pub struct DataPoint {
     pub label: f32,
 }
 
+/// Structural problems found by [`Dataset::validate`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum DatasetError {
+    /// The dataset has no samples.
+    #[error("dataset contains no samples")]
+    Empty,
+    /// A sample's feature vector differs in length from the first sample's.
+    #[error("sample {sample} has {found} features, expected {expected}")]
+    Ragged {
+        /// Index of the offending sample.
+        sample: usize,
+        /// Feature dimension of the first sample.
+        expected: usize,
+        /// Feature dimension of the offending sample.
+        found: usize,
+    },
+    /// A feature value is NaN or infinite.
+    #[error("sample {sample} has a non-finite value in feature {feature}")]
+    NonFiniteFeature {
+        /// Index of the offending sample.
+        sample: usize,
+        /// Index of the offending feature.
+        feature: usize,
+    },
+    /// A label is NaN or infinite.
+    #[error("sample {sample} has a non-finite label")]
+    NonFiniteLabel {
+        /// Index of the offending sample.
+        sample: usize,
+    },
+}
+
 /// Dataset wrapper.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Dataset {
@@ -35,44 +68,98 @@ impl Dataset {
         Self { samples }
     }
 
-    /// Applies feature standardization.
-    pub fn standardize(&mut self) {
+    /// Checks the dataset is non-empty, has a uniform feature dimension, and holds only
+    /// finite values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`DatasetError`] found: an empty dataset, a sample whose feature
+    /// count differs from the first sample, or a non-finite feature or label.
+    pub fn validate(&self) -> Result<(), DatasetError> {
         if self.samples.is_empty() {
-            return;
+            return Err(DatasetError::Empty);
+        }
+        let expected = self.feature_dim();
+        for (sample, point) in self.samples.iter().enumerate() {
+            if point.features.len() != expected {
+                return Err(DatasetError::Ragged {
+                    sample,
+                    expected,
+                    found: point.features.len(),
+                });
+            }
+            if let Some(feature) = point.features.iter().position(|value| !value.is_finite()) {
+                return Err(DatasetError::NonFiniteFeature { sample, feature });
+            }
+            if !point.label.is_finite() {
+                return Err(DatasetError::NonFiniteLabel { sample });
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies feature standardization fitted on this dataset.
+    pub fn standardize(&mut self) {
+        if let Some(standardizer) = Standardizer::fit(self) {
+            standardizer.apply(self);
+        }
+    }
+
+    /// Returns the feature dimensionality.
+    #[must_use]
+    pub fn feature_dim(&self) -> usize {
+        self.samples.first().map_or(0, |point| point.features.len())
+    }
+}
+
+/// Per-feature mean and scale learned from a (training) dataset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Standardizer {
+    means: Vec<f32>,
+    scales: Vec<f32>,
+}
+
+impl Standardizer {
+    /// Learns feature statistics from `dataset`; `None` when it is empty.
+    #[must_use]
+    pub fn fit(dataset: &Dataset) -> Option<Self> {
+        if dataset.samples.is_empty() {
+            return None;
         }
-        let feature_dim = self.samples[0].features.len();
+        let feature_dim = dataset.feature_dim();
+        let count = dataset.samples.len() as f32;
         let mut means = vec![0.0; feature_dim];
-        for point in &self.samples {
+        for point in &dataset.samples {
             for (idx, value) in point.features.iter().enumerate() {
                 means[idx] += value;
             }
         }
         for mean in &mut means {
-            *mean /= self.samples.len() as f32;
+            *mean /= count;
         }
 
-        let mut variances = vec![0.0; feature_dim];
-        for point in &self.samples {
+        let mut scales = vec![0.0; feature_dim];
+        for point in &dataset.samples {
             for (idx, value) in point.features.iter().enumerate() {
-                variances[idx] += (value - means[idx]).powi(2);
+                scales[idx] += (value - means[idx]).powi(2);
             }
         }
-        for variance in &mut variances {
-            *variance = (*variance / self.samples.len() as f32).sqrt().max(1e-6);
+        for scale in &mut scales {
+            *scale = (*scale / count).sqrt().max(1e-6);
         }
+        Some(Self { means, scales })
+    }
 
-        for point in &mut self.samples {
-            for (idx, value) in point.features.iter_mut().enumerate() {
-                *value = (*value - means[idx]) / variances[idx];
+    /// Standardizes `dataset` in place using the fitted statistics.
+    pub fn apply(&self, dataset: &mut Dataset) {
+        for point in &mut dataset.samples {
+            for ((value, mean), scale) in
+                point.features.iter_mut().zip(&self.means).zip(&self.scales)
+            {
+                *value = (*value - mean) / scale;
             }
         }
     }
-
-    /// Returns the feature dimensionality.
-    #[must_use]
-    pub fn feature_dim(&self) -> usize {
-        self.samples.first().map_or(0, |point| point.features.len())
-    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
 use crate::classical_ml::editor::{DataPoint, Dataset};
 
 /// Splits dataset into train/test partitions (80/20 by default).
@@ -17,6 +19,29 @@ pub fn train_test_split(dataset: &Dataset, test_ratio: f32) -> (Dataset, Dataset
     (train, test)
 }
 
+/// Shuffles samples with `seed` and partitions them into `k` folds, returning one
+/// `(train, test)` pair per fold. Fold sizes differ by at most one sample.
+#[must_use]
+pub fn k_fold(dataset: &Dataset, k: usize, seed: u64) -> Vec<(Dataset, Dataset)> {
+    let mut order: Vec<usize> = (0..dataset.samples.len()).collect();
+    order.shuffle(&mut SmallRng::seed_from_u64(seed));
+    (0..k)
+        .map(|fold| {
+            let mut train = Dataset::default();
+            let mut test = Dataset::default();
+            for (position, &idx) in order.iter().enumerate() {
+                let sample = dataset.samples[idx].clone();
+                if position % k == fold {
+                    test.samples.push(sample);
+                } else {
+                    train.samples.push(sample);
+                }
+            }
+            (train, test)
+        })
+        .collect()
+}
+
 /// Computes mean squared error between predictions and labels.
 #[must_use]
 pub fn mean_squared_error(predictions: &[f32], labels: &[f32]) -> f32 {
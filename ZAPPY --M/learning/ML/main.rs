@@ -13,14 +13,19 @@ pub mod submodel;
 
 use std::path::PathBuf;
 
-use editor::Dataset;
+use anyhow::ensure;
+use editor::{Dataset, Standardizer};
+use func::{k_fold, mean_squared_error, to_matrix};
 use ml::LinearRegressionModel;
-use reporter::TrainingReport;
+use reporter::{CrossValReport, FoldResult, TrainingReport};
 use serde_json::json;
 use shared_logging::LogLevel;
 
 use crate::telemetry::LearningTelemetry;
 
+const EPOCHS: usize = 10;
+const LEARNING_RATE: f32 = 0.05;
+
 /// End-to-end classical ML pipeline orchestrator.
 #[derive(Debug, Default)]
 pub struct ClassicalMlPipeline;
@@ -32,11 +37,15 @@ impl ClassicalMlPipeline {
     }
 
     /// Runs the pipeline with optional telemetry instrumentation.
+    ///
+    /// The dataset is validated first, so malformed samples fail with a [`editor::DatasetError`]
+    /// instead of panicking during training.
     pub fn run_with_telemetry(
         &self,
         mut dataset: Dataset,
         telemetry: Option<&LearningTelemetry>,
     ) -> anyhow::Result<TrainingReport> {
+        dataset.validate()?;
         log(
             telemetry,
             LogLevel::Info,
@@ -44,20 +53,18 @@ impl ClassicalMlPipeline {
             json!({ "samples": dataset.samples.len(), "feature_dim": dataset.feature_dim() }),
         );
         dataset.standardize();
-        let weights_path =
-            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("dataset/linear_weights.json");
-        let mut model = LinearRegressionModel::from_dataset_file(weights_path)?;
+        let mut model = initial_model()?;
         log(
             telemetry,
             LogLevel::Debug,
             "classical_ml_training_start",
-            json!({ "epochs": 10, "learning_rate": 0.05 }),
+            json!({ "epochs": EPOCHS, "learning_rate": LEARNING_RATE }),
         );
-        let mse = model.fit(&dataset, 0.05, 10);
+        let mse = model.fit(&dataset, LEARNING_RATE, EPOCHS);
         let report = TrainingReport {
             model: "linear_regression".into(),
             mse,
-            epochs: 10,
+            epochs: EPOCHS,
         };
         log(
             telemetry,
@@ -75,6 +82,63 @@ impl ClassicalMlPipeline {
     }
 }
 
+impl ClassicalMlPipeline {
+    /// Runs k-fold cross-validation, returning per-fold and mean held-out MSE.
+    ///
+    /// Standardization is fitted on each fold's training split only and then applied
+    /// to its held-out split, so no statistics leak from the evaluation samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `k` is below 2, when the dataset fails validation, when it
+    /// has fewer samples than folds, or when the initial model weights cannot be loaded.
+    pub fn cross_validate(
+        &self,
+        dataset: &Dataset,
+        k: usize,
+        seed: u64,
+    ) -> anyhow::Result<CrossValReport> {
+        ensure!(k >= 2, "cross-validation needs at least 2 folds, got {k}");
+        dataset.validate()?;
+        ensure!(
+            dataset.samples.len() >= k,
+            "cannot split {} samples into {k} folds",
+            dataset.samples.len()
+        );
+        let mut folds = Vec::with_capacity(k);
+        for (fold, (mut train, mut test)) in k_fold(dataset, k, seed).into_iter().enumerate() {
+            if let Some(standardizer) = Standardizer::fit(&train) {
+                standardizer.apply(&mut train);
+                standardizer.apply(&mut test);
+            }
+            let mut model = initial_model()?;
+            let train_mse = model.fit(&train, LEARNING_RATE, EPOCHS);
+            let (features, labels) = to_matrix(&test);
+            let test_mse = mean_squared_error(&model.predict(&features), &labels);
+            folds.push(FoldResult {
+                fold,
+                train_samples: train.samples.len(),
+                test_samples: test.samples.len(),
+                train_mse,
+                test_mse,
+            });
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let mean_mse = folds.iter().map(|result| result.test_mse).sum::<f32>() / k as f32;
+        Ok(CrossValReport {
+            model: "linear_regression".into(),
+            folds,
+            mean_mse,
+        })
+    }
+}
+
+fn initial_model() -> anyhow::Result<LinearRegressionModel> {
+    let weights_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("dataset/linear_weights.json");
+    LinearRegressionModel::from_dataset_file(weights_path)
+}
+
 fn log(
     telemetry: Option<&LearningTelemetry>,
     level: LogLevel,
@@ -85,3 +149,85 @@ fn log(
         let _ = tel.log(level, message, metadata);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use editor::{DataPoint, DatasetError};
+
+    fn linear_dataset(count: u16) -> Dataset {
+        let samples = (0..count)
+            .map(|idx| {
+                let x = f32::from(idx);
+                let features = vec![(x * 0.37).sin(), (x * 0.11).cos() * 2.0, x / 10.0];
+                let label = [0.8, -0.5, 0.3]
+                    .iter()
+                    .zip(&features)
+                    .map(|(weight, feature)| weight * feature)
+                    .sum();
+                DataPoint { features, label }
+            })
+            .collect();
+        Dataset { samples }
+    }
+
+    #[test]
+    fn cross_validation_reports_every_fold() {
+        let dataset = linear_dataset(50);
+        let pipeline = ClassicalMlPipeline;
+        let report = pipeline.cross_validate(&dataset, 5, 7).unwrap();
+
+        assert_eq!(report.folds.len(), 5);
+        assert!(report
+            .folds
+            .iter()
+            .all(|fold| fold.test_samples == 10 && fold.train_samples == 40));
+        let mean = report.folds.iter().map(|fold| fold.test_mse).sum::<f32>() / 5.0;
+        assert!((report.mean_mse - mean).abs() < 1e-6);
+        assert!(
+            report.mean_mse.is_finite() && report.mean_mse < 1.0,
+            "{}",
+            report.mean_mse
+        );
+
+        let again = pipeline.cross_validate(&dataset, 5, 7).unwrap();
+        assert!((again.mean_mse - report.mean_mse).abs() < 1e-6);
+        assert!(pipeline.cross_validate(&dataset, 1, 7).is_err());
+    }
+
+    #[test]
+    fn malformed_datasets_are_rejected_before_training() {
+        let pipeline = ClassicalMlPipeline;
+        let reject = |dataset: Dataset| {
+            pipeline
+                .run(dataset)
+                .unwrap_err()
+                .downcast::<DatasetError>()
+                .unwrap()
+        };
+
+        let mut ragged = linear_dataset(6);
+        ragged.samples[4].features.pop();
+        let err = reject(ragged);
+        assert_eq!(
+            err,
+            DatasetError::Ragged {
+                sample: 4,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(err.to_string(), "sample 4 has 2 features, expected 3");
+
+        let mut poisoned = linear_dataset(6);
+        poisoned.samples[2].features[1] = f32::NAN;
+        assert_eq!(
+            reject(poisoned),
+            DatasetError::NonFiniteFeature {
+                sample: 2,
+                feature: 1
+            }
+        );
+        assert_eq!(reject(Dataset::default()), DatasetError::Empty);
+    }
+}
@@ -21,3 +21,42 @@ impl TrainingReport {
         )
     }
 }
+
+/// Held-out result for a single cross-validation fold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FoldResult {
+    /// Zero-based fold index.
+    pub fold: usize,
+    /// Samples used for training.
+    pub train_samples: usize,
+    /// Samples held out for evaluation.
+    pub test_samples: usize,
+    /// Mean squared error on the training split.
+    pub train_mse: f32,
+    /// Mean squared error on the held-out split.
+    pub test_mse: f32,
+}
+
+/// Report describing a k-fold cross-validation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossValReport {
+    /// Model name.
+    pub model: String,
+    /// Per-fold results.
+    pub folds: Vec<FoldResult>,
+    /// Mean held-out error across folds.
+    pub mean_mse: f32,
+}
+
+impl CrossValReport {
+    /// Renders a concise summary string.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "[ML] model={} folds={} mean_mse={:.4}",
+            self.model,
+            self.folds.len(),
+            self.mean_mse
+        )
+    }
+}
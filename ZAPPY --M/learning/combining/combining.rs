@@ -1,3 +1,4 @@
+use anyhow::bail;
 use serde::{Deserialize, Serialize};
 
 use super::reviewer::CombinationReviewer;
@@ -10,6 +11,29 @@ pub struct CombinationResult {
     pub predictions: Vec<f32>,
     /// Reviewer notes.
     pub notes: String,
+    /// Per-prediction variance of the blend (empty for weight-based blends).
+    #[serde(default)]
+    pub uncertainty: Vec<f32>,
+}
+
+/// Predictions from one submodel together with their variances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubModelEstimate {
+    /// Point predictions.
+    pub predictions: Vec<f32>,
+    /// Variance of each prediction.
+    pub variances: Vec<f32>,
+}
+
+impl SubModelEstimate {
+    /// Creates an estimate from matching prediction and variance vectors.
+    #[must_use]
+    pub const fn new(predictions: Vec<f32>, variances: Vec<f32>) -> Self {
+        Self {
+            predictions,
+            variances,
+        }
+    }
 }
 
 /// Engine that blends multiple submodels and validates the output.
@@ -36,6 +60,58 @@ impl CombinationEngine {
         Ok(CombinationResult {
             predictions,
             notes: "ensemble validated".into(),
+            uncertainty: Vec::new(),
+        })
+    }
+
+    /// Blends estimates by inverse variance so confident submodels dominate.
+    ///
+    /// The combined variance of each prediction is `1 / sum(1 / variance)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `estimates` is empty, when their prediction or variance
+    /// lengths differ, when a variance is negative or non-finite, or when the reviewer
+    /// rejects the blended predictions.
+    pub fn combine_uncertain(
+        &self,
+        estimates: &[SubModelEstimate],
+    ) -> anyhow::Result<CombinationResult> {
+        let Some(first) = estimates.first() else {
+            bail!("no submodel estimates to combine");
+        };
+        let len = first.predictions.len();
+        if estimates
+            .iter()
+            .any(|e| e.predictions.len() != len || e.variances.len() != len)
+        {
+            bail!("submodel estimates have mismatched lengths");
+        }
+        if estimates
+            .iter()
+            .flat_map(|e| &e.variances)
+            .any(|variance| !variance.is_finite() || *variance < 0.0)
+        {
+            bail!("submodel variances must be finite and non-negative");
+        }
+        let mut predictions = vec![0.0; len];
+        let mut uncertainty = vec![0.0; len];
+        for idx in 0..len {
+            let mut precision_sum = 0.0;
+            let mut weighted_sum = 0.0;
+            for estimate in estimates {
+                let precision = 1.0 / estimate.variances[idx].max(f32::EPSILON);
+                precision_sum += precision;
+                weighted_sum += precision * estimate.predictions[idx];
+            }
+            predictions[idx] = weighted_sum / precision_sum;
+            uncertainty[idx] = 1.0 / precision_sum;
+        }
+        self.reviewer.review(&predictions)?;
+        Ok(CombinationResult {
+            predictions,
+            notes: "uncertainty-weighted ensemble validated".into(),
+            uncertainty,
         })
     }
 }
@@ -57,4 +133,19 @@ mod tests {
         let result = engine.combine(&manager, &[vec![0.0, 0.0]]).unwrap();
         assert_eq!(result.predictions.len(), 1);
     }
+
+    #[test]
+    fn low_variance_estimate_dominates_blend() {
+        let engine = CombinationEngine::new(CombinationReviewer);
+        let confident = SubModelEstimate::new(vec![10.0, 4.0], vec![0.01, 0.01]);
+        let noisy = SubModelEstimate::new(vec![0.0, 8.0], vec![1.0, 1.0]);
+        let result = engine.combine_uncertain(&[confident, noisy]).unwrap();
+        // weights are 100:1, so each blend sits ~1% of the way towards the noisy model
+        assert!((result.predictions[0] - 1000.0 / 101.0).abs() < 1e-3);
+        assert!((result.predictions[1] - 408.0 / 101.0).abs() < 1e-3);
+        assert!(result.uncertainty.iter().all(|v| *v < 0.01));
+        assert!(engine
+            .combine_uncertain(&[SubModelEstimate::new(vec![1.0], vec![])])
+            .is_err());
+    }
 }
@@ -7,5 +7,5 @@ pub mod func;
 /// Reviewers ensuring safe ensembles.
 pub mod reviewer;
 
-pub use combining::{CombinationEngine, CombinationResult};
+pub use combining::{CombinationEngine, CombinationResult, SubModelEstimate};
 pub use reviewer::CombinationReviewer;
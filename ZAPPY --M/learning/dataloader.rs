@@ -1,12 +1,58 @@
 use std::{
     collections::VecDeque,
-    fs,
+    fs::{self, File},
+    io::Read,
     path::{Path, PathBuf},
 };
 
+use flate2::read::GzDecoder;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression applied to a shard file on disk.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShardCompression {
+    /// Not declared in the index; resolved from the file name or magic bytes on load.
+    #[default]
+    Auto,
+    /// Stored as raw bytes, even if the name or contents look compressed.
+    None,
+    /// Gzip stream (`.gz`).
+    Gzip,
+    /// Zstandard frame (`.zst`).
+    Zstd,
+}
+
+impl ShardCompression {
+    /// Detects compression from the file extension, falling back to magic bytes.
+    #[must_use]
+    pub fn detect(path: &Path, head: &[u8]) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gz" | "gzip") => Self::Gzip,
+            Some("zst" | "zstd") => Self::Zstd,
+            _ if head.starts_with(&GZIP_MAGIC) => Self::Gzip,
+            _ if head.starts_with(&ZSTD_MAGIC) => Self::Zstd,
+            _ => Self::None,
+        }
+    }
+
+    fn decompress(self, raw: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Auto | Self::None => Ok(raw),
+            Self::Gzip => {
+                let mut payload = Vec::new();
+                GzDecoder::new(raw.as_slice()).read_to_end(&mut payload)?;
+                Ok(payload)
+            }
+            Self::Zstd => zstd::stream::decode_all(raw.as_slice()),
+        }
+    }
+}
+
 /// Entry describing a shard in the dataset index.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShardRecord {
@@ -16,6 +62,9 @@ pub struct ShardRecord {
     pub samples: u64,
     /// Importance weight used for prioritised loading.
     pub importance: f32,
+    /// Compression of the shard file; detected on load when omitted or `auto`.
+    #[serde(default)]
+    pub compression: ShardCompression,
 }
 
 impl ShardRecord {
@@ -41,7 +90,13 @@ pub struct DatasetIndex {
 }
 
 impl DatasetIndex {
-    /// Loads an index JSON file from disk.
+    /// Loads an index JSON file from disk, resolving `auto` shard compression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLoaderError::Io`] if the index or a shard needing detection cannot
+    /// be read, [`DataLoaderError::Json`] for a malformed index, and
+    /// [`DataLoaderError::EmptyIndex`] when it lists no shards.
     pub fn load(index_path: impl AsRef<Path>) -> Result<Self, DataLoaderError> {
         let path = index_path.as_ref();
         let contents = fs::read_to_string(path)?;
@@ -53,6 +108,13 @@ impl DatasetIndex {
         if index.records.is_empty() {
             return Err(DataLoaderError::EmptyIndex);
         }
+        for record in &mut index.records {
+            if record.compression == ShardCompression::Auto {
+                let shard_path = index.base_path.join(&record.shard);
+                record.compression =
+                    ShardCompression::detect(&shard_path, &read_head(&shard_path)?);
+            }
+        }
         Ok(index)
     }
 }
@@ -62,8 +124,65 @@ impl DatasetIndex {
 pub struct ShardBatch {
     /// Metadata describing the shard.
     pub record: ShardRecord,
-    /// Binary payload read from disk.
+    /// Binary payload read from disk, decompressed when the shard is compressed.
     pub payload: Vec<u8>,
+    /// Size of the shard file on disk.
+    pub stored_bytes: usize,
+}
+
+/// Options controlling how shard payloads are split into fixed-size mini-batches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Bytes per mini-batch.
+    pub batch_size: usize,
+    /// Drop the dataset's final batch when it is shorter than `batch_size`.
+    pub drop_last: bool,
+    /// Pad the dataset's final batch up to `batch_size` with `fill`.
+    pub pad_to_batch: bool,
+    /// Byte used when padding.
+    pub fill: u8,
+}
+
+impl BatchOptions {
+    /// Keeps trailing partial batches as-is.
+    #[must_use]
+    pub const fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            drop_last: false,
+            pad_to_batch: false,
+            fill: 0,
+        }
+    }
+
+    /// Drops the final partial batch.
+    #[must_use]
+    pub const fn drop_last(mut self) -> Self {
+        self.drop_last = true;
+        self
+    }
+
+    /// Pads the final partial batch with `fill`.
+    #[must_use]
+    pub const fn pad_to_batch(mut self, fill: u8) -> Self {
+        self.pad_to_batch = true;
+        self.fill = fill;
+        self
+    }
+
+    fn validate(&self) -> Result<(), DataLoaderError> {
+        if self.batch_size == 0 {
+            return Err(DataLoaderError::InvalidBatchOptions(
+                "batch_size must be positive".into(),
+            ));
+        }
+        if self.drop_last && self.pad_to_batch {
+            return Err(DataLoaderError::InvalidBatchOptions(
+                "drop_last and pad_to_batch are mutually exclusive".into(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Prefetching shard loader with importance-aware ordering.
@@ -99,12 +218,39 @@ impl ShardLoader {
         Ok(self.queue.pop_front())
     }
 
+    /// Splits the shard stream into mini-batches of `options.batch_size` bytes.
+    ///
+    /// Batches span shard boundaries, so only the last batch of the dataset can be partial.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLoaderError::InvalidBatchOptions`] for a zero batch size or when
+    /// `drop_last` and `pad_to_batch` are both set.
+    pub fn mini_batches(self, options: BatchOptions) -> Result<MiniBatches, DataLoaderError> {
+        options.validate()?;
+        Ok(MiniBatches {
+            loader: self,
+            options,
+            current: None,
+            offset: 0,
+        })
+    }
+
     fn fill_prefetch(&mut self) -> Result<(), DataLoaderError> {
         while self.queue.len() < self.prefetch {
-            if let Some(record) = self.pending.pop_front() {
+            if let Some(mut record) = self.pending.pop_front() {
                 let shard_path = self.index.base_path.join(&record.shard);
-                let payload = fs::read(&shard_path)?;
-                self.queue.push_back(ShardBatch { record, payload });
+                let raw = fs::read(&shard_path)?;
+                if record.compression == ShardCompression::Auto {
+                    record.compression = ShardCompression::detect(&shard_path, &raw);
+                }
+                let stored_bytes = raw.len();
+                let payload = record.compression.decompress(raw)?;
+                self.queue.push_back(ShardBatch {
+                    record,
+                    payload,
+                    stored_bytes,
+                });
             } else {
                 break;
             }
@@ -113,6 +259,83 @@ impl ShardLoader {
     }
 }
 
+fn read_head(path: &Path) -> std::io::Result<Vec<u8>> {
+    let mut head = Vec::with_capacity(ZSTD_MAGIC.len());
+    File::open(path)?
+        .take(ZSTD_MAGIC.len() as u64)
+        .read_to_end(&mut head)?;
+    Ok(head)
+}
+
+/// Fixed-size slice of the shard stream produced by [`MiniBatches`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiniBatch {
+    /// Batch bytes, possibly spanning several shards.
+    pub payload: Vec<u8>,
+    /// Fill bytes appended to the payload to reach the batch size.
+    pub padding: usize,
+    /// Shards contributing bytes to the batch, in load order.
+    pub shards: Vec<String>,
+}
+
+/// Iterator over fixed-size mini-batches produced by [`ShardLoader::mini_batches`].
+#[derive(Debug)]
+pub struct MiniBatches {
+    loader: ShardLoader,
+    options: BatchOptions,
+    current: Option<ShardBatch>,
+    offset: usize,
+}
+
+impl Iterator for MiniBatches {
+    type Item = Result<MiniBatch, DataLoaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.options.batch_size;
+        let mut payload = Vec::with_capacity(size);
+        let mut shards: Vec<String> = Vec::new();
+        while payload.len() < size {
+            let shard = match self.current.take() {
+                Some(shard) => shard,
+                None => match self.loader.next() {
+                    Ok(Some(shard)) => {
+                        self.offset = 0;
+                        shard
+                    }
+                    Ok(None) => break,
+                    Err(err) => return Some(Err(err)),
+                },
+            };
+            let take = (size - payload.len()).min(shard.payload.len() - self.offset);
+            if take == 0 {
+                continue;
+            }
+            payload.extend_from_slice(&shard.payload[self.offset..self.offset + take]);
+            self.offset += take;
+            if shards.last() != Some(&shard.record.shard) {
+                shards.push(shard.record.shard.clone());
+            }
+            if self.offset < shard.payload.len() {
+                self.current = Some(shard);
+            }
+        }
+        if payload.is_empty() || (payload.len() < size && self.options.drop_last) {
+            return None;
+        }
+        let padding = if self.options.pad_to_batch {
+            size - payload.len()
+        } else {
+            0
+        };
+        payload.resize(payload.len() + padding, self.options.fill);
+        Some(Ok(MiniBatch {
+            payload,
+            padding,
+            shards,
+        }))
+    }
+}
+
 /// Errors raised by the dataset loader.
 #[derive(Debug, Error)]
 pub enum DataLoaderError {
@@ -125,6 +348,9 @@ pub enum DataLoaderError {
     /// JSON parsing failure.
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
+    /// Mini-batch options are inconsistent.
+    #[error("invalid batch options: {0}")]
+    InvalidBatchOptions(String),
 }
 
 #[cfg(test)]
@@ -165,4 +391,197 @@ mod tests {
         assert_eq!(second.record.shard, "shards/shard-00000.data");
         assert!(loader.next().unwrap().is_none());
     }
+
+    fn ten_byte_loader(dir: &Path) -> ShardLoader {
+        fs::write(dir.join("shard.data"), b"0123456789").unwrap();
+        let index = json!({
+            "name": "odd",
+            "records": [{"shard": "shard.data", "samples": 10, "importance": 1.0}]
+        });
+        fs::write(dir.join("index.json"), index.to_string()).unwrap();
+        ShardLoader::from_index(DatasetIndex::load(dir.join("index.json")).unwrap(), 1).unwrap()
+    }
+
+    fn collect(batches: MiniBatches) -> Vec<MiniBatch> {
+        batches.map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn drop_last_discards_partial_batch() {
+        let tmp = tempdir().unwrap();
+        let batches = collect(
+            ten_byte_loader(tmp.path())
+                .mini_batches(BatchOptions::new(4).drop_last())
+                .unwrap(),
+        );
+        let payloads: Vec<_> = batches.iter().map(|b| b.payload.as_slice()).collect();
+        assert_eq!(payloads, vec![b"0123".as_slice(), b"4567"]);
+        assert!(batches.iter().all(|b| b.padding == 0));
+    }
+
+    #[test]
+    fn pad_to_batch_fills_partial_batch() {
+        let tmp = tempdir().unwrap();
+        let batches = collect(
+            ten_byte_loader(tmp.path())
+                .mini_batches(BatchOptions::new(4).pad_to_batch(b'_'))
+                .unwrap(),
+        );
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[2].payload, b"89__");
+        assert_eq!(batches[2].padding, 2);
+        assert!(batches.iter().all(|b| b.payload.len() == 4));
+    }
+
+    /// Two shards of 5 and 6 bytes, loaded `abcde` first.
+    fn two_shard_loader(dir: &Path) -> ShardLoader {
+        write_dataset(
+            dir,
+            &[
+                ("first.data", 0.9, b"abcde".to_vec()),
+                ("second.data", 0.5, b"fghijk".to_vec()),
+            ],
+        );
+        ShardLoader::from_index(DatasetIndex::load(dir.join("index.json")).unwrap(), 1).unwrap()
+    }
+
+    #[test]
+    fn batches_carry_remainders_across_shards() {
+        let tmp = tempdir().unwrap();
+        let batches = collect(
+            two_shard_loader(tmp.path())
+                .mini_batches(BatchOptions::new(4))
+                .unwrap(),
+        );
+        let payloads: Vec<_> = batches.iter().map(|b| b.payload.as_slice()).collect();
+        assert_eq!(payloads, vec![b"abcd".as_slice(), b"efgh", b"ijk"]);
+        assert_eq!(batches[0].shards, ["first.data"]);
+        assert_eq!(batches[1].shards, ["first.data", "second.data"]);
+        assert_eq!(batches[2].shards, ["second.data"]);
+
+        let dropped = collect(
+            two_shard_loader(tmp.path())
+                .mini_batches(BatchOptions::new(4).drop_last())
+                .unwrap(),
+        );
+        assert_eq!(dropped.len(), 2);
+        assert_eq!(dropped[1].payload, b"efgh");
+
+        let padded = collect(
+            two_shard_loader(tmp.path())
+                .mini_batches(BatchOptions::new(4).pad_to_batch(b'_'))
+                .unwrap(),
+        );
+        assert_eq!(padded.len(), 3);
+        assert_eq!(padded[1].padding, 0);
+        assert_eq!(padded[2].payload, b"ijk_");
+        assert_eq!(padded[2].padding, 1);
+    }
+
+    #[test]
+    fn conflicting_batch_options_are_rejected() {
+        let tmp = tempdir().unwrap();
+        let options = BatchOptions::new(4).drop_last().pad_to_batch(0);
+        assert!(matches!(
+            ten_byte_loader(tmp.path()).mini_batches(options),
+            Err(DataLoaderError::InvalidBatchOptions(_))
+        ));
+    }
+
+    fn load_all(dataset_dir: &Path) -> Vec<ShardBatch> {
+        let index = DatasetIndex::load(dataset_dir.join("index.json")).unwrap();
+        let mut loader = ShardLoader::from_index(index, 1).unwrap();
+        let mut batches = Vec::new();
+        while let Some(batch) = loader.next().unwrap() {
+            batches.push(batch);
+        }
+        batches
+    }
+
+    fn write_dataset(dataset_dir: &Path, shards: &[(&str, f32, Vec<u8>)]) {
+        fs::create_dir_all(dataset_dir).unwrap();
+        let records: Vec<_> = shards
+            .iter()
+            .map(|(name, importance, bytes)| {
+                fs::write(dataset_dir.join(name), bytes).unwrap();
+                json!({ "shard": name, "samples": 16, "importance": importance })
+            })
+            .collect();
+        let index = json!({ "name": "compressed", "records": records });
+        fs::write(dataset_dir.join("index.json"), index.to_string()).unwrap();
+    }
+
+    #[test]
+    fn compressed_shards_load_like_plain_ones() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let first = b"alpha,beta,gamma,delta\n".repeat(16);
+        let second = b"0.1,0.2,0.3,0.4\n".repeat(16);
+        let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+        gzip.write_all(&first).unwrap();
+        let gzip = gzip.finish().unwrap();
+        let zstd = zstd::stream::encode_all(second.as_slice(), 3).unwrap();
+
+        let tmp = tempdir().unwrap();
+        let plain_dir = tmp.path().join("plain");
+        let packed_dir = tmp.path().join("packed");
+        write_dataset(
+            &plain_dir,
+            &[("a.data", 0.9, first), ("b.data", 0.5, second)],
+        );
+        write_dataset(
+            &packed_dir,
+            &[
+                ("a.data.gz", 0.9, gzip.clone()),
+                ("b.data", 0.5, zstd.clone()),
+            ],
+        );
+
+        let index = DatasetIndex::load(packed_dir.join("index.json")).unwrap();
+        assert_eq!(index.records[0].compression, ShardCompression::Gzip);
+        assert_eq!(index.records[1].compression, ShardCompression::Zstd);
+
+        let plain = load_all(&plain_dir);
+        let packed = load_all(&packed_dir);
+        assert_eq!(plain.len(), packed.len());
+        for (plain, packed) in plain.iter().zip(&packed) {
+            assert_eq!(plain.payload, packed.payload);
+            assert_eq!(plain.record.samples, packed.record.samples);
+        }
+        assert_eq!(packed[0].stored_bytes, gzip.len());
+        assert_eq!(packed[1].stored_bytes, zstd.len());
+        assert!(packed[1].payload.len() > packed[1].stored_bytes);
+    }
+
+    #[test]
+    fn explicit_none_skips_detection() {
+        let tmp = tempdir().unwrap();
+        let raw = vec![0x1f, 0x8b, b'r', b'a', b'w'];
+        fs::write(tmp.path().join("raw.gz"), &raw).unwrap();
+        let index = json!({
+            "name": "raw",
+            "records": [{ "shard": "raw.gz", "samples": 1, "importance": 1.0, "compression": "none" }]
+        });
+        fs::write(tmp.path().join("index.json"), index.to_string()).unwrap();
+
+        let index = DatasetIndex::load(tmp.path().join("index.json")).unwrap();
+        assert_eq!(index.records[0].compression, ShardCompression::None);
+        let mut loader = ShardLoader::from_index(index, 1).unwrap();
+        assert_eq!(loader.next().unwrap().unwrap().payload, raw);
+    }
+
+    #[test]
+    fn missing_shard_fails_detection() {
+        let tmp = tempdir().unwrap();
+        let index = json!({
+            "name": "missing",
+            "records": [{ "shard": "absent.data", "samples": 1, "importance": 1.0 }]
+        });
+        fs::write(tmp.path().join("index.json"), index.to_string()).unwrap();
+        assert!(matches!(
+            DatasetIndex::load(tmp.path().join("index.json")),
+            Err(DataLoaderError::Io(_))
+        ));
+    }
 }
@@ -25,6 +25,8 @@ use serde_json::json;
 use shared_logging::LogLevel;
 use trainer::{Trainer, TrainingConfig};
 
+pub use trainer::TrainingProgress;
+
 use crate::telemetry::LearningTelemetry;
 
 /// Deep learning pipeline orchestrator.
@@ -21,6 +21,17 @@ impl Default for TrainingConfig {
     }
 }
 
+/// Progress reported after each optimization step.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TrainingProgress {
+    /// Zero-based step index.
+    pub step: u64,
+    /// Training loss at this step.
+    pub train_loss: f32,
+    /// Validation loss at this step.
+    pub val_loss: f32,
+}
+
 /// Trainer responsible for running optimization.
 #[derive(Debug)]
 pub struct Trainer {
@@ -36,6 +47,15 @@ impl Trainer {
 
     /// Runs training with dummy gradients and returns snapshots.
     pub fn train(&self, model: &mut DenseModel) -> Vec<ModelSnapshot> {
+        self.train_with_progress(model, |_| {})
+    }
+
+    /// Runs training, invoking `on_progress` synchronously after every step.
+    pub fn train_with_progress(
+        &self,
+        model: &mut DenseModel,
+        mut on_progress: impl FnMut(TrainingProgress),
+    ) -> Vec<ModelSnapshot> {
         let mut snapshots = Vec::new();
         let shape = model.weight_shape();
         let grad = Array2::from_elem(shape, 0.05);
@@ -43,8 +63,40 @@ impl Trainer {
             // Fake gradient descent step
             model.sgd_step(&grad, self.config.learning_rate);
             let loss = 1.0 / (step as f32 + 1.0);
-            snapshots.push(model.snapshot(step, loss));
+            let snapshot = model.snapshot(step, loss);
+            on_progress(TrainingProgress {
+                step,
+                train_loss: loss,
+                val_loss: snapshot.val_loss,
+            });
+            snapshots.push(snapshot);
         }
         snapshots
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, path::Path, rc::Rc};
+
+    #[test]
+    fn progress_fires_once_per_step() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("dataset/dense_weights.json");
+        let mut model = DenseModel::from_dataset_file(path).unwrap();
+        let trainer = Trainer::new(TrainingConfig {
+            learning_rate: 0.01,
+            steps: 6,
+        });
+        // `Rc` is not `Send`; the callback runs on the caller's thread.
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&seen);
+        let snapshots = trainer.train_with_progress(&mut model, move |progress| {
+            sink.borrow_mut().push(progress.step);
+        });
+        let seen = seen.borrow().clone();
+        assert_eq!(seen.len(), snapshots.len());
+        assert_eq!(seen, (0..6).collect::<Vec<_>>());
+        assert!(seen.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -79,6 +79,8 @@ impl Default for DevicePreference {
 #[derive(Debug, Clone)]
 pub struct AllocationPlan {
     selected: Vec<DeviceInfo>,
+    fallback: Option<DevicePreference>,
+    fell_back: bool,
 }
 
 impl AllocationPlan {
@@ -88,8 +90,31 @@ impl AllocationPlan {
         &self.selected
     }
 
-    fn new(selected: Vec<DeviceInfo>) -> Self {
-        Self { selected }
+    /// Preference used when the selected devices cannot be acquired at run time.
+    #[must_use]
+    pub fn with_fallback(mut self, preference: DevicePreference) -> Self {
+        self.fallback = Some(preference);
+        self
+    }
+
+    /// Fallback preference, if any.
+    #[must_use]
+    pub const fn fallback(&self) -> Option<&DevicePreference> {
+        self.fallback.as_ref()
+    }
+
+    /// Whether the plan was degraded to its fallback preference.
+    #[must_use]
+    pub const fn fell_back(&self) -> bool {
+        self.fell_back
+    }
+
+    const fn new(selected: Vec<DeviceInfo>) -> Self {
+        Self {
+            selected,
+            fallback: None,
+            fell_back: false,
+        }
     }
 }
 
@@ -179,6 +204,29 @@ impl DeviceManager {
         };
         AllocationPlan::new(candidates.into_iter().take(take).collect())
     }
+
+    /// Confirms the planned devices right before a run.
+    ///
+    /// When any selected device fails `is_available`, the plan is re-allocated with its
+    /// fallback preference (same device count) and marked as fallen back; plans without a
+    /// fallback are returned unchanged.
+    #[must_use]
+    pub fn acquire(
+        &self,
+        plan: AllocationPlan,
+        is_available: impl Fn(&DeviceInfo) -> bool,
+    ) -> AllocationPlan {
+        if plan.selected.iter().all(&is_available) {
+            return plan;
+        }
+        let Some(fallback) = plan.fallback else {
+            return plan;
+        };
+        let mut degraded = self.allocate(fallback.clone(), plan.selected.len());
+        degraded.fallback = Some(fallback);
+        degraded.fell_back = true;
+        degraded
+    }
 }
 
 fn detect_devices() -> Result<Vec<DeviceInfo>, DeviceDetectionError> {
@@ -275,6 +323,23 @@ mod tests {
         assert!(plan.devices().iter().all(|d| d.kind == DeviceKind::Cpu));
     }
 
+    #[test]
+    fn unavailable_gpus_fall_back_to_cpu() {
+        let manager = DeviceManager::from_devices(sample_devices());
+        let plan = manager
+            .allocate(DevicePreference::GpuFirst, 1)
+            .with_fallback(DevicePreference::CpuOnly);
+        assert_eq!(plan.devices()[0].kind, DeviceKind::NvidiaGpu);
+
+        let plan = manager.acquire(plan, |dev| dev.kind == DeviceKind::Cpu);
+        assert!(plan.fell_back());
+        assert_eq!(plan.devices().len(), 1);
+        assert_eq!(plan.devices()[0].kind, DeviceKind::Cpu);
+
+        let plan = manager.acquire(plan, |_| true);
+        assert!(plan.fell_back());
+    }
+
     #[test]
     fn explicit_selection() {
         let manager = DeviceManager::from_devices(sample_devices());
@@ -1,24 +1,73 @@
 //! Learning orchestration runtime tying classical, deep, combining, and subsidiary loops together.
 
 use crate::{
-    classical_ml::{editor::Dataset, reporter::TrainingReport, ClassicalMlPipeline},
+    classical_ml::{
+        editor::{Dataset, DatasetError},
+        reporter::TrainingReport,
+        ClassicalMlPipeline,
+    },
     combining::{
         combining::CombinationEngine, func::normalize_weights, reviewer::CombinationReviewer,
     },
     deep_learning::{reporter::DlReport, DeepLearningPipeline},
     modules::{LearningModuleDescriptor, LearningModuleRegistry},
     subsidiary::{
-        define::{SubsidiaryPlan, SubsidiaryTask},
+        define::{PlanStatus, SubsidiaryPlan, SubsidiaryTask},
         submodels::SubsidiaryModel,
         SubsidiaryLearningRuntime,
     },
+    summary::{ReportSummary, ToReportSummary},
     telemetry::LearningTelemetry,
 };
 
+use std::{fs, path::Path};
+
 use crate::classical_ml::submodel::SubModelManager;
 use crate::combining::combining::CombinationResult;
+use crate::dataloader::DataLoaderError;
+use crate::device_manager::DeviceDetectionError;
 use serde_json::{json, Value};
 use shared_logging::LogLevel;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors surfaced by the learning runtime.
+#[derive(Debug, Error, Clone)]
+pub enum LearningError {
+    /// Dataset could not be read or parsed.
+    #[error("dataset error: {0}")]
+    Dataset(String),
+    /// Device discovery or allocation failed.
+    #[error("device error: {0}")]
+    Device(String),
+    /// Model loading or training failed.
+    #[error("model error: {0}")]
+    Model(String),
+    /// Ensemble combination was invalid.
+    #[error("combination error: {0}")]
+    Combination(String),
+    /// Telemetry sink failure.
+    #[error("telemetry error: {0}")]
+    Telemetry(String),
+}
+
+impl From<DataLoaderError> for LearningError {
+    fn from(err: DataLoaderError) -> Self {
+        Self::Dataset(err.to_string())
+    }
+}
+
+impl From<DatasetError> for LearningError {
+    fn from(err: DatasetError) -> Self {
+        Self::Dataset(err.to_string())
+    }
+}
+
+impl From<DeviceDetectionError> for LearningError {
+    fn from(err: DeviceDetectionError) -> Self {
+        Self::Device(err.to_string())
+    }
+}
 
 /// Top-level runtime coordinating every learning pipeline.
 pub struct LearningRuntime {
@@ -60,15 +109,43 @@ impl LearningRuntime {
         self.registry.register(descriptor);
     }
 
+    /// Loads a JSON dataset from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LearningError::Dataset`] if the file cannot be read, is not a valid
+    /// dataset document, or fails validation.
+    pub fn load_dataset(&self, path: impl AsRef<Path>) -> Result<Dataset, LearningError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| LearningError::Dataset(format!("reading {}: {err}", path.display())))?;
+        let dataset: Dataset = serde_json::from_str(&contents)
+            .map_err(|err| LearningError::Dataset(format!("parsing {}: {err}", path.display())))?;
+        dataset.validate().map_err(|err| {
+            LearningError::Dataset(format!("validating {}: {err}", path.display()))
+        })?;
+        Ok(dataset)
+    }
+
     /// Runs the classical ML pipeline.
-    pub fn run_classical(&self, dataset: Dataset) -> anyhow::Result<TrainingReport> {
-        self.classical
+    pub fn run_classical(&self, dataset: Dataset) -> Result<TrainingReport, LearningError> {
+        dataset.validate()?;
+        let report = self
+            .classical
             .run_with_telemetry(dataset, self.telemetry.as_ref())
+            .map_err(|err| LearningError::Model(format!("{err:#}")))?;
+        self.emit_summary("classical", &report.to_summary());
+        Ok(report)
     }
 
     /// Runs the deep learning pipeline.
-    pub fn run_deep(&mut self) -> anyhow::Result<DlReport> {
-        self.deep.run_with_telemetry(self.telemetry.as_ref())
+    pub fn run_deep(&mut self) -> Result<DlReport, LearningError> {
+        let report = self
+            .deep
+            .run_with_telemetry(self.telemetry.as_ref())
+            .map_err(|err| LearningError::Model(format!("{err:#}")))?;
+        self.emit_summary("deep", &report.to_summary());
+        Ok(report)
     }
 
     /// Combines predictions from submodels.
@@ -76,7 +153,13 @@ impl LearningRuntime {
         &self,
         mut manager: SubModelManager,
         features: &[Vec<f32>],
-    ) -> anyhow::Result<CombinationResult> {
+    ) -> Result<CombinationResult, LearningError> {
+        let total_weight: f32 = manager.models.iter().map(|model| model.weight).sum();
+        if manager.models.is_empty() || total_weight <= f32::EPSILON {
+            return Err(LearningError::Combination(format!(
+                "submodel weights sum to {total_weight}"
+            )));
+        }
         normalize_weights(&mut manager.models);
         self.log(
             LogLevel::Debug,
@@ -87,7 +170,9 @@ impl LearningRuntime {
             "learning.combine.invoked",
             json!({ "submodels": manager.models.len(), "batch": features.len() }),
         );
-        self.combination.combine(&manager, features)
+        self.combination
+            .combine(&manager, features)
+            .map_err(|err| LearningError::Combination(err.to_string()))
     }
 
     /// Adds a subsidiary task + model for planning.
@@ -137,6 +222,40 @@ impl LearningRuntime {
         plans
     }
 
+    /// Records the execution status of a subsidiary plan, returning false if it is unknown.
+    pub fn mark_subsidiary_status(&self, plan_id: Uuid, status: PlanStatus) -> bool {
+        let updated = self.subsidiary.mark_status(plan_id, status);
+        self.log(
+            if updated {
+                LogLevel::Info
+            } else {
+                LogLevel::Warn
+            },
+            "subsidiary_plan_status",
+            json!({ "plan_id": plan_id, "status": status, "updated": updated }),
+        );
+        if updated {
+            self.event(
+                "learning.subsidiary.plan_status",
+                json!({ "plan_id": plan_id, "status": status }),
+            );
+        }
+        updated
+    }
+
+    /// Subsidiary plans waiting for an executor.
+    #[must_use]
+    pub fn pending_subsidiary_plans(&self) -> Vec<SubsidiaryPlan> {
+        self.subsidiary.pending_plans()
+    }
+
+    fn emit_summary(&self, pipeline: &str, summary: &ReportSummary) {
+        self.event(
+            "learning.report.summary",
+            json!({ "pipeline": pipeline, "summary": summary }),
+        );
+    }
+
     fn log(&self, level: LogLevel, message: &str, metadata: Value) {
         if let Some(telemetry) = self.telemetry.as_ref() {
             let _ = telemetry.log(level, message, metadata);
@@ -149,3 +268,48 @@ impl LearningRuntime {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classical_ml::{ml::LinearRegressionModel, submodel::SubModel};
+    use tempfile::tempdir;
+
+    #[test]
+    fn missing_dataset_is_dataset_error() {
+        let dir = tempdir().unwrap();
+        let runtime = LearningRuntime::new();
+        let err = runtime
+            .load_dataset(dir.path().join("missing.json"))
+            .unwrap_err();
+        assert!(matches!(err, LearningError::Dataset(_)));
+    }
+
+    #[test]
+    fn zero_weight_combination_is_combination_error() {
+        let runtime = LearningRuntime::new();
+        let mut manager = SubModelManager::default();
+        manager.add(SubModel::new(LinearRegressionModel::new(2), 0.0));
+        let err = runtime
+            .combine_predictions(manager, &[vec![1.0, 2.0]])
+            .unwrap_err();
+        assert!(matches!(err, LearningError::Combination(_)));
+    }
+
+    #[test]
+    fn completed_subsidiary_plans_leave_the_pending_queue() {
+        let mut runtime = LearningRuntime::new();
+        runtime.add_subsidiary_model(SubsidiaryModel::new("supply_chain", "forecast", 0.8));
+        runtime.add_subsidiary_task(SubsidiaryTask::new("supply_chain", "restock", 7));
+        runtime.add_subsidiary_task(SubsidiaryTask::new("supply_chain", "reroute", 9));
+        let plans = runtime.plan_subsidiary("supply_chain");
+        assert_eq!(plans.len(), 2);
+        assert!(plans.iter().all(|plan| plan.status == PlanStatus::Pending));
+
+        assert!(runtime.mark_subsidiary_status(plans[0].id, PlanStatus::Done));
+        assert!(!runtime.mark_subsidiary_status(Uuid::new_v4(), PlanStatus::Failed));
+        let pending = runtime.pending_subsidiary_plans();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, plans[1].id);
+    }
+}
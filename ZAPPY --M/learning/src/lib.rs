@@ -48,17 +48,28 @@ pub mod pipeline;
 #[path = "../replay.rs"]
 pub mod replay;
 
+/// Pipeline-independent report summaries.
+#[path = "../summary.rs"]
+pub mod summary;
+
 /// High-level orchestration entry point.
 #[path = "../main.rs"]
 pub mod orchestration_entry;
 
-pub use classical_ml::{editor::Dataset as ClassicalDataset, ClassicalMlPipeline};
-pub use combining::{CombinationEngine, CombinationResult, CombinationReviewer};
-pub use dataloader::{DatasetIndex, ShardBatch, ShardLoader};
+pub use classical_ml::{
+    editor::{Dataset as ClassicalDataset, DatasetError},
+    ClassicalMlPipeline,
+};
+pub use combining::{CombinationEngine, CombinationResult, CombinationReviewer, SubModelEstimate};
+pub use dataloader::{
+    BatchOptions, DatasetIndex, MiniBatch, MiniBatches, ShardBatch, ShardCompression, ShardLoader,
+};
 pub use deep_learning::DeepLearningPipeline;
 pub use device_manager::{AllocationPlan, DeviceInfo, DeviceKind, DeviceManager, DevicePreference};
 pub use modules::{LearningModuleDescriptor, LearningModuleRegistry};
+pub use orchestration_entry::{LearningError, LearningRuntime};
 pub use pipeline::{ExperienceArchive, ExperienceHub, ExperienceRecorder, PipelineEnvelope};
 pub use replay::ExperienceReplayService;
 pub use subsidiary::SubsidiaryLearningRuntime;
+pub use summary::{ReportSummary, ToReportSummary};
 pub use telemetry::{LearningTelemetry, LearningTelemetryBuilder};
@@ -31,13 +31,34 @@ impl SubsidiaryTask {
     }
 }
 
+/// Execution lifecycle of a [`SubsidiaryPlan`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// Waiting for an executor.
+    #[default]
+    Pending,
+    /// Picked up by an executor.
+    Running,
+    /// Executed successfully.
+    Done,
+    /// Execution failed.
+    Failed,
+}
+
 /// Plan mapping tasks to specific submodels.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubsidiaryPlan {
+    /// Identifier used to report execution status.
+    #[serde(default = "Uuid::new_v4")]
+    pub id: Uuid,
     /// Task reference.
     pub task_id: Uuid,
     /// Selected submodel.
     pub submodel_id: Uuid,
     /// Notes.
     pub notes: String,
+    /// Execution status.
+    #[serde(default)]
+    pub status: PlanStatus,
 }
@@ -9,7 +9,7 @@ pub mod searching;
 /// Registry of subsidiary models.
 pub mod submodels;
 
-use define::{SubsidiaryPlan, SubsidiaryTask};
+use define::{PlanStatus, SubsidiaryPlan, SubsidiaryTask};
 use saver::SubsidiaryStore;
 use searching::TaskSearcher;
 use submodels::{SubsidiaryModel, SubsidiaryModelRegistry};
@@ -40,9 +40,11 @@ impl SubsidiaryLearningRuntime {
         for result in tasks {
             if let Some(model) = self.models.best_for_domain(domain) {
                 let plan = SubsidiaryPlan {
+                    id: uuid::Uuid::new_v4(),
                     task_id: result.task.id,
                     submodel_id: model.id,
                     notes: format!("score={:.2}", result.score),
+                    status: PlanStatus::Pending,
                 };
                 self.store.add_plan(plan.clone());
                 plans.push(plan);
@@ -50,4 +52,16 @@ impl SubsidiaryLearningRuntime {
         }
         plans
     }
+
+    /// Records the execution status of a plan, returning false if the plan is unknown.
+    #[must_use]
+    pub fn mark_status(&self, plan_id: uuid::Uuid, status: PlanStatus) -> bool {
+        self.store.mark_status(plan_id, status)
+    }
+
+    /// Plans not yet picked up by an executor.
+    #[must_use]
+    pub fn pending_plans(&self) -> Vec<SubsidiaryPlan> {
+        self.store.pending_plans()
+    }
 }
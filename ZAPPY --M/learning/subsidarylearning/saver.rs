@@ -1,13 +1,13 @@
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 
-use super::define::{SubsidiaryPlan, SubsidiaryTask};
+use super::define::{PlanStatus, SubsidiaryPlan, SubsidiaryTask};
 
 /// Persistence layer for subsidiary tasks and plans.
 #[derive(Debug, Default, Clone)]
 pub struct SubsidiaryStore {
     tasks: std::sync::Arc<RwLock<IndexMap<uuid::Uuid, SubsidiaryTask>>>,
-    plans: std::sync::Arc<RwLock<Vec<SubsidiaryPlan>>>,
+    plans: std::sync::Arc<RwLock<IndexMap<uuid::Uuid, SubsidiaryPlan>>>,
 }
 
 impl SubsidiaryStore {
@@ -18,7 +18,16 @@ impl SubsidiaryStore {
 
     /// Adds a plan.
     pub fn add_plan(&self, plan: SubsidiaryPlan) {
-        self.plans.write().push(plan);
+        self.plans.write().insert(plan.id, plan);
+    }
+
+    /// Updates the status of a plan, returning false if the plan is unknown.
+    #[must_use]
+    pub fn mark_status(&self, plan_id: uuid::Uuid, status: PlanStatus) -> bool {
+        self.plans.write().get_mut(&plan_id).is_some_and(|plan| {
+            plan.status = status;
+            true
+        })
     }
 
     /// Returns all tasks filtered by domain.
@@ -35,6 +44,17 @@ impl SubsidiaryStore {
     /// Returns all plans.
     #[must_use]
     pub fn plans(&self) -> Vec<SubsidiaryPlan> {
-        self.plans.read().clone()
+        self.plans.read().values().cloned().collect()
+    }
+
+    /// Returns plans still waiting for an executor, in creation order.
+    #[must_use]
+    pub fn pending_plans(&self) -> Vec<SubsidiaryPlan> {
+        self.plans
+            .read()
+            .values()
+            .filter(|plan| plan.status == PlanStatus::Pending)
+            .cloned()
+            .collect()
     }
 }
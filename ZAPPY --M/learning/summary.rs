@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    classical_ml::reporter::{CrossValReport, TrainingReport},
+    deep_learning::reporter::DlReport,
+};
+
+/// Pipeline-independent view of a training report, used for uniform events.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportSummary {
+    /// Model or experiment name.
+    pub model: String,
+    /// Name of the metric the run is judged by (lower is better).
+    pub primary_metric: String,
+    /// Value of the primary metric.
+    pub metric_value: f32,
+    /// Training steps (epochs, folds, or optimizer steps) covered by the report.
+    pub steps: u64,
+}
+
+/// Reports that can be reduced to a [`ReportSummary`].
+pub trait ToReportSummary {
+    /// Builds the unified summary.
+    fn to_summary(&self) -> ReportSummary;
+}
+
+impl ToReportSummary for TrainingReport {
+    fn to_summary(&self) -> ReportSummary {
+        ReportSummary {
+            model: self.model.clone(),
+            primary_metric: "mse".into(),
+            metric_value: self.mse,
+            steps: self.epochs as u64,
+        }
+    }
+}
+
+impl ToReportSummary for CrossValReport {
+    fn to_summary(&self) -> ReportSummary {
+        ReportSummary {
+            model: self.model.clone(),
+            primary_metric: "mean_mse".into(),
+            metric_value: self.mean_mse,
+            steps: self.folds.len() as u64,
+        }
+    }
+}
+
+impl ToReportSummary for DlReport {
+    /// Uses the best validation loss; reports without snapshots yield `NaN`.
+    fn to_summary(&self) -> ReportSummary {
+        ReportSummary {
+            model: self.experiment.clone(),
+            primary_metric: "val_loss".into(),
+            metric_value: self.best().map_or(f32::NAN, |snapshot| snapshot.val_loss),
+            steps: self
+                .snapshots
+                .iter()
+                .map(|snapshot| snapshot.step)
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deep_learning::model::ModelSnapshot;
+
+    #[test]
+    fn classical_report_summary() {
+        let report = TrainingReport {
+            model: "linear".into(),
+            mse: 0.125,
+            epochs: 40,
+        };
+        assert_eq!(
+            report.to_summary(),
+            ReportSummary {
+                model: "linear".into(),
+                primary_metric: "mse".into(),
+                metric_value: 0.125,
+                steps: 40,
+            }
+        );
+    }
+
+    #[test]
+    fn deep_report_summary_uses_best_snapshot() {
+        let snapshot = |step, val_loss| ModelSnapshot {
+            id: uuid::Uuid::new_v4(),
+            step,
+            val_loss,
+        };
+        let report = DlReport {
+            experiment: "dense-v2".into(),
+            snapshots: vec![snapshot(100, 0.8), snapshot(200, 0.3), snapshot(300, 0.4)],
+        };
+        let summary = report.to_summary();
+        assert_eq!(summary.model, "dense-v2");
+        assert_eq!(summary.primary_metric, "val_loss");
+        assert!((summary.metric_value - 0.3).abs() < f32::EPSILON);
+        assert_eq!(summary.steps, 300);
+
+        let empty = DlReport {
+            experiment: "dense-v3".into(),
+            snapshots: Vec::new(),
+        };
+        assert!(empty.to_summary().metric_value.is_nan());
+        assert_eq!(empty.to_summary().steps, 0);
+    }
+}
@@ -9,7 +9,7 @@ use shared_logging::LogLevel;
 
 use crate::{
     long_term::{LongTermMemory, MemoryLevel},
-    short_term::{MemoryEntry, MemoryImportance, ShortTermMemory},
+    short_term::{MemoryEntry, MemoryImportance, ShortTermMemory, SimilarMemory},
     telemetry::MemoryTelemetry,
 };
 
@@ -63,13 +63,22 @@ impl MemoryRuntime {
         importance: MemoryImportance,
         tags: impl IntoIterator<Item = impl Into<String>>,
     ) -> MemoryEntry {
-        let entry = MemoryEntry::new(content, importance, tags);
-        self.short_term.push(entry.clone());
+        let (entry, deduplicated) = self
+            .short_term
+            .push_dedup(MemoryEntry::new(content, importance, tags));
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 LogLevel::Info,
-                "memory.capture",
-                json!({ "importance": format!("{:?}", entry.importance), "tags": entry.tags }),
+                if deduplicated {
+                    "memory.capture.deduplicated"
+                } else {
+                    "memory.capture"
+                },
+                json!({
+                    "importance": format!("{:?}", entry.importance),
+                    "tags": entry.tags,
+                    "access_count": entry.access_count
+                }),
             );
         }
         entry
@@ -84,6 +93,19 @@ impl MemoryRuntime {
         self.short_term.search_by_tag(tag)
     }
 
+    /// Recalls the `top_k` short-term memories whose content best matches `query`.
+    #[must_use]
+    pub fn recall(&self, query: &str, top_k: usize) -> Vec<SimilarMemory> {
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(
+                LogLevel::Debug,
+                "memory.recall",
+                json!({ "query": query, "top_k": top_k }),
+            );
+        }
+        self.short_term.search_similar(query, top_k)
+    }
+
     /// Flushes high-importance memories to long-term storage.
     pub fn flush_high_importance(&self) -> Result<Vec<PathBuf>> {
         let drained = self
@@ -142,4 +164,15 @@ mod tests {
         let paths = runtime.flush_high_importance().unwrap();
         assert_eq!(paths.len(), 1);
     }
+
+    #[test]
+    fn runtime_dedups_repeated_captures() {
+        let dir = tempdir().unwrap();
+        let short = ShortTermMemory::new(8).with_dedup_window(chrono::Duration::minutes(1));
+        let runtime = MemoryRuntime::with_paths(short, LongTermMemory::new(dir.path()));
+        runtime.capture("disk at 91%", MemoryImportance::Medium, ["ops"]);
+        let entry = runtime.capture("disk at 91%", MemoryImportance::Medium, ["ops"]);
+        assert_eq!(entry.access_count, 1);
+        assert_eq!(runtime.search("ops").len(), 1);
+    }
 }
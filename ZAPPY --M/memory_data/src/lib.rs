@@ -20,6 +20,8 @@ pub mod telemetry;
 #[path = "../main.rs"]
 pub mod orchestration_entry;
 
-pub use long_term::{LongTermMemory, MemoryLevel};
-pub use short_term::{MemoryEntry, MemoryImportance, ShortTermMemory};
+pub use long_term::{CompressionReport, LongTermMemory, MemoryLevel};
+pub use short_term::{
+    Clock, EvictionCallback, MemoryEntry, MemoryImportance, ShortTermMemory, SimilarMemory,
+};
 pub use telemetry::{MemoryTelemetry, MemoryTelemetryBuilder};
@@ -1,6 +1,11 @@
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
@@ -23,6 +28,15 @@ pub enum MemoryLevel {
 }
 
 impl MemoryLevel {
+    /// Every level, lowest to highest.
+    pub const ALL: [Self; 5] = [
+        Self::Level1,
+        Self::Level2,
+        Self::Level3,
+        Self::Level4,
+        Self::Level5,
+    ];
+
     /// Directory name for the level.
     #[must_use]
     pub fn dir_name(&self) -> &'static str {
@@ -87,6 +101,15 @@ pub enum MemoryStorageError {
     Serialization(#[from] serde_json::Error),
 }
 
+/// Result of [`LongTermMemory::compress_older_than`].
+#[derive(Debug, Default)]
+pub struct CompressionReport {
+    /// Entries compressed in this pass.
+    pub compressed: usize,
+    /// Files left uncompressed because reading or compressing them failed.
+    pub failed: Vec<(PathBuf, MemoryStorageError)>,
+}
+
 /// File-system backed long-term memory.
 #[derive(Debug, Clone)]
 pub struct LongTermMemory {
@@ -118,27 +141,72 @@ impl LongTermMemory {
     }
 
     /// Loads the most recent `limit` memories for the given level.
+    ///
+    /// Compressed (`.json.gz`) entries are decompressed transparently.
     #[must_use]
     pub fn load_recent(&self, level: MemoryLevel, limit: usize) -> Vec<StoredMemory> {
-        let dir = self.base_path.join("long_term").join(level.dir_name());
-        let mut entries = Vec::new();
-        if let Ok(read_dir) = fs::read_dir(dir) {
-            for entry in read_dir
-                .flatten()
-                .filter(|e| e.path().extension().map_or(false, |ext| ext == "json"))
-            {
-                if let Ok(data) = fs::read(entry.path()) {
-                    if let Ok(memory) = serde_json::from_slice::<StoredMemory>(&data) {
-                        entries.push(memory);
-                    }
-                }
-            }
-        }
+        let mut entries = self
+            .level_files(level)
+            .iter()
+            .filter_map(|path| read_stored(path).ok())
+            .collect::<Vec<_>>();
         entries.sort_by(|a, b| b.persisted_at.cmp(&a.persisted_at));
         entries.truncate(limit);
         entries
     }
 
+    /// Returns up to `limit` memories at a level whose content or tags contain `needle`.
+    #[must_use]
+    pub fn query(&self, level: MemoryLevel, needle: &str, limit: usize) -> Vec<StoredMemory> {
+        let needle = needle.to_lowercase();
+        let mut matches = self.load_recent(level, usize::MAX);
+        matches.retain(|memory| {
+            memory.content.to_lowercase().contains(&needle)
+                || memory.tags.iter().any(|tag| tag.to_lowercase() == needle)
+        });
+        matches.truncate(limit);
+        matches
+    }
+
+    /// Gzip-compresses entries persisted more than `age` ago, across all levels.
+    ///
+    /// Compressed files stay in their level directory as `<id>.json.gz`. A file that
+    /// cannot be read or compressed is left in place and reported without stopping the
+    /// pass.
+    #[must_use]
+    pub fn compress_older_than(&self, age: Duration) -> CompressionReport {
+        let cutoff = Utc::now() - age;
+        let mut report = CompressionReport::default();
+        for level in MemoryLevel::ALL {
+            for path in self.level_files(level) {
+                if is_compressed(&path) {
+                    continue;
+                }
+                match compress_if_older(&path, cutoff) {
+                    Ok(true) => report.compressed += 1,
+                    Ok(false) => {}
+                    Err(err) => report.failed.push((path, err)),
+                }
+            }
+        }
+        report
+    }
+
+    fn level_files(&self, level: MemoryLevel) -> Vec<PathBuf> {
+        let dir = self.base_path.join("long_term").join(level.dir_name());
+        fs::read_dir(dir)
+            .map(|read_dir| {
+                read_dir
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.extension().is_some_and(|ext| ext == "json") || is_compressed(path)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Clears all stored memories (primarily used in tests).
     pub fn clear(&self) -> std::io::Result<()> {
         if self.base_path.exists() {
@@ -155,6 +223,44 @@ impl LongTermMemory {
     }
 }
 
+fn is_compressed(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".json.gz"))
+}
+
+/// Compresses `path` when it was persisted before `cutoff`, returning whether it did.
+fn compress_if_older(path: &Path, cutoff: DateTime<Utc>) -> Result<bool, MemoryStorageError> {
+    let data = fs::read(path)?;
+    let stored = serde_json::from_slice::<StoredMemory>(&data)?;
+    if stored.persisted_at > cutoff {
+        return Ok(false);
+    }
+    let target = path.with_extension("json.gz");
+    let written = fs::File::create(&target).and_then(|file| {
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(&data)?;
+        encoder.finish()?.sync_all()
+    });
+    if let Err(err) = written {
+        let _ = fs::remove_file(&target);
+        return Err(err.into());
+    }
+    fs::remove_file(path)?;
+    Ok(true)
+}
+
+fn read_stored(path: &Path) -> Result<StoredMemory, MemoryStorageError> {
+    let mut file = fs::File::open(path)?;
+    let mut data = Vec::new();
+    if is_compressed(path) {
+        GzDecoder::new(file).read_to_end(&mut data)?;
+    } else {
+        file.read_to_end(&mut data)?;
+    }
+    Ok(serde_json::from_slice(&data)?)
+}
+
 impl Default for LongTermMemory {
     fn default() -> Self {
         Self::new(env!("CARGO_MANIFEST_DIR"))
@@ -177,4 +283,41 @@ mod tests {
         assert_eq!(memories.len(), 1);
         assert_eq!(memories[0].content, "critical insight");
     }
+
+    #[test]
+    fn compressed_memories_remain_queryable() {
+        let dir = tempdir().unwrap();
+        let repo = LongTermMemory::new(dir.path());
+        let first = MemoryEntry::new("reactor anomaly", MemoryImportance::High, ["core"]);
+        let second = MemoryEntry::new("routine note", MemoryImportance::Low, ["ops"]);
+        let first_path = repo.persist(first, MemoryLevel::Level5).unwrap();
+        repo.persist(second, MemoryLevel::Level1).unwrap();
+
+        assert_eq!(repo.compress_older_than(Duration::zero()).compressed, 2);
+        assert!(!first_path.exists());
+        assert!(first_path.with_extension("json.gz").exists());
+
+        let matches = repo.query(MemoryLevel::Level5, "anomaly", 10);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].content, "reactor anomaly");
+        assert_eq!(repo.load_recent(MemoryLevel::Level1, 10).len(), 1);
+        assert_eq!(repo.compress_older_than(Duration::zero()).compressed, 0);
+    }
+
+    #[test]
+    fn unreadable_files_are_reported_and_skipped() {
+        let dir = tempdir().unwrap();
+        let repo = LongTermMemory::new(dir.path());
+        let entry = MemoryEntry::new("reactor anomaly", MemoryImportance::High, ["core"]);
+        let good = repo.persist(entry, MemoryLevel::Level5).unwrap();
+        let corrupt = good.with_file_name("corrupt.json");
+        fs::write(&corrupt, "{ not json").unwrap();
+
+        let report = repo.compress_older_than(Duration::zero());
+        assert_eq!(report.compressed, 1);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, corrupt);
+        assert!(corrupt.exists());
+        assert!(good.with_extension("json.gz").exists());
+    }
 }
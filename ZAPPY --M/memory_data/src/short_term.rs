@@ -1,12 +1,17 @@
-use chrono::{DateTime, Utc};
-use indexmap::IndexSet;
+use chrono::{DateTime, Duration, Utc};
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::{
+    collections::{hash_map::DefaultHasher, VecDeque},
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 use uuid::Uuid;
 
 /// Importance of a memory entry. Used to choose retention and persistence strategy.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MemoryImportance {
     /// Routine observations; lowest retention.
     Low,
@@ -29,6 +34,12 @@ pub struct MemoryEntry {
     pub tags: IndexSet<String>,
     /// Importance level.
     pub importance: MemoryImportance,
+    /// Number of times the entry was re-captured or accessed.
+    #[serde(default)]
+    pub access_count: u32,
+    /// Timestamp of the most recent capture or access.
+    #[serde(default = "Utc::now")]
+    pub last_accessed: DateTime<Utc>,
 }
 
 impl MemoryEntry {
@@ -43,28 +54,76 @@ impl MemoryEntry {
         for tag in tags {
             tag_set.insert(tag.into());
         }
+        let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
             content: content.into(),
-            created_at: Utc::now(),
+            created_at: now,
             tags: tag_set,
             importance,
+            access_count: 0,
+            last_accessed: now,
         }
     }
+
+    /// Returns a hash of the entry content used for deduplication.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.content.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Entry returned by similarity search along with its score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarMemory {
+    /// Matching entry.
+    pub entry: MemoryEntry,
+    /// Bag-of-words cosine similarity to the query (0-1).
+    pub similarity: f32,
 }
 
+/// Callback invoked with entries evicted from short-term memory.
+pub type EvictionCallback = Arc<dyn Fn(&MemoryEntry) + Send + Sync>;
+
+/// Source of the current time used for expiry.
+pub type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
 /// Short-term memory implemented as a rolling buffer with tag-based queries.
-#[derive(Debug)]
 pub struct ShortTermMemory {
     capacity: usize,
+    dedup_window: Option<Duration>,
+    ttl: Option<Duration>,
+    on_evict: Option<EvictionCallback>,
+    evict_expired: bool,
+    clock: Clock,
     entries: RwLock<VecDeque<MemoryEntry>>,
 }
 
+impl fmt::Debug for ShortTermMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortTermMemory")
+            .field("capacity", &self.capacity)
+            .field("dedup_window", &self.dedup_window)
+            .field("ttl", &self.ttl)
+            .field("on_evict", &self.on_evict.is_some())
+            .field("evict_expired", &self.evict_expired)
+            .field("entries", &self.entries)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Clone for ShortTermMemory {
     fn clone(&self) -> Self {
         let snapshot = self.entries.read().clone();
         Self {
             capacity: self.capacity,
+            dedup_window: self.dedup_window,
+            ttl: self.ttl,
+            on_evict: self.on_evict.clone(),
+            evict_expired: self.evict_expired,
+            clock: Arc::clone(&self.clock),
             entries: RwLock::new(snapshot),
         }
     }
@@ -76,10 +135,53 @@ impl ShortTermMemory {
     pub fn new(capacity: usize) -> Self {
         Self {
             capacity: capacity.max(1),
+            dedup_window: None,
+            ttl: None,
+            on_evict: None,
+            evict_expired: false,
+            clock: Arc::new(Utc::now),
             entries: RwLock::new(VecDeque::new()),
         }
     }
 
+    /// Expires entries once they are older than `ttl`, regardless of capacity.
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Invokes `callback` with every entry evicted to make room for new ones.
+    #[must_use]
+    pub fn with_eviction_callback(
+        mut self,
+        callback: impl Fn(&MemoryEntry) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_evict = Some(Arc::new(callback));
+        self
+    }
+
+    /// Also routes TTL-expired entries to the eviction callback.
+    #[must_use]
+    pub const fn route_expired_to_eviction(mut self, enabled: bool) -> Self {
+        self.evict_expired = enabled;
+        self
+    }
+
+    /// Replaces the clock used to age entries (useful for tests).
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Fn() -> DateTime<Utc> + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Collapses identical captures seen within `window` into a single entry.
+    #[must_use]
+    pub const fn with_dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = Some(window);
+        self
+    }
+
     /// Returns the number of stored entries.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -90,9 +192,58 @@ impl ShortTermMemory {
     pub fn push(&self, entry: MemoryEntry) {
         let mut entries = self.entries.write();
         entries.push_back(entry);
-        if entries.len() > self.capacity {
-            entries.pop_front();
+        let evicted = if entries.len() > self.capacity {
+            entries.pop_front()
+        } else {
+            None
+        };
+        drop(entries);
+        if let (Some(evicted), Some(callback)) = (evicted, &self.on_evict) {
+            callback(&evicted);
+        }
+    }
+
+    /// Removes entries older than the TTL and returns them, oldest first.
+    ///
+    /// Does nothing when no TTL is configured.
+    pub fn expire_now(&self) -> Vec<MemoryEntry> {
+        let Some(ttl) = self.ttl else {
+            return Vec::new();
+        };
+        let cutoff = (self.clock)() - ttl;
+        let expired = self.drain_filter(|entry| entry.created_at < cutoff);
+        if self.evict_expired {
+            if let Some(callback) = &self.on_evict {
+                for entry in &expired {
+                    callback(entry);
+                }
+            }
         }
+        expired
+    }
+
+    /// Stores an entry unless identical content was captured within the dedup window,
+    /// in which case the existing entry is touched and keeps the higher importance of the
+    /// two. Returns the stored entry and whether it was deduplicated.
+    pub fn push_dedup(&self, entry: MemoryEntry) -> (MemoryEntry, bool) {
+        if let Some(window) = self.dedup_window {
+            let cutoff = entry.created_at - window;
+            let hash = entry.content_hash();
+            let mut entries = self.entries.write();
+            if let Some(existing) = entries.iter_mut().rev().find(|existing| {
+                existing.last_accessed >= cutoff
+                    && existing.content_hash() == hash
+                    && existing.content == entry.content
+            }) {
+                existing.access_count += 1;
+                existing.last_accessed = entry.created_at;
+                existing.importance = existing.importance.max(entry.importance);
+                existing.tags.extend(entry.tags);
+                return (existing.clone(), true);
+            }
+        }
+        self.push(entry.clone());
+        (entry, false)
     }
 
     /// Returns a snapshot of all entries.
@@ -101,9 +252,10 @@ impl ShortTermMemory {
         self.entries.read().iter().cloned().collect()
     }
 
-    /// Searches for entries containing the specified tag.
+    /// Searches for entries containing the specified tag, expiring stale entries first.
     #[must_use]
     pub fn search_by_tag(&self, tag: &str) -> Vec<MemoryEntry> {
+        self.expire_now();
         let tag_lower = tag.to_lowercase();
         self.entries
             .read()
@@ -113,6 +265,33 @@ impl ShortTermMemory {
             .collect()
     }
 
+    /// Ranks entries by bag-of-words cosine similarity to `query`, returning at most
+    /// `top_k` entries that share at least one token with it. Stale entries are expired
+    /// first.
+    #[must_use]
+    pub fn search_similar(&self, query: &str, top_k: usize) -> Vec<SimilarMemory> {
+        self.expire_now();
+        let query_terms = term_counts(query);
+        if query_terms.is_empty() || top_k == 0 {
+            return Vec::new();
+        }
+        let mut matches = self
+            .entries
+            .read()
+            .iter()
+            .filter_map(|entry| {
+                let similarity = cosine(&query_terms, &term_counts(&entry.content));
+                (similarity > 0.0).then(|| SimilarMemory {
+                    entry: entry.clone(),
+                    similarity,
+                })
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        matches.truncate(top_k);
+        matches
+    }
+
     /// Drains entries matching the predicate (used when persisting to long-term storage).
     pub fn drain_filter<F>(&self, mut predicate: F) -> Vec<MemoryEntry>
     where
@@ -133,6 +312,29 @@ impl ShortTermMemory {
     }
 }
 
+fn term_counts(text: &str) -> IndexMap<String, f32> {
+    let mut counts = IndexMap::new();
+    for token in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+    {
+        *counts.entry(token.to_lowercase()).or_insert(0.0) += 1.0;
+    }
+    counts
+}
+
+fn cosine(a: &IndexMap<String, f32>, b: &IndexMap<String, f32>) -> f32 {
+    let dot = a
+        .iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+        .sum::<f32>();
+    if dot == 0.0 {
+        return 0.0;
+    }
+    let norm = |counts: &IndexMap<String, f32>| counts.values().map(|w| w * w).sum::<f32>().sqrt();
+    dot / (norm(a) * norm(b))
+}
+
 impl Default for ShortTermMemory {
     fn default() -> Self {
         Self::new(256)
@@ -178,4 +380,101 @@ mod tests {
         let matches = memory.search_by_tag("Ops");
         assert_eq!(matches.len(), 1);
     }
+
+    #[test]
+    fn similar_content_ranks_first() {
+        let memory = ShortTermMemory::new(8);
+        for content in [
+            "database failover completed for the east region",
+            "weekly team lunch moved to friday",
+            "east region database latency rising after failover",
+        ] {
+            memory.push(MemoryEntry::new(
+                content,
+                MemoryImportance::Medium,
+                Vec::<&str>::new(),
+            ));
+        }
+        let matches = memory.search_similar("database latency in east region", 2);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(
+            matches[0].entry.content,
+            "east region database latency rising after failover"
+        );
+        assert!(matches[0].similarity > matches[1].similarity);
+        assert!(memory.search_similar("unrelated gardening", 3).is_empty());
+    }
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+
+        let origin = Utc::now();
+        let elapsed = Arc::new(AtomicI64::new(0));
+        let evicted = Arc::new(AtomicUsize::new(0));
+        let clock = Arc::clone(&elapsed);
+        let counter = Arc::clone(&evicted);
+        let memory = ShortTermMemory::new(8)
+            .with_ttl(Duration::minutes(5))
+            .with_clock(move || origin + Duration::seconds(clock.load(Ordering::SeqCst)))
+            .with_eviction_callback(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .route_expired_to_eviction(true);
+        let mut entry = MemoryEntry::new("cache warmed", MemoryImportance::Low, ["ops"]);
+        entry.created_at = origin;
+        memory.push(entry);
+
+        elapsed.store(60, Ordering::SeqCst);
+        assert!(memory.expire_now().is_empty());
+        assert_eq!(memory.search_by_tag("ops").len(), 1);
+
+        elapsed.store(6 * 60, Ordering::SeqCst);
+        assert!(memory.search_by_tag("ops").is_empty());
+        assert_eq!(memory.len(), 0);
+        assert_eq!(evicted.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn identical_captures_within_window_are_merged() {
+        let memory = ShortTermMemory::new(4).with_dedup_window(Duration::seconds(30));
+        let (first, merged) = memory.push_dedup(MemoryEntry::new(
+            "heartbeat ok",
+            MemoryImportance::Low,
+            ["ops"],
+        ));
+        assert!(!merged);
+        let (second, merged) = memory.push_dedup(MemoryEntry::new(
+            "heartbeat ok",
+            MemoryImportance::Low,
+            ["health"],
+        ));
+        assert!(merged);
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.access_count, 1);
+        assert_eq!(memory.len(), 1);
+        assert_eq!(memory.search_by_tag("health").len(), 1);
+    }
+
+    #[test]
+    fn merged_captures_keep_the_highest_importance() {
+        let memory = ShortTermMemory::new(4).with_dedup_window(Duration::seconds(30));
+        memory.push_dedup(MemoryEntry::new(
+            "disk full",
+            MemoryImportance::Low,
+            ["ops"],
+        ));
+        let (merged, _) = memory.push_dedup(MemoryEntry::new(
+            "disk full",
+            MemoryImportance::High,
+            ["ops"],
+        ));
+        assert_eq!(merged.importance, MemoryImportance::High);
+        let (merged, _) = memory.push_dedup(MemoryEntry::new(
+            "disk full",
+            MemoryImportance::Medium,
+            ["ops"],
+        ));
+        assert_eq!(merged.importance, MemoryImportance::High);
+    }
 }
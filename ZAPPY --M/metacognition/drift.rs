@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::orchestration_entry::ReflectionReport;
+
+/// Metric whose value moved beyond the drift threshold between consecutive cycles.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MetricDrift {
+    /// Metric key (the observation description).
+    pub metric: String,
+    /// Value in the previous report.
+    pub previous: f32,
+    /// Value in the current report.
+    pub current: f32,
+}
+
+impl MetricDrift {
+    /// Signed change from the previous cycle.
+    #[must_use]
+    pub fn delta(&self) -> f32 {
+        self.current - self.previous
+    }
+
+    /// Whether the metric got worse (severity increased).
+    #[must_use]
+    pub fn is_deteriorating(&self) -> bool {
+        self.delta() > 0.0
+    }
+}
+
+/// Compares consecutive reflection reports and keeps a bounded report history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftDetector {
+    threshold: f32,
+    capacity: usize,
+    history: VecDeque<ReflectionReport>,
+}
+
+impl Default for DriftDetector {
+    fn default() -> Self {
+        Self::new(0.15, 16)
+    }
+}
+
+impl DriftDetector {
+    /// Flags changes larger than `threshold`, remembering the last `capacity` reports.
+    #[must_use]
+    pub fn new(threshold: f32, capacity: usize) -> Self {
+        Self {
+            threshold,
+            capacity: capacity.max(1),
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Records `report` and returns metrics that drifted since the previous report.
+    ///
+    /// Metrics absent from either report are not compared.
+    pub fn observe(&mut self, report: &ReflectionReport) -> Vec<MetricDrift> {
+        let drifts = self.history.back().map_or_else(Vec::new, |previous| {
+            report
+                .metrics
+                .iter()
+                .filter_map(|(metric, &current)| {
+                    let &prior = previous.metrics.get(metric)?;
+                    ((current - prior).abs() > self.threshold).then(|| MetricDrift {
+                        metric: metric.clone(),
+                        previous: prior,
+                        current,
+                    })
+                })
+                .collect()
+        });
+        self.history.push_back(report.clone());
+        while self.history.len() > self.capacity {
+            self.history.pop_front();
+        }
+        drifts
+    }
+
+    /// Reports retained for comparison, oldest first.
+    #[must_use]
+    pub const fn history(&self) -> &VecDeque<ReflectionReport> {
+        &self.history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(severity: f32) -> ReflectionReport {
+        let mut report = ReflectionReport::default();
+        report.metrics.insert("cache hit rate".into(), 0.1);
+        report.metrics.insert("p99 latency".into(), severity);
+        report
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut detector = DriftDetector::new(0.1, 2);
+        for severity in [0.1, 0.2, 0.3] {
+            detector.observe(&report(severity));
+        }
+        assert_eq!(detector.history().len(), 2);
+        assert!((detector.history()[0].metrics["p99 latency"] - 0.2).abs() < 1e-6);
+    }
+}
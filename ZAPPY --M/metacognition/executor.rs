@@ -1,11 +1,18 @@
-use std::collections::VecDeque;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use async_trait::async_trait;
 use rand::{seq::SliceRandom, thread_rng};
 use serde::{Deserialize, Serialize};
+use tokio::time::timeout;
 
 use crate::{
     cmd::{ReflectionCommand, ReflectionVerb},
     methods::{ReflectionMethod, ReflectionPlan},
+    script::{ReflectionScript, ScriptOp, ScriptStep},
 };
 
 /// Aggregated insight emitted after executing reflection commands.
@@ -128,3 +135,249 @@ impl ReflectionExecutor {
         }
     }
 }
+
+/// Raw output produced by running a single script step.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StepOutput {
+    /// Captured standard output.
+    pub stdout: String,
+    /// Captured standard error.
+    pub stderr: String,
+    /// Exit code; non-zero means failure.
+    pub exit_code: i32,
+}
+
+/// Backend that performs a whitelisted script step.
+#[async_trait]
+pub trait StepRunner: Send + Sync {
+    /// Runs the step and returns its captured output.
+    async fn run(&self, step: &ScriptStep) -> anyhow::Result<StepOutput>;
+}
+
+/// Runner that performs steps in-process without side effects.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SimulatedStepRunner;
+
+#[async_trait]
+impl StepRunner for SimulatedStepRunner {
+    async fn run(&self, step: &ScriptStep) -> anyhow::Result<StepOutput> {
+        let verb = match step.op {
+            ScriptOp::Summarize => "summarized",
+            ScriptOp::Collect => "collected",
+            ScriptOp::Simulate => "simulated",
+            ScriptOp::Counterfactual => "drafted counterfactuals for",
+            ScriptOp::Mitigate => "proposed mitigation for",
+        };
+        Ok(StepOutput {
+            stdout: format!("{verb} {}", step.target),
+            ..StepOutput::default()
+        })
+    }
+}
+
+/// Terminal state of a script step.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    /// Step exited with code zero.
+    Succeeded,
+    /// Step exited non-zero or the runner errored.
+    Failed,
+    /// Step exceeded the per-step timeout.
+    TimedOut,
+    /// Step was not run because an earlier step failed.
+    Skipped,
+}
+
+/// Captured result of one script step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepResult {
+    /// Step position in the script.
+    pub ordinal: usize,
+    /// Operation performed.
+    pub op: ScriptOp,
+    /// Final status.
+    pub status: StepStatus,
+    /// Captured standard output.
+    pub stdout: String,
+    /// Captured standard error.
+    pub stderr: String,
+    /// Exit code when the step ran to completion.
+    pub exit_code: Option<i32>,
+    /// Wall-clock duration in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl StepResult {
+    const fn skipped(step: &ScriptStep) -> Self {
+        Self {
+            ordinal: step.ordinal,
+            op: step.op,
+            status: StepStatus::Skipped,
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: None,
+            duration_ms: 0,
+        }
+    }
+}
+
+/// Aggregated results of running a reflection script.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionReport {
+    /// Per-step results in script order.
+    pub steps: Vec<StepResult>,
+}
+
+impl ExecutionReport {
+    /// Whether every step that ran succeeded and none were skipped.
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|step| step.status == StepStatus::Succeeded)
+    }
+
+    /// Steps that failed or timed out.
+    pub fn failures(&self) -> impl Iterator<Item = &StepResult> {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step.status, StepStatus::Failed | StepStatus::TimedOut))
+    }
+}
+
+/// Runs script steps one at a time with a per-step timeout, capturing their output.
+#[derive(Clone)]
+pub struct SandboxedExecutor {
+    runner: Arc<dyn StepRunner>,
+    step_timeout: Duration,
+}
+
+impl Default for SandboxedExecutor {
+    fn default() -> Self {
+        Self::new(Arc::new(SimulatedStepRunner), Duration::from_secs(5))
+    }
+}
+
+impl SandboxedExecutor {
+    /// Creates an executor around `runner`.
+    #[must_use]
+    pub fn new(runner: Arc<dyn StepRunner>, step_timeout: Duration) -> Self {
+        Self {
+            runner,
+            step_timeout,
+        }
+    }
+
+    /// Runs the script; a failed step skips the rest unless it is marked continue-on-error.
+    pub async fn run(&self, script: &ReflectionScript) -> ExecutionReport {
+        let mut report = ExecutionReport::default();
+        let mut halted = false;
+        for step in &script.steps {
+            if halted {
+                report.steps.push(StepResult::skipped(step));
+                continue;
+            }
+            let result = self.run_step(step).await;
+            if result.status != StepStatus::Succeeded && !step.continue_on_error {
+                halted = true;
+            }
+            report.steps.push(result);
+        }
+        report
+    }
+
+    async fn run_step(&self, step: &ScriptStep) -> StepResult {
+        let started = Instant::now();
+        let mut result = StepResult::skipped(step);
+        match timeout(self.step_timeout, self.runner.run(step)).await {
+            Ok(Ok(output)) => {
+                result.status = if output.exit_code == 0 {
+                    StepStatus::Succeeded
+                } else {
+                    StepStatus::Failed
+                };
+                result.stdout = output.stdout;
+                result.stderr = output.stderr;
+                result.exit_code = Some(output.exit_code);
+            }
+            Ok(Err(err)) => {
+                result.status = StepStatus::Failed;
+                result.stderr = err.to_string();
+            }
+            Err(_) => {
+                result.status = StepStatus::TimedOut;
+                result.stderr = format!("step timed out after {:?}", self.step_timeout);
+            }
+        }
+        result.duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::ReflectionMethod;
+
+    struct FailingFirstRunner;
+
+    #[async_trait]
+    impl StepRunner for FailingFirstRunner {
+        async fn run(&self, step: &ScriptStep) -> anyhow::Result<StepOutput> {
+            Ok(if step.ordinal == 1 {
+                StepOutput {
+                    stdout: String::new(),
+                    stderr: "collector unavailable".into(),
+                    exit_code: 2,
+                }
+            } else {
+                StepOutput {
+                    stdout: "ok".into(),
+                    ..StepOutput::default()
+                }
+            })
+        }
+    }
+
+    fn script(continue_on_error: bool) -> ReflectionScript {
+        ReflectionScript {
+            observation: "latency regression".into(),
+            method: ReflectionMethod::RapidReview,
+            steps: vec![
+                ScriptStep {
+                    ordinal: 1,
+                    op: ScriptOp::Collect,
+                    target: "Gather latency traces".into(),
+                    continue_on_error,
+                },
+                ScriptStep {
+                    ordinal: 2,
+                    op: ScriptOp::Mitigate,
+                    target: "Propose interventions".into(),
+                    continue_on_error: false,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn failing_step_skips_the_rest() {
+        let executor = SandboxedExecutor::new(Arc::new(FailingFirstRunner), Duration::from_secs(1));
+        let report = executor.run(&script(false)).await;
+        assert!(!report.succeeded());
+        assert_eq!(report.steps[0].status, StepStatus::Failed);
+        assert_eq!(report.steps[0].exit_code, Some(2));
+        assert_eq!(report.steps[0].stderr, "collector unavailable");
+        assert_eq!(report.steps[1].status, StepStatus::Skipped);
+        assert_eq!(report.failures().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn continue_on_error_runs_later_steps() {
+        let executor = SandboxedExecutor::new(Arc::new(FailingFirstRunner), Duration::from_secs(1));
+        let report = executor.run(&script(true)).await;
+        assert_eq!(report.steps[1].status, StepStatus::Succeeded);
+        assert_eq!(report.steps[1].stdout, "ok");
+    }
+}
@@ -1 +1,36 @@
-
+use serde::{Deserialize, Serialize};
+
+/// Bounds applied to a metacognition reflection cycle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetacognitionOptions {
+    /// Deepest reflection level; 1 reflects only on the submitted observations,
+    /// higher values also reflect on the corrections proposed at the level above.
+    pub max_reflection_depth: usize,
+    /// Maximum reflection passes (plan, execute, review) per cycle; `None` is unbounded.
+    pub compute_budget: Option<usize>,
+}
+
+impl Default for MetacognitionOptions {
+    fn default() -> Self {
+        Self {
+            max_reflection_depth: 1,
+            compute_budget: None,
+        }
+    }
+}
+
+impl MetacognitionOptions {
+    /// Sets the deepest reflection level (at least 1).
+    #[must_use]
+    pub fn max_reflection_depth(mut self, depth: usize) -> Self {
+        self.max_reflection_depth = depth.max(1);
+        self
+    }
+
+    /// Caps the number of reflection passes per cycle.
+    #[must_use]
+    pub const fn compute_budget(mut self, passes: usize) -> Self {
+        self.compute_budget = Some(passes);
+        self
+    }
+}
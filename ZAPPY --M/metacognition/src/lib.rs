@@ -23,6 +23,9 @@ pub mod reviewer;
 /// Script generation utilities.
 pub mod script;
 
+/// Drift detection across reflection cycles.
+#[path = "../drift.rs"]
+pub mod drift;
 /// Executors used by the runtime.
 #[path = "../executor.rs"]
 pub mod executor;
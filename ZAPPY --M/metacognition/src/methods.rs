@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::cognition::SelfObservation;
+use crate::{cognition::SelfObservation, script::ScriptOp};
 
 /// Available reflection methods controlling scope and intensity.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -14,6 +14,20 @@ pub enum ReflectionMethod {
     ComprehensiveAudit,
 }
 
+impl ReflectionMethod {
+    /// Picks a method whose depth matches the observation severity.
+    #[must_use]
+    pub fn for_severity(severity: f32) -> Self {
+        if severity >= 0.7 {
+            Self::ComprehensiveAudit
+        } else if severity >= 0.4 {
+            Self::StructuredAnalysis
+        } else {
+            Self::RapidReview
+        }
+    }
+}
+
 /// Reflection plan describing steps to execute.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReflectionPlan {
@@ -40,20 +54,20 @@ impl ReflectionPlanner {
     ) -> anyhow::Result<ReflectionPlan> {
         let steps = match method {
             ReflectionMethod::RapidReview => vec![
-                "Summarize observation".into(),
-                "List immediate mitigation".into(),
+                ScriptOp::Summarize.step("observation"),
+                ScriptOp::Mitigate.step("immediate impact"),
             ],
             ReflectionMethod::StructuredAnalysis => vec![
-                "Summarize observation".into(),
-                "Gather supporting signals".into(),
-                "Draft counterfactuals".into(),
-                "Propose interventions".into(),
+                ScriptOp::Summarize.step("observation"),
+                ScriptOp::Collect.step("supporting signals"),
+                ScriptOp::Counterfactual.step("explanations"),
+                ScriptOp::Mitigate.step("through targeted interventions"),
             ],
             ReflectionMethod::ComprehensiveAudit => vec![
-                "Summarize observation".into(),
-                "Aggregate multi-domain context".into(),
-                "Run risk simulations".into(),
-                "Present final remediation strategy".into(),
+                ScriptOp::Summarize.step("observation"),
+                ScriptOp::Collect.step("multi-domain context"),
+                ScriptOp::Simulate.step("risk scenarios"),
+                ScriptOp::Mitigate.step("with a final remediation strategy"),
             ],
         };
 
@@ -1,8 +1,9 @@
 //! Metacognition runtime orchestrating cognition, scripts, and reviewers.
 
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use shared_logging::LogLevel;
@@ -11,10 +12,14 @@ use tokio::sync::Mutex;
 use crate::{
     cmd::CommandSynthesizer,
     cognition::SelfObservation,
-    executor::{CommandInsight, ReflectionExecutor},
-    metacognition::MetaCognitionKernel,
-    methods::{ReflectionMethod, ReflectionPlanner},
-    reviewer::MetaReviewer,
+    drift::{DriftDetector, MetricDrift},
+    executor::{
+        CommandInsight, ExecutionInsight, ExecutionReport, ReflectionExecutor, SandboxedExecutor,
+    },
+    metacognition::{MetaCognitionKernel, ReflectionOutcome},
+    methods::{ReflectionMethod, ReflectionPlan, ReflectionPlanner},
+    options::MetacognitionOptions,
+    reviewer::{Correction, MetaReviewer, Misalignment},
     script::ScriptEngine,
     telemetry::MetacognitionTelemetry,
 };
@@ -25,9 +30,19 @@ pub struct MetacognitionRuntime {
     kernel: Arc<Mutex<MetaCognitionKernel>>,
     reviewer: MetaReviewer,
     script_engine: ScriptEngine,
+    sandbox: SandboxedExecutor,
+    options: MetacognitionOptions,
+    drift: Arc<parking_lot::Mutex<DriftDetector>>,
     telemetry: Option<MetacognitionTelemetry>,
 }
 
+struct PlanRun {
+    plan: ReflectionPlan,
+    outcome: ReflectionOutcome,
+    insight: ExecutionInsight,
+    execution: ExecutionReport,
+}
+
 impl MetacognitionRuntime {
     /// Creates a new runtime with default parameters.
     #[must_use]
@@ -36,10 +51,33 @@ impl MetacognitionRuntime {
             kernel: Arc::new(Mutex::new(MetaCognitionKernel::default())),
             reviewer: MetaReviewer::default(),
             script_engine: ScriptEngine::default(),
+            sandbox: SandboxedExecutor::default(),
+            options: MetacognitionOptions::default(),
+            drift: Arc::new(parking_lot::Mutex::new(DriftDetector::default())),
             telemetry: None,
         }
     }
 
+    /// Replaces the detector comparing consecutive cycle reports.
+    #[must_use]
+    pub fn with_drift_detector(mut self, detector: DriftDetector) -> Self {
+        self.drift = Arc::new(parking_lot::Mutex::new(detector));
+        self
+    }
+
+    /// Returns a copy of the drift detector, including its report history.
+    #[must_use]
+    pub fn drift_detector(&self) -> DriftDetector {
+        self.drift.lock().clone()
+    }
+
+    /// Overrides the depth and budget bounds of reflection cycles.
+    #[must_use]
+    pub const fn with_options(mut self, options: MetacognitionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Attaches telemetry sinks.
     #[must_use]
     pub fn with_telemetry(mut self, telemetry: MetacognitionTelemetry) -> Self {
@@ -47,6 +85,13 @@ impl MetacognitionRuntime {
         self
     }
 
+    /// Overrides the executor used to run generated scripts.
+    #[must_use]
+    pub fn with_sandbox(mut self, sandbox: SandboxedExecutor) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
     /// Sets telemetry after construction.
     pub fn set_telemetry(&mut self, telemetry: MetacognitionTelemetry) {
         self.telemetry = Some(telemetry);
@@ -64,20 +109,12 @@ impl MetacognitionRuntime {
         observation: SelfObservation,
         method: ReflectionMethod,
     ) -> Result<ReflectionDigest> {
-        let planner = ReflectionPlanner::default();
-        let plan = planner.plan(observation.clone(), method)?;
-        if let Some(tel) = &self.telemetry {
-            let _ = tel.log(
-                LogLevel::Info,
-                "metacognition.plan.generated",
-                json!({ "observation": observation.description, "method": format!("{:?}", method) }),
-            );
-        }
-        let script = self.script_engine.render(&plan)?;
-        let commands = CommandSynthesizer::synthesize(&plan);
-        let insight = ReflectionExecutor::execute(&plan, &commands);
-        let mut kernel = self.kernel.lock().await;
-        let outcome = kernel.execute(plan, script).await?;
+        let PlanRun {
+            outcome,
+            insight,
+            execution,
+            ..
+        } = self.run_plan(observation, method).await?;
         self.reviewer.review(&outcome, &insight)?;
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
@@ -100,6 +137,181 @@ impl MetacognitionRuntime {
             summary: outcome.summary,
             resilience: insight.resiliency_score,
             diagnostics: insight.diagnostics,
+            execution,
+            depth: 1,
+        })
+    }
+
+    /// Runs a full reflection cycle over a batch of observations.
+    ///
+    /// Each observation is planned with a method matching its severity, executed,
+    /// and reviewed; misalignments and their proposed corrections are collected
+    /// instead of aborting the cycle. Corrections are reflected on again up to
+    /// [`MetacognitionOptions::max_reflection_depth`], and the cycle stops early
+    /// with a partial report once the compute budget is spent.
+    ///
+    /// Metrics that drifted since the previous cycle are flagged on the report, and
+    /// corrections for deteriorating ones get their priority raised by the drift.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when planning an observation fails, when its plan produces a step
+    /// outside the script whitelist, or when the kernel fails to execute the plan. Review
+    /// failures are recorded as misalignments instead.
+    pub async fn reflect_cycle(
+        &self,
+        observations: Vec<SelfObservation>,
+    ) -> Result<ReflectionReport> {
+        let mut report = ReflectionReport::default();
+        let mut queue: VecDeque<(SelfObservation, usize)> =
+            observations.into_iter().map(|obs| (obs, 1)).collect();
+        let mut passes = 0;
+        while let Some((observation, depth)) = queue.pop_front() {
+            if self
+                .options
+                .compute_budget
+                .is_some_and(|budget| passes >= budget)
+            {
+                report.budget_truncated = true;
+                if let Some(tel) = &self.telemetry {
+                    let _ = tel.log(
+                        LogLevel::Warn,
+                        "metacognition.cycle.budget_exhausted",
+                        json!({ "passes": passes, "skipped": queue.len() + 1 }),
+                    );
+                }
+                break;
+            }
+            passes += 1;
+            if depth == 1 {
+                let metric = report
+                    .metrics
+                    .entry(observation.description.clone())
+                    .or_default();
+                *metric = metric.max(observation.severity);
+            }
+            let method = ReflectionMethod::for_severity(observation.severity);
+            let PlanRun {
+                plan,
+                outcome,
+                insight,
+                execution,
+            } = self.run_plan(observation.clone(), method).await?;
+            let mut misalignment = self.reviewer.misalignment(&observation, &insight);
+            if let Err(err) = self.reviewer.review(&outcome, &insight) {
+                let flagged = misalignment.get_or_insert_with(|| Misalignment {
+                    observation_id: observation.id,
+                    description: observation.description.clone(),
+                    severity: observation.severity,
+                    tags: Vec::new(),
+                    reason: String::new(),
+                });
+                flagged.reason = format!("review failed: {err}");
+            }
+            if let Some(misalignment) = misalignment {
+                let correction = self.reviewer.propose_correction(&misalignment, &plan);
+                if let Some(tel) = &self.telemetry {
+                    let _ = tel.event(
+                        "metacognition.misalignment.detected",
+                        json!({
+                            "observation_id": misalignment.observation_id,
+                            "reason": misalignment.reason,
+                            "correction": correction.action,
+                        }),
+                    );
+                }
+                if depth < self.options.max_reflection_depth {
+                    queue.push_back((
+                        SelfObservation::new(
+                            format!("follow-up on correction: {}", correction.action),
+                            misalignment.severity / 2.0,
+                        ),
+                        depth + 1,
+                    ));
+                }
+                report.misalignments.push(misalignment);
+                report.corrections.push(correction);
+            }
+            report.digests.push(ReflectionDigest {
+                summary: outcome.summary,
+                resilience: insight.resiliency_score,
+                diagnostics: insight.diagnostics,
+                execution,
+                depth,
+            });
+        }
+        self.flag_drift(&mut report);
+        Ok(report)
+    }
+
+    fn flag_drift(&self, report: &mut ReflectionReport) {
+        report.drift = self.drift.lock().observe(report);
+        for drift in report.drift.iter().filter(|drift| drift.is_deteriorating()) {
+            let observations: Vec<_> = report
+                .misalignments
+                .iter()
+                .filter(|misalignment| misalignment.description == drift.metric)
+                .map(|misalignment| misalignment.observation_id)
+                .collect();
+            for correction in report
+                .corrections
+                .iter_mut()
+                .filter(|correction| observations.contains(&correction.observation_id))
+            {
+                correction.priority = (correction.priority + drift.delta()).min(1.0);
+            }
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.event(
+                    "metacognition.drift.detected",
+                    json!({
+                        "metric": drift.metric,
+                        "previous": drift.previous,
+                        "current": drift.current,
+                    }),
+                );
+            }
+        }
+    }
+
+    async fn run_plan(
+        &self,
+        observation: SelfObservation,
+        method: ReflectionMethod,
+    ) -> Result<PlanRun> {
+        let planner = ReflectionPlanner::default();
+        let plan = planner.plan(observation.clone(), method)?;
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(
+                LogLevel::Info,
+                "metacognition.plan.generated",
+                json!({ "observation": observation.description, "method": format!("{:?}", method) }),
+            );
+        }
+        let script = self.script_engine.generate(&plan)?;
+        let execution = self.sandbox.run(&script).await;
+        if let Some(tel) = &self.telemetry {
+            for failure in execution.failures() {
+                let _ = tel.log(
+                    LogLevel::Warn,
+                    "metacognition.script.step_failed",
+                    json!({
+                        "ordinal": failure.ordinal,
+                        "op": failure.op,
+                        "status": failure.status,
+                        "stderr": failure.stderr,
+                    }),
+                );
+            }
+        }
+        let commands = CommandSynthesizer::synthesize(&plan);
+        let insight = ReflectionExecutor::execute(&plan, &commands);
+        let mut kernel = self.kernel.lock().await;
+        let outcome = kernel.execute(plan.clone(), script.to_string()).await?;
+        Ok(PlanRun {
+            plan,
+            outcome,
+            insight,
+            execution,
         })
     }
 }
@@ -113,4 +325,132 @@ pub struct ReflectionDigest {
     pub resilience: f32,
     /// Command diagnostics.
     pub diagnostics: Vec<CommandInsight>,
+    /// Captured results of the generated script.
+    #[serde(default)]
+    pub execution: ExecutionReport,
+    /// Reflection level that produced the digest (1 for submitted observations).
+    #[serde(default)]
+    pub depth: usize,
+}
+
+/// Result of a reflection cycle over several observations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReflectionReport {
+    /// Per-observation digests, in input order.
+    pub digests: Vec<ReflectionDigest>,
+    /// Misalignments identified during review.
+    pub misalignments: Vec<Misalignment>,
+    /// Corrections proposed for each misalignment.
+    pub corrections: Vec<Correction>,
+    /// Whether the compute budget ran out before every observation was reflected on.
+    #[serde(default)]
+    pub budget_truncated: bool,
+    /// Severity of each submitted observation, keyed by its description.
+    #[serde(default)]
+    pub metrics: IndexMap<String, f32>,
+    /// Metrics that drifted beyond the threshold since the previous cycle.
+    #[serde(default)]
+    pub drift: Vec<MetricDrift>,
+}
+
+impl ReflectionReport {
+    /// Whether the cycle found nothing to correct.
+    #[must_use]
+    pub const fn is_aligned(&self) -> bool {
+        self.misalignments.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn degraded_metric_yields_correction() {
+        let runtime = MetacognitionRuntime::new();
+        let degraded =
+            SelfObservation::new("validation accuracy degraded by 12% after deploy", 0.8);
+        let healthy = SelfObservation::new("planner throughput nominal", 0.1);
+        let degraded_id = degraded.id;
+
+        let report = runtime
+            .reflect_cycle(vec![healthy, degraded])
+            .await
+            .unwrap();
+        assert_eq!(report.digests.len(), 2);
+        assert!(!report.is_aligned());
+        let correction = report
+            .corrections
+            .iter()
+            .find(|c| c.observation_id == degraded_id)
+            .expect("correction for degraded metric");
+        assert!(correction.action.contains("retraining"));
+        let misalignment = report
+            .misalignments
+            .iter()
+            .find(|m| m.observation_id == degraded_id)
+            .unwrap();
+        assert!(misalignment.tags.contains(&"quality".to_string()));
+    }
+
+    fn degraded() -> SelfObservation {
+        SelfObservation::new("validation accuracy degraded by 12% after deploy", 0.8)
+    }
+
+    #[tokio::test]
+    async fn depth_one_does_not_reflect_on_corrections() {
+        let shallow = MetacognitionRuntime::new()
+            .with_options(MetacognitionOptions::default().max_reflection_depth(1))
+            .reflect_cycle(vec![degraded()])
+            .await
+            .unwrap();
+        assert_eq!(shallow.digests.len(), 1);
+        assert!(shallow.digests.iter().all(|digest| digest.depth == 1));
+
+        let deeper = MetacognitionRuntime::new()
+            .with_options(MetacognitionOptions::default().max_reflection_depth(2))
+            .reflect_cycle(vec![degraded()])
+            .await
+            .unwrap();
+        assert_eq!(deeper.digests.len(), 2);
+        assert_eq!(deeper.digests[1].depth, 2);
+    }
+
+    #[tokio::test]
+    async fn worsening_metric_is_flagged_as_drift() {
+        let runtime = MetacognitionRuntime::new().with_drift_detector(DriftDetector::new(0.1, 4));
+        let mut reports = Vec::new();
+        for severity in [0.2, 0.45, 0.7, 0.95] {
+            let report = runtime
+                .reflect_cycle(vec![
+                    SelfObservation::new("p99 latency above SLO", severity),
+                    SelfObservation::new("cache hit rate nominal", 0.1),
+                ])
+                .await
+                .unwrap();
+            reports.push(report);
+        }
+
+        assert!(reports[0].drift.is_empty());
+        for report in &reports[1..] {
+            assert_eq!(report.drift.len(), 1);
+            assert_eq!(report.drift[0].metric, "p99 latency above SLO");
+            assert!(report.drift[0].is_deteriorating());
+        }
+        assert_eq!(runtime.drift_detector().history().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn exhausted_budget_returns_partial_report() {
+        let report = MetacognitionRuntime::new()
+            .with_options(MetacognitionOptions::default().compute_budget(1))
+            .reflect_cycle(vec![
+                degraded(),
+                SelfObservation::new("cache hit rate nominal", 0.1),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(report.digests.len(), 1);
+        assert!(report.budget_truncated);
+    }
 }
@@ -1,10 +1,45 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::{
+    cognition::SelfObservation,
     executor::{CommandOutcome, ExecutionInsight},
+    helper::MetaTagger,
     metacognition::ReflectionOutcome,
+    methods::ReflectionPlan,
 };
 
+/// Severity at or above which an observation is treated as a misalignment.
+const MISALIGNMENT_SEVERITY: f32 = 0.5;
+/// Wording that marks an observation as a regression regardless of severity.
+const DEGRADATION_MARKERS: &[&str] = &["degrad", "regress", "drop", "declin", "spike"];
+
+/// Gap between intended and observed behaviour surfaced during reflection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Misalignment {
+    /// Observation that exposed the gap.
+    pub observation_id: Uuid,
+    /// Observation description.
+    pub description: String,
+    /// Observation severity (0-1).
+    pub severity: f32,
+    /// Categories derived from the description.
+    pub tags: Vec<String>,
+    /// Why the reviewer flagged it.
+    pub reason: String,
+}
+
+/// Corrective action proposed for a misalignment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Correction {
+    /// Observation the correction addresses.
+    pub observation_id: Uuid,
+    /// Recommended action.
+    pub action: String,
+    /// Urgency (0-1).
+    pub priority: f32,
+}
+
 /// Reviewer that validates the outcome of metacognitive reflections.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MetaReviewer;
@@ -32,4 +67,59 @@ impl MetaReviewer {
         }
         Ok(())
     }
+
+    /// Flags the observation when it is severe, reads as a regression, or the
+    /// reflection itself lost resiliency.
+    #[must_use]
+    pub fn misalignment(
+        &self,
+        observation: &SelfObservation,
+        insight: &ExecutionInsight,
+    ) -> Option<Misalignment> {
+        let lowered = observation.description.to_lowercase();
+        let reason = if let Some(marker) = DEGRADATION_MARKERS
+            .iter()
+            .find(|marker| lowered.contains(*marker))
+        {
+            format!("observation reports a regression ('{marker}')")
+        } else if observation.severity >= MISALIGNMENT_SEVERITY {
+            format!("severity {:.2} exceeds tolerance", observation.severity)
+        } else if insight.resiliency_score < 0.0 {
+            format!(
+                "reflection resiliency {:.2} is negative",
+                insight.resiliency_score
+            )
+        } else {
+            return None;
+        };
+        Some(Misalignment {
+            observation_id: observation.id,
+            description: observation.description.clone(),
+            severity: observation.severity,
+            tags: MetaTagger::derive_tags(&lowered),
+            reason,
+        })
+    }
+
+    /// Proposes a correction for the misalignment using the reflection plan.
+    #[must_use]
+    pub fn propose_correction(
+        &self,
+        misalignment: &Misalignment,
+        plan: &ReflectionPlan,
+    ) -> Correction {
+        let action = if misalignment.tags.iter().any(|tag| tag == "quality") {
+            "Re-evaluate against recent failures and schedule targeted retraining".to_string()
+        } else if misalignment.tags.iter().any(|tag| tag == "performance") {
+            "Profile latency hot paths and restore the performance budget".to_string()
+        } else {
+            let remediation = plan.steps.last().map_or("Review manually", String::as_str);
+            format!("{remediation} for '{}'", misalignment.description)
+        };
+        Correction {
+            observation_id: misalignment.observation_id,
+            action,
+            priority: misalignment.severity.max(MISALIGNMENT_SEVERITY),
+        }
+    }
 }
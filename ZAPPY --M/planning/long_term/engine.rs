@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::long_term::{PlanPhase, StrategicPlan};
+use crate::long_term::{CostRates, PlanPhase, StrategicPlan};
 
 use super::func::{confidence_score, projected_roi, risk_from_complexity};
 use super::helper::normalize_resources;
@@ -14,17 +14,29 @@ pub struct PlanScore {
     pub risk: f32,
     /// Overall confidence.
     pub confidence: f32,
+    /// Total plan cost, when the engine was configured with cost rates.
+    #[serde(default)]
+    pub cost: Option<f32>,
 }
 
 /// Engine that evaluates plans and phases.
-#[derive(Debug, Clone)]
-pub struct PlanScoringEngine;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanScoringEngine {
+    costing: Option<(CostRates, f32)>,
+}
 
 impl PlanScoringEngine {
     /// Creates a new engine.
     #[must_use]
-    pub fn new() -> Self {
-        Self
+    pub const fn new() -> Self {
+        Self { costing: None }
+    }
+
+    /// Prices plans with `rates`; plans costing more than `budget` score as riskier.
+    #[must_use]
+    pub fn with_costs(mut self, rates: CostRates, budget: f32) -> Self {
+        self.costing = Some((rates, budget));
+        self
     }
 
     /// Scores a plan based on ROI/risk heuristics.
@@ -35,12 +47,21 @@ impl PlanScoringEngine {
             &plan.objective.metrics,
             plan.total_duration(),
         );
-        let risk = self.phase_risk(plan);
+        let mut risk = self.phase_risk(plan);
+        let cost = self.costing.as_ref().map(|(rates, budget)| {
+            let cost = plan.total_cost(rates);
+            if *budget > 0.0 && cost > *budget {
+                let overrun = (cost / budget - 1.0).min(1.0);
+                risk = (risk + overrun * 0.3).clamp(0.0, 1.0);
+            }
+            cost
+        });
         let confidence = confidence_score(roi, risk);
         PlanScore {
             roi,
             risk,
             confidence,
+            cost,
         }
     }
 
@@ -96,5 +117,19 @@ mod tests {
         };
         let score = PlanScoringEngine::new().score(&plan);
         assert!(score.confidence > 0.0);
+        assert!(score.cost.is_none());
+
+        let rates = CostRates::new(1_000.0);
+        let within = PlanScoringEngine::new()
+            .with_costs(rates.clone(), 10_000.0)
+            .score(&plan);
+        let over = PlanScoringEngine::new()
+            .with_costs(rates, 4_000.0)
+            .score(&plan);
+        assert!(within
+            .cost
+            .is_some_and(|cost| (cost - 8_000.0).abs() < 1e-2));
+        assert!(over.risk > within.risk);
+        assert!(over.confidence < within.confidence);
     }
 }
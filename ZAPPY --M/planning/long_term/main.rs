@@ -1,5 +1,6 @@
+use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -17,6 +18,15 @@ pub struct StrategicObjective {
     pub horizon_weeks: u16,
     /// Key metrics to improve (name -> target delta).
     pub metrics: IndexMap<String, f32>,
+    /// Objectives that must complete before this one starts.
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Expected impact if the objective succeeds (0-1).
+    #[serde(default)]
+    pub expected_impact: f32,
+    /// Risk carried by the objective before planning (0-1).
+    #[serde(default)]
+    pub baseline_risk: f32,
 }
 
 impl StrategicObjective {
@@ -29,8 +39,81 @@ impl StrategicObjective {
             priority,
             horizon_weeks,
             metrics: IndexMap::new(),
+            depends_on: Vec::new(),
+            expected_impact: 0.0,
+            baseline_risk: 0.0,
         }
     }
+
+    /// Sets the expected impact (clamped to 0-1).
+    #[must_use]
+    pub const fn expected_impact(mut self, impact: f32) -> Self {
+        self.expected_impact = impact.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the baseline risk (clamped to 0-1).
+    #[must_use]
+    pub const fn baseline_risk(mut self, risk: f32) -> Self {
+        self.baseline_risk = risk.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Portfolio ordering score under `weights`; priority is normalized to 0-1.
+    #[must_use]
+    pub fn score(&self, weights: &ObjectiveWeights) -> f32 {
+        let priority = weights.priority * f32::from(self.priority) / 100.0;
+        weights.risk.mul_add(
+            -self.baseline_risk,
+            weights.impact.mul_add(self.expected_impact, priority),
+        )
+    }
+
+    /// Overlap with `other` (0-1): Jaccard similarity of the description words, averaged
+    /// with the overlap of metric names when both objectives declare metrics.
+    #[must_use]
+    pub fn similarity(&self, other: &Self) -> f32 {
+        let words = |text: &str| {
+            text.split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(str::to_lowercase)
+                .collect::<IndexSet<_>>()
+        };
+        let description = jaccard(&words(&self.description), &words(&other.description));
+        if self.metrics.is_empty() || other.metrics.is_empty() {
+            return description;
+        }
+        let metrics = jaccard(
+            &self.metrics.keys().collect(),
+            &other.metrics.keys().collect(),
+        );
+        description.midpoint(metrics)
+    }
+
+    /// Folds `other` into this objective: priorities are summed (capped at 100), metrics
+    /// and prerequisites unioned, and the tighter horizon kept.
+    pub fn absorb(&mut self, other: Self) {
+        self.priority = self.priority.saturating_add(other.priority).min(100);
+        self.horizon_weeks = self.horizon_weeks.min(other.horizon_weeks);
+        for (metric, target) in other.metrics {
+            let entry = self.metrics.entry(metric).or_insert(target);
+            *entry = entry.max(target);
+        }
+        for prerequisite in other.depends_on {
+            if prerequisite != self.id && !self.depends_on.contains(&prerequisite) {
+                self.depends_on.push(prerequisite);
+            }
+        }
+        self.expected_impact = self.expected_impact.max(other.expected_impact);
+        self.baseline_risk = self.baseline_risk.max(other.baseline_risk);
+    }
+
+    /// Declares a prerequisite objective.
+    #[must_use]
+    pub fn depends_on(mut self, prerequisite: Uuid) -> Self {
+        self.depends_on.push(prerequisite);
+        self
+    }
 }
 
 /// Detailed phase inside a strategic plan.
@@ -48,6 +131,61 @@ pub struct PlanPhase {
     pub risk_multiplier: f32,
 }
 
+impl PlanPhase {
+    /// Phase length in weeks.
+    #[must_use]
+    pub const fn duration_weeks(&self) -> u16 {
+        self.end_week.saturating_sub(self.start_week)
+    }
+
+    /// Cost of the phase: each commitment times its weekly rate times the phase length.
+    #[must_use]
+    pub fn cost(&self, rates: &CostRates) -> f32 {
+        let weekly = self
+            .resources
+            .iter()
+            .map(|(resource, commitment)| commitment * rates.rate_for(resource))
+            .sum::<f32>();
+        weekly * f32::from(self.duration_weeks())
+    }
+}
+
+/// Weekly cost of a full (1.0) commitment per resource.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostRates {
+    /// Rate applied to resources without an explicit entry.
+    pub default_rate: f32,
+    /// Resource-specific rates.
+    pub rates: IndexMap<String, f32>,
+}
+
+impl CostRates {
+    /// Creates a rate table charging `default_rate` for every resource.
+    #[must_use]
+    pub fn new(default_rate: f32) -> Self {
+        Self {
+            default_rate,
+            rates: IndexMap::new(),
+        }
+    }
+
+    /// Overrides the rate for a single resource.
+    #[must_use]
+    pub fn rate(mut self, resource: impl Into<String>, rate: f32) -> Self {
+        self.rates.insert(resource.into(), rate);
+        self
+    }
+
+    /// Returns the rate charged for `resource`.
+    #[must_use]
+    pub fn rate_for(&self, resource: &str) -> f32 {
+        self.rates
+            .get(resource)
+            .copied()
+            .unwrap_or(self.default_rate)
+    }
+}
+
 /// Multi-phase plan built by the long-term planner.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategicPlan {
@@ -73,10 +211,29 @@ impl StrategicPlan {
             .max()
             .unwrap_or(0)
     }
+
+    /// Weeks from the first phase's start to the last phase's end, ignoring any
+    /// dependency offset before the plan begins.
+    #[must_use]
+    pub fn span_weeks(&self) -> u16 {
+        let start = self
+            .phases
+            .iter()
+            .map(|phase| phase.start_week)
+            .min()
+            .unwrap_or(0);
+        self.total_duration().saturating_sub(start)
+    }
+
+    /// Sums the cost of every phase under `rates`.
+    #[must_use]
+    pub fn total_cost(&self, rates: &CostRates) -> f32 {
+        self.phases.iter().map(|phase| phase.cost(rates)).sum()
+    }
 }
 
 /// Heuristic configuration for the long-term planner.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanningHeuristics {
     /// Maximum number of phases per plan.
     pub max_phases: usize,
@@ -84,6 +241,32 @@ pub struct PlanningHeuristics {
     pub per_phase_risk: f32,
     /// Baseline ROI multiplier for high priority objectives.
     pub high_priority_roi_boost: f32,
+    /// Weights used to order objectives in a portfolio.
+    #[serde(default)]
+    pub objective_weights: ObjectiveWeights,
+}
+
+/// Weights of the objective score `priority * p + impact * i - risk * r`.
+///
+/// The default weighs priority alone, matching a plain priority sort.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ObjectiveWeights {
+    /// Weight of the normalized priority.
+    pub priority: f32,
+    /// Weight of the expected impact.
+    pub impact: f32,
+    /// Penalty applied to the baseline risk.
+    pub risk: f32,
+}
+
+impl Default for ObjectiveWeights {
+    fn default() -> Self {
+        Self {
+            priority: 1.0,
+            impact: 0.0,
+            risk: 0.0,
+        }
+    }
 }
 
 impl Default for PlanningHeuristics {
@@ -92,44 +275,112 @@ impl Default for PlanningHeuristics {
             max_phases: 4,
             per_phase_risk: 0.04,
             high_priority_roi_boost: 0.15,
+            objective_weights: ObjectiveWeights::default(),
         }
     }
 }
 
 /// Long-term planner that transforms objectives into phased plans.
-#[derive(Debug, Clone)]
+///
+/// Each plan draws from an RNG seeded with `seed` plus the number of plans generated so
+/// far, so a serialized planner resumes with exactly the same sequence of plans.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LongTermPlanner {
     heuristics: PlanningHeuristics,
-    rng: SmallRng,
+    seed: u64,
+    #[serde(default)]
+    plans_generated: u64,
 }
 
 impl LongTermPlanner {
-    /// Creates a new planner.
+    /// Creates a new planner with a random seed.
     #[must_use]
     pub fn new(heuristics: PlanningHeuristics) -> Self {
+        Self::seeded(heuristics, rand::random())
+    }
+
+    /// Creates a planner with a fixed seed (useful for reproducible plans).
+    #[must_use]
+    pub const fn seeded(heuristics: PlanningHeuristics, seed: u64) -> Self {
         Self {
             heuristics,
-            rng: SmallRng::from_entropy(),
+            seed,
+            plans_generated: 0,
         }
     }
 
-    /// Generates plans for the provided objectives (sorted by priority).
+    /// Seed the planner's plan sequence derives from.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Replaces the weights used to order objectives.
+    #[must_use]
+    pub const fn with_objective_weights(mut self, weights: ObjectiveWeights) -> Self {
+        self.heuristics.objective_weights = weights;
+        self
+    }
+
+    /// Generates plans for the provided objectives (sorted by [`StrategicObjective::score`]).
+    ///
+    /// Dependent objectives are planned after their prerequisites and their phases are
+    /// staggered to begin once the prerequisite plan completes. Cyclic dependencies are
+    /// rejected. When `capacity` cuts the list, an objective is only kept if its
+    /// prerequisites fit alongside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the dependencies contain a cycle or a dependency chain
+    /// pushes a phase past week `u16::MAX`.
     pub fn generate_portfolio(
         &mut self,
         mut objectives: Vec<StrategicObjective>,
         capacity: usize,
-    ) -> Vec<StrategicPlan> {
-        objectives.sort_by(|a, b| b.priority.cmp(&a.priority));
-        objectives
-            .into_iter()
-            .take(capacity)
-            .map(|objective| self.generate_plan(objective))
-            .collect()
+    ) -> Result<Vec<StrategicPlan>> {
+        let weights = self.heuristics.objective_weights;
+        objectives.sort_by(|a, b| b.score(&weights).total_cmp(&a.score(&weights)));
+        dependency_order(&objectives)?;
+        let objectives = fill_capacity(objectives, capacity);
+        let order = dependency_order(&objectives)?;
+        let mut slots = objectives.into_iter().map(Some).collect::<Vec<_>>();
+        let mut completed: IndexMap<Uuid, u16> = IndexMap::new();
+        let mut plans = Vec::with_capacity(order.len());
+        for idx in order {
+            let Some(objective) = slots[idx].take() else {
+                continue;
+            };
+            let offset = objective
+                .depends_on
+                .iter()
+                .filter_map(|id| completed.get(id).copied())
+                .max()
+                .unwrap_or(0);
+            let mut plan = self.generate_plan(objective);
+            for phase in &mut plan.phases {
+                let (Some(start), Some(end)) = (
+                    phase.start_week.checked_add(offset),
+                    phase.end_week.checked_add(offset),
+                ) else {
+                    bail!(
+                        "dependency chain pushes objective {} past week {}",
+                        plan.objective.id,
+                        u16::MAX
+                    );
+                };
+                phase.start_week = start;
+                phase.end_week = end;
+            }
+            completed.insert(plan.objective.id, plan.total_duration());
+            plans.push(plan);
+        }
+        Ok(plans)
     }
 
     fn generate_plan(&mut self, objective: StrategicObjective) -> StrategicPlan {
-        let phase_count = self
-            .rng
+        let mut rng = SmallRng::seed_from_u64(self.seed.wrapping_add(self.plans_generated));
+        self.plans_generated += 1;
+        let phase_count = rng
             .gen_range(2..=self.heuristics.max_phases.max(2))
             .min(objective.horizon_weeks.max(1) as usize);
         let mut phases = Vec::with_capacity(phase_count);
@@ -138,10 +389,10 @@ impl LongTermPlanner {
             let span = (objective.horizon_weeks / phase_count as u16).max(1);
             let label = format!("Phase {}", idx + 1);
             let mut resources = IndexMap::new();
-            resources.insert("engineering".into(), self.rng.gen_range(0.3..0.7));
-            resources.insert("ops".into(), self.rng.gen_range(0.1..0.3));
+            resources.insert("engineering".into(), rng.gen_range(0.3..0.7));
+            resources.insert("ops".into(), rng.gen_range(0.1..0.3));
             if idx % 2 == 0 {
-                resources.insert("research".into(), self.rng.gen_range(0.05..0.2));
+                resources.insert("research".into(), rng.gen_range(0.05..0.2));
             }
             let risk_multiplier = 1.0 + (idx as f32 * 0.05);
             phases.push(PlanPhase {
@@ -172,6 +423,128 @@ impl LongTermPlanner {
     }
 }
 
+/// Merges objectives whose [`StrategicObjective::similarity`] reaches `threshold` into the
+/// first of them, redirecting dependencies on merged objectives to the survivor.
+#[must_use]
+pub fn merge_duplicates(
+    objectives: Vec<StrategicObjective>,
+    threshold: f32,
+) -> Vec<StrategicObjective> {
+    let mut merged: Vec<StrategicObjective> = Vec::with_capacity(objectives.len());
+    let mut redirects: IndexMap<Uuid, Uuid> = IndexMap::new();
+    for objective in objectives {
+        match merged
+            .iter_mut()
+            .find(|kept| kept.similarity(&objective) >= threshold)
+        {
+            Some(kept) => {
+                redirects.insert(objective.id, kept.id);
+                kept.absorb(objective);
+            }
+            None => merged.push(objective),
+        }
+    }
+    for objective in &mut merged {
+        let id = objective.id;
+        let mut depends_on = Vec::with_capacity(objective.depends_on.len());
+        for prerequisite in objective.depends_on.drain(..) {
+            let prerequisite = redirects
+                .get(&prerequisite)
+                .copied()
+                .unwrap_or(prerequisite);
+            if prerequisite != id && !depends_on.contains(&prerequisite) {
+                depends_on.push(prerequisite);
+            }
+        }
+        objective.depends_on = depends_on;
+    }
+    merged
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn jaccard<T: Eq + std::hash::Hash>(a: &IndexSet<T>, b: &IndexSet<T>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// Selects up to `capacity` objectives in score order, admitting each together with
+/// its transitive prerequisites or not at all.
+fn fill_capacity(objectives: Vec<StrategicObjective>, capacity: usize) -> Vec<StrategicObjective> {
+    if objectives.len() <= capacity {
+        return objectives;
+    }
+    let mut selected = vec![false; objectives.len()];
+    let mut admitted = 0;
+    for idx in 0..objectives.len() {
+        if admitted >= capacity {
+            break;
+        }
+        if selected[idx] {
+            continue;
+        }
+        let mut closure = vec![idx];
+        let mut cursor = 0;
+        while cursor < closure.len() {
+            for dep in &objectives[closure[cursor]].depends_on {
+                if let Some(pos) = objectives.iter().position(|objective| objective.id == *dep) {
+                    if !selected[pos] && !closure.contains(&pos) {
+                        closure.push(pos);
+                    }
+                }
+            }
+            cursor += 1;
+        }
+        if admitted + closure.len() > capacity {
+            continue;
+        }
+        admitted += closure.len();
+        for pos in closure {
+            selected[pos] = true;
+        }
+    }
+    objectives
+        .into_iter()
+        .zip(selected)
+        .filter_map(|(objective, keep)| keep.then_some(objective))
+        .collect()
+}
+
+/// Orders objectives so prerequisites come first, preserving priority order otherwise.
+fn dependency_order(objectives: &[StrategicObjective]) -> Result<Vec<usize>> {
+    let known = objectives
+        .iter()
+        .map(|objective| objective.id)
+        .collect::<Vec<_>>();
+    let mut emitted = vec![false; objectives.len()];
+    let mut order = Vec::with_capacity(objectives.len());
+    while order.len() < objectives.len() {
+        let ready = objectives.iter().enumerate().position(|(idx, objective)| {
+            !emitted[idx]
+                && objective.depends_on.iter().all(|dep| {
+                    known
+                        .iter()
+                        .position(|id| id == dep)
+                        .is_none_or(|pos| emitted[pos])
+                })
+        });
+        let Some(idx) = ready else {
+            let blocked = objectives
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !emitted[*idx])
+                .map(|(_, objective)| objective.description.as_str())
+                .collect::<Vec<_>>();
+            bail!("cyclic objective dependencies: {}", blocked.join(", "));
+        };
+        emitted[idx] = true;
+        order.push(idx);
+    }
+    Ok(order)
+}
+
 impl Default for LongTermPlanner {
     fn default() -> Self {
         Self::new(PlanningHeuristics::default())
@@ -193,10 +566,10 @@ pub mod reviewer;
 /// Objective sources.
 pub mod sources;
 
-pub use advanced::AdvancedPortfolioPlanner;
+pub use advanced::{AdvancedPlannerSnapshot, AdvancedPortfolioPlanner};
 pub use engine::{PlanScore, PlanScoringEngine};
 pub use plans::PlanArchive;
-pub use reviewer::StrategicPlanReviewer;
+pub use reviewer::{PlanFinding, PlanFindingKind, StrategicPlanReviewer};
 
 #[cfg(test)]
 mod tests {
@@ -208,9 +581,134 @@ mod tests {
         let objective = StrategicObjective::new("Scale infra", 85, 24);
         let plan = planner
             .generate_portfolio(vec![objective], 1)
+            .unwrap()
             .pop()
             .unwrap();
         assert!(!plan.phases.is_empty());
         assert!(plan.risk_score >= 0.0);
     }
+
+    #[test]
+    fn dependent_plans_start_after_prerequisite() {
+        let mut planner = LongTermPlanner::default();
+        let stabilize = StrategicObjective::new("stabilize infra", 40, 12);
+        let scale = StrategicObjective::new("scale infra", 90, 16).depends_on(stabilize.id);
+        let prerequisite_id = stabilize.id;
+        let plans = planner
+            .generate_portfolio(vec![scale, stabilize], 2)
+            .unwrap();
+        assert_eq!(plans[0].objective.id, prerequisite_id);
+        let prerequisite_end = plans[0].total_duration();
+        assert!(plans[1]
+            .phases
+            .iter()
+            .all(|phase| phase.start_week >= prerequisite_end));
+    }
+
+    #[test]
+    fn overlong_dependency_chains_are_rejected() {
+        let mut planner = LongTermPlanner::default();
+        let first = StrategicObjective::new("first", 40, 40_000);
+        let second = StrategicObjective::new("second", 40, 40_000).depends_on(first.id);
+        let err = planner
+            .generate_portfolio(vec![first, second], 2)
+            .unwrap_err();
+        assert!(err.to_string().contains("past week"));
+    }
+
+    #[test]
+    fn capacity_keeps_prerequisites_with_their_dependents() {
+        let mut planner = LongTermPlanner::default();
+        let foundation = StrategicObjective::new("foundation", 20, 8);
+        let platform = StrategicObjective::new("platform", 30, 8).depends_on(foundation.id);
+        let launch = StrategicObjective::new("launch", 95, 8).depends_on(platform.id);
+        let standalone = StrategicObjective::new("standalone", 60, 8);
+        let (foundation_id, platform_id, launch_id, standalone_id) =
+            (foundation.id, platform.id, launch.id, standalone.id);
+        let objectives = vec![launch, standalone, platform, foundation];
+
+        let plans = planner.generate_portfolio(objectives.clone(), 2).unwrap();
+        let ids = plans
+            .iter()
+            .map(|plan| plan.objective.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![standalone_id, foundation_id]);
+
+        let plans = planner.generate_portfolio(objectives, 3).unwrap();
+        let ids = plans
+            .iter()
+            .map(|plan| plan.objective.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![foundation_id, platform_id, launch_id]);
+    }
+
+    #[test]
+    fn total_cost_sums_phase_costs() {
+        let phase = |label: &str, start_week, end_week, resources| PlanPhase {
+            label: label.into(),
+            start_week,
+            end_week,
+            resources,
+            risk_multiplier: 1.0,
+        };
+        let plan = StrategicPlan {
+            objective: StrategicObjective::new("migrate", 60, 10),
+            phases: vec![
+                phase(
+                    "build",
+                    0,
+                    4,
+                    IndexMap::from([("engineering".into(), 0.5), ("ops".into(), 0.25)]),
+                ),
+                phase(
+                    "rollout",
+                    4,
+                    10,
+                    IndexMap::from([("ops".into(), 0.5), ("research".into(), 0.1)]),
+                ),
+            ],
+            risk_score: 0.2,
+            expected_roi: 0.5,
+            generated_at: Utc::now(),
+        };
+        let rates = CostRates::new(500.0)
+            .rate("engineering", 2_000.0)
+            .rate("ops", 1_200.0);
+        // build: (0.5 * 2000 + 0.25 * 1200) * 4 = 5200
+        // rollout: (0.5 * 1200 + 0.1 * 500) * 6 = 3900
+        assert!((plan.phases[0].cost(&rates) - 5_200.0).abs() < 1e-2);
+        assert!((plan.total_cost(&rates) - 9_100.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn impact_weighting_outranks_risky_priority() {
+        let bold = StrategicObjective::new("bold bet", 90, 12).baseline_risk(0.8);
+        let quiet = StrategicObjective::new("quiet win", 60, 12).expected_impact(0.9);
+        let quiet_id = quiet.id;
+
+        let mut by_priority = LongTermPlanner::seeded(PlanningHeuristics::default(), 7);
+        let plans = by_priority
+            .generate_portfolio(vec![bold.clone(), quiet.clone()], 1)
+            .unwrap();
+        assert_eq!(plans[0].objective.description, "bold bet");
+
+        let mut weighted = LongTermPlanner::seeded(PlanningHeuristics::default(), 7)
+            .with_objective_weights(ObjectiveWeights {
+                priority: 1.0,
+                impact: 0.5,
+                risk: 0.5,
+            });
+        // bold: 0.9 - 0.4 = 0.5; quiet: 0.6 + 0.45 = 1.05
+        let plans = weighted.generate_portfolio(vec![bold, quiet], 1).unwrap();
+        assert_eq!(plans[0].objective.id, quiet_id);
+    }
+
+    #[test]
+    fn cyclic_dependencies_are_rejected() {
+        let mut planner = LongTermPlanner::default();
+        let mut first = StrategicObjective::new("first", 50, 8);
+        let second = StrategicObjective::new("second", 60, 8).depends_on(first.id);
+        first.depends_on.push(second.id);
+        assert!(planner.generate_portfolio(vec![first, second], 2).is_err());
+    }
 }
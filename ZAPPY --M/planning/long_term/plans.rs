@@ -20,7 +20,7 @@ pub struct PlanRecord {
 }
 
 /// In-memory archive of past plans.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlanArchive {
     records: Vec<PlanRecord>,
     capacity: usize,
@@ -89,6 +89,7 @@ mod tests {
             roi: 0.6,
             risk: 0.3,
             confidence: 0.7,
+            cost: None,
         };
         archive.push(&plan, &score);
         assert_eq!(archive.history_for(plan.objective.id, 1).len(), 1);
@@ -4,6 +4,27 @@ use crate::long_term::StrategicPlan;
 
 use super::engine::PlanScore;
 
+/// Category of issue raised while reviewing a plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanFindingKind {
+    /// Plan duration exceeds the objective horizon.
+    HorizonOverrun,
+    /// Plan risk score exceeds the configured ceiling.
+    RiskCeiling,
+}
+
+/// Actionable finding produced by [`StrategicPlanReviewer::review`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanFinding {
+    /// Finding category.
+    pub kind: PlanFindingKind,
+    /// Human readable explanation.
+    pub message: String,
+    /// Suggested remediation.
+    pub recommendation: String,
+}
+
 /// Reviewer ensures plan risk stays within guardrails.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategicPlanReviewer {
@@ -11,6 +32,13 @@ pub struct StrategicPlanReviewer {
     pub max_risk: f32,
     /// Minimum confidence required.
     pub min_confidence: f32,
+    /// Ceiling applied to the plan's own risk score.
+    #[serde(default = "default_risk_ceiling")]
+    pub risk_ceiling: f32,
+}
+
+const fn default_risk_ceiling() -> f32 {
+    0.8
 }
 
 impl StrategicPlanReviewer {
@@ -20,14 +48,50 @@ impl StrategicPlanReviewer {
         Self {
             max_risk,
             min_confidence,
+            risk_ceiling: default_risk_ceiling(),
         }
     }
 
+    /// Overrides the plan risk ceiling.
+    #[must_use]
+    pub const fn with_risk_ceiling(mut self, ceiling: f32) -> Self {
+        self.risk_ceiling = ceiling;
+        self
+    }
+
     /// Determines if plan should be approved.
     #[must_use]
     pub fn approve(&self, _plan: &StrategicPlan, score: &PlanScore) -> bool {
         score.risk <= self.max_risk && score.confidence >= self.min_confidence
     }
+
+    /// Returns actionable findings for unrealistic horizons or excessive risk.
+    #[must_use]
+    pub fn review(&self, plan: &StrategicPlan) -> Vec<PlanFinding> {
+        let mut findings = Vec::new();
+        let duration = plan.span_weeks();
+        if duration > plan.objective.horizon_weeks {
+            findings.push(PlanFinding {
+                kind: PlanFindingKind::HorizonOverrun,
+                message: format!(
+                    "plan spans {duration} weeks but objective horizon is {} weeks",
+                    plan.objective.horizon_weeks
+                ),
+                recommendation: "reduce phase spans or extend the objective horizon".into(),
+            });
+        }
+        if plan.risk_score > self.risk_ceiling {
+            findings.push(PlanFinding {
+                kind: PlanFindingKind::RiskCeiling,
+                message: format!(
+                    "plan risk {:.2} exceeds ceiling {:.2}",
+                    plan.risk_score, self.risk_ceiling
+                ),
+                recommendation: "split the objective or add mitigation phases".into(),
+            });
+        }
+        findings
+    }
 }
 
 impl Default for StrategicPlanReviewer {
@@ -39,8 +103,9 @@ impl Default for StrategicPlanReviewer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::long_term::{StrategicObjective, StrategicPlan};
+    use crate::long_term::{PlanPhase, StrategicObjective, StrategicPlan};
     use chrono::Utc;
+    use indexmap::IndexMap;
 
     #[test]
     fn reviewer_blocks_high_risk() {
@@ -56,7 +121,49 @@ mod tests {
             roi: 0.7,
             risk: 0.4,
             confidence: 0.6,
+            cost: None,
         };
         assert!(!reviewer.approve(&plan, &score));
     }
+
+    #[test]
+    fn reviewer_flags_horizon_overrun() {
+        let reviewer = StrategicPlanReviewer::default();
+        let plan = StrategicPlan {
+            objective: StrategicObjective::new("migrate", 60, 8),
+            phases: vec![PlanPhase {
+                label: "p1".into(),
+                start_week: 0,
+                end_week: 12,
+                resources: IndexMap::new(),
+                risk_multiplier: 1.0,
+            }],
+            risk_score: 0.3,
+            expected_roi: 0.6,
+            generated_at: Utc::now(),
+        };
+        let findings = reviewer.review(&plan);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].kind, PlanFindingKind::HorizonOverrun);
+        assert!(findings[0].message.contains("12 weeks"));
+    }
+
+    #[test]
+    fn dependency_offset_does_not_count_against_the_horizon() {
+        let reviewer = StrategicPlanReviewer::default();
+        let plan = StrategicPlan {
+            objective: StrategicObjective::new("scale", 60, 8),
+            phases: vec![PlanPhase {
+                label: "p1".into(),
+                start_week: 20,
+                end_week: 28,
+                resources: IndexMap::new(),
+                risk_multiplier: 1.0,
+            }],
+            risk_score: 0.3,
+            expected_roi: 0.6,
+            generated_at: Utc::now(),
+        };
+        assert!(reviewer.review(&plan).is_empty());
+    }
 }
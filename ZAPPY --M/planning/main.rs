@@ -1,25 +1,59 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use shared_event_bus::MemoryEventBus;
 use shared_logging::LogLevel;
 use uuid::Uuid;
+use zappy_simulationengine::{advanced::report::SimulationReport, methods::SimulationMethod};
 
 use crate::{
-    long_term::{AdvancedPortfolioPlanner, LongTermPlanner, StrategicObjective, StrategicPlan},
+    long_term::{
+        merge_duplicates, AdvancedPlannerSnapshot, AdvancedPortfolioPlanner, LongTermPlanner,
+        StrategicObjective, StrategicPlan, StrategicPlanReviewer,
+    },
     short_term::{ShortTermPlanner, TacticalSchedule},
+    simulation::{phase_scenarios, PlanSimulator, PLAN_SIMULATION_METHOD},
     telemetry::PlanningTelemetry,
 };
 
 use crate::module::{PlanningDirective, PriorityBand};
 
+/// Similarity at which submitted objectives are merged before planning.
+pub const DEFAULT_MERGE_THRESHOLD: f32 = 0.8;
+
+/// Serializable planning state used to move a runtime between processes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningSnapshot {
+    /// Long-term planner, including its seed and position in the plan sequence.
+    pub long_term: LongTermPlanner,
+    /// Short-term planner configuration.
+    pub short_term: ShortTermPlanner,
+    /// Reviewer auditing generated plans.
+    pub reviewer: StrategicPlanReviewer,
+    /// Advanced planner state, when one is attached.
+    pub advanced: Option<AdvancedPlannerSnapshot>,
+    /// Most recently proposed plan.
+    pub active_plan: Option<StrategicPlan>,
+    /// Similarity at which submitted objectives are merged; older snapshots use the default.
+    #[serde(default = "default_merge_threshold")]
+    pub merge_threshold: f32,
+}
+
+const fn default_merge_threshold() -> f32 {
+    DEFAULT_MERGE_THRESHOLD
+}
+
 /// Composite planning runtime used by the autonomy + metacognition stack.
 pub struct PlanningRuntime {
     long_term: LongTermPlanner,
     short_term: ShortTermPlanner,
     telemetry: Option<PlanningTelemetry>,
     advanced: Option<AdvancedPortfolioPlanner>,
+    reviewer: StrategicPlanReviewer,
+    active_plan: Option<StrategicPlan>,
+    merge_threshold: f32,
 }
 
 impl Default for PlanningRuntime {
@@ -34,6 +68,9 @@ impl Default for PlanningRuntime {
             short_term: ShortTermPlanner::default(),
             telemetry,
             advanced: None,
+            reviewer: StrategicPlanReviewer::default(),
+            active_plan: None,
+            merge_threshold: DEFAULT_MERGE_THRESHOLD,
         }
     }
 }
@@ -51,6 +88,9 @@ impl PlanningRuntime {
             short_term,
             telemetry,
             advanced: None,
+            reviewer: StrategicPlanReviewer::default(),
+            active_plan: None,
+            merge_threshold: DEFAULT_MERGE_THRESHOLD,
         }
     }
 
@@ -67,6 +107,60 @@ impl PlanningRuntime {
         self
     }
 
+    /// Overrides the reviewer used to audit generated plans.
+    #[must_use]
+    pub fn with_reviewer(mut self, reviewer: StrategicPlanReviewer) -> Self {
+        self.reviewer = reviewer;
+        self
+    }
+
+    /// Merges submitted objectives at least this similar before planning (above 1.0
+    /// disables merging).
+    #[must_use]
+    pub const fn with_merge_threshold(mut self, threshold: f32) -> Self {
+        self.merge_threshold = threshold;
+        self
+    }
+
+    /// Captures everything needed to resume planning in another process.
+    #[must_use]
+    pub fn to_snapshot(&self) -> PlanningSnapshot {
+        PlanningSnapshot {
+            long_term: self.long_term.clone(),
+            short_term: self.short_term.clone(),
+            reviewer: self.reviewer.clone(),
+            advanced: self
+                .advanced
+                .as_ref()
+                .map(AdvancedPortfolioPlanner::snapshot),
+            active_plan: self.active_plan.clone(),
+            merge_threshold: self.merge_threshold,
+        }
+    }
+
+    /// Restores a runtime from [`Self::to_snapshot`]; telemetry is not part of the
+    /// snapshot and is shared with the advanced planner when provided.
+    #[must_use]
+    pub fn from_snapshot(snapshot: PlanningSnapshot, telemetry: Option<PlanningTelemetry>) -> Self {
+        Self {
+            long_term: snapshot.long_term,
+            short_term: snapshot.short_term,
+            advanced: snapshot.advanced.map(|advanced| {
+                AdvancedPortfolioPlanner::from_snapshot(advanced, telemetry.clone())
+            }),
+            telemetry,
+            reviewer: snapshot.reviewer,
+            active_plan: snapshot.active_plan,
+            merge_threshold: snapshot.merge_threshold,
+        }
+    }
+
+    /// Most recently proposed strategic plan.
+    #[must_use]
+    pub const fn active_plan(&self) -> Option<&StrategicPlan> {
+        self.active_plan.as_ref()
+    }
+
     /// Sets advanced planner after construction.
     pub fn set_advanced(&mut self, advanced: AdvancedPortfolioPlanner) {
         self.advanced = Some(advanced);
@@ -95,8 +189,20 @@ impl PlanningRuntime {
                     PriorityBand::High => 24,
                 },
                 metrics: Default::default(),
+                depends_on: Vec::new(),
+                expected_impact: 0.0,
+                baseline_risk: 0.0,
             })
             .collect::<Vec<_>>();
+        let submitted = objectives.len();
+        let objectives = merge_duplicates(objectives, self.merge_threshold);
+        if objectives.len() < submitted {
+            self.log(
+                LogLevel::Info,
+                "planning.long_term.objectives_merged",
+                json!({ "submitted": submitted, "remaining": objectives.len() }),
+            );
+        }
         self.log(
             LogLevel::Info,
             "planning.long_term.queue",
@@ -105,7 +211,7 @@ impl PlanningRuntime {
         let mut portfolio = if let Some(advanced) = self.advanced.as_mut() {
             advanced.build_portfolio(objectives.clone(), 3)?
         } else {
-            self.long_term.generate_portfolio(objectives, 3)
+            self.long_term.generate_portfolio(objectives, 3)?
         };
         let plan = portfolio.pop();
         if let Some(plan) = &plan {
@@ -127,6 +233,21 @@ impl PlanningRuntime {
                     "roi": plan.expected_roi
                 }),
             );
+            for finding in self.reviewer.review(plan) {
+                self.log(
+                    LogLevel::Warn,
+                    "planning.long_term.review_finding",
+                    json!({
+                        "objective_id": plan.objective.id,
+                        "kind": finding.kind,
+                        "message": finding.message,
+                        "recommendation": finding.recommendation
+                    }),
+                );
+            }
+        }
+        if plan.is_some() {
+            self.active_plan.clone_from(&plan);
         }
         Ok(plan)
     }
@@ -154,6 +275,42 @@ impl PlanningRuntime {
         Ok(schedule)
     }
 
+    /// Simulates every phase of `plan` and returns the projected outcomes.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error raised by `engine` while simulating the phase scenarios.
+    pub async fn simulate_plan(
+        &self,
+        plan: &StrategicPlan,
+        engine: &impl PlanSimulator,
+    ) -> Result<SimulationReport> {
+        let scenarios = phase_scenarios(plan);
+        self.log(
+            LogLevel::Info,
+            "planning.simulation.start",
+            json!({
+                "objective": plan.objective.description,
+                "scenarios": scenarios.len()
+            }),
+        );
+        let report = engine
+            .simulate_scenarios(
+                SimulationMethod::Custom(PLAN_SIMULATION_METHOD.into()),
+                scenarios,
+            )
+            .await?;
+        self.event(
+            "planning.simulation.completed",
+            json!({
+                "objective_id": plan.objective.id,
+                "scenarios": report.scenario_count,
+                "insights": report.insights.len()
+            }),
+        );
+        Ok(report)
+    }
+
     /// Reacts to new signals (re-planning) by evaluating threshold.
     pub fn ingest_signal(&mut self, signal: crate::module::PlanningSignal) -> Result<bool> {
         self.log(
@@ -192,6 +349,112 @@ mod tests {
     use std::sync::Arc;
     use tempfile::tempdir;
 
+    #[test]
+    fn snapshot_round_trip_resumes_identical_plans() {
+        use crate::long_term::{PlanScoringEngine, PlanningHeuristics};
+
+        let advanced = AdvancedPortfolioPlanner::new(
+            LongTermPlanner::seeded(PlanningHeuristics::default(), 7),
+            PlanScoringEngine::new(),
+            StrategicPlanReviewer::new(0.9, 0.1),
+            None,
+        );
+        let mut original = PlanningRuntime::new(
+            LongTermPlanner::seeded(PlanningHeuristics::default(), 11),
+            ShortTermPlanner::new(3),
+            None,
+        )
+        .with_advanced(advanced);
+        let directive = |objective: &str| PlanningDirective {
+            signal: Some(crate::module::PlanningSignal {
+                id: Uuid::from_u128(42),
+                narrative: objective.into(),
+                impact: 70,
+            }),
+            priority: PriorityBand::Medium,
+            objective: objective.into(),
+        };
+        original
+            .propose_strategic_plan(vec![directive("stabilize infra")])
+            .unwrap();
+
+        let json = serde_json::to_string(&original.to_snapshot()).unwrap();
+        let mut restored =
+            PlanningRuntime::from_snapshot(serde_json::from_str(&json).unwrap(), None);
+        assert_eq!(
+            restored.active_plan().unwrap().objective.description,
+            "stabilize infra"
+        );
+
+        let next = |runtime: &mut PlanningRuntime| {
+            let plan = runtime
+                .propose_strategic_plan(vec![directive("scale infra")])
+                .unwrap()
+                .unwrap();
+            serde_json::to_value((
+                &plan.objective,
+                &plan.phases,
+                plan.risk_score,
+                plan.expected_roi,
+            ))
+            .unwrap()
+        };
+        assert_eq!(next(&mut original), next(&mut restored));
+    }
+
+    #[test]
+    fn snapshot_keeps_the_merge_threshold() {
+        let runtime = PlanningRuntime::new(
+            LongTermPlanner::default(),
+            ShortTermPlanner::default(),
+            None,
+        )
+        .with_merge_threshold(0.95);
+        let mut json = serde_json::to_value(runtime.to_snapshot()).unwrap();
+        let restored =
+            PlanningRuntime::from_snapshot(serde_json::from_value(json.clone()).unwrap(), None);
+        assert!((restored.to_snapshot().merge_threshold - 0.95).abs() < f32::EPSILON);
+
+        json.as_object_mut().unwrap().remove("merge_threshold");
+        let legacy: PlanningSnapshot = serde_json::from_value(json).unwrap();
+        assert!((legacy.merge_threshold - DEFAULT_MERGE_THRESHOLD).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn near_identical_objectives_merge_into_one_plan() {
+        let mut runtime = PlanningRuntime::new(
+            LongTermPlanner::default(),
+            ShortTermPlanner::default(),
+            None,
+        );
+        let directive = |objective: &str| PlanningDirective {
+            signal: None,
+            priority: PriorityBand::Low,
+            objective: objective.into(),
+        };
+        let plan = runtime
+            .propose_strategic_plan(vec![
+                directive("Scale ingestion pipeline capacity"),
+                directive("scale the ingestion pipeline capacity"),
+            ])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            plan.objective.description,
+            "Scale ingestion pipeline capacity"
+        );
+        assert_eq!(plan.objective.priority, 40);
+
+        let distinct = merge_duplicates(
+            vec![
+                StrategicObjective::new("Scale ingestion pipeline capacity", 20, 8),
+                StrategicObjective::new("Retire legacy billing service", 20, 8),
+            ],
+            DEFAULT_MERGE_THRESHOLD,
+        );
+        assert_eq!(distinct.len(), 2);
+    }
+
     #[test]
     fn runtime_generates_plan_and_schedule() {
         let temp = tempdir().unwrap();
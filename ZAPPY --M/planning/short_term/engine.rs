@@ -1,4 +1,5 @@
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::long_term::StrategicPlan;
@@ -10,7 +11,7 @@ use super::{
 };
 
 /// Engine responsible for turning plan phases into executable tasks.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleEngine {
     max_parallel: usize,
 }
@@ -46,6 +47,7 @@ impl ScheduleEngine {
             horizon_hours: plan.total_duration() as u32 * 24 * method.cadence_multiplier(),
             tasks,
             generated_at: Utc::now(),
+            max_parallel: self.max_parallel,
         }
     }
 
@@ -74,6 +76,7 @@ mod tests {
         let mut planner = LongTermPlanner::default();
         let plan = planner
             .generate_portfolio(vec![StrategicObjective::new("grow", 70, 20)], 1)
+            .unwrap()
             .pop()
             .unwrap();
         let engine = ScheduleEngine::default();
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 /// Selects an owner for a task deterministically.
 #[must_use]
 pub fn select_owner(idx: u16) -> String {
@@ -18,6 +20,22 @@ pub fn task_count(resource_slots: usize, risk_multiplier: f32) -> u16 {
     count
 }
 
+/// Formats a timestamp in iCalendar UTC form (`YYYYMMDDTHHMMSSZ`).
+#[must_use]
+pub fn ics_timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes text values per RFC 5545.
+#[must_use]
+pub fn escape_ics_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +50,9 @@ mod tests {
     fn task_count_increases_with_risk() {
         assert!(task_count(1, 1.3) > task_count(1, 1.0));
     }
+
+    #[test]
+    fn ics_text_is_escaped() {
+        assert_eq!(escape_ics_text("a,b;c"), "a\\,b\\;c");
+    }
 }
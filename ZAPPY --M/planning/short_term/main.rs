@@ -1,4 +1,6 @@
-use chrono::{DateTime, Utc};
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -14,6 +16,8 @@ pub mod methods;
 pub use engine::ScheduleEngine;
 pub use methods::TacticalMethod;
 
+use helper::{escape_ics_text, ics_timestamp};
+
 /// Tactical task produced from long-term plan phases.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TacticalTask {
@@ -40,10 +44,65 @@ pub struct TacticalSchedule {
     pub tasks: Vec<TacticalTask>,
     /// Timestamp when schedule was created.
     pub generated_at: DateTime<Utc>,
+    /// Maximum number of tasks executed concurrently.
+    #[serde(default = "default_max_parallel")]
+    pub max_parallel: usize,
+}
+
+const fn default_max_parallel() -> usize {
+    1
+}
+
+impl TacticalSchedule {
+    /// Lays tasks onto a timeline starting at `start`, honouring `max_parallel`.
+    #[must_use]
+    pub fn timeline(
+        &self,
+        start: DateTime<Utc>,
+    ) -> Vec<(&TacticalTask, DateTime<Utc>, DateTime<Utc>)> {
+        let mut lanes = vec![start; self.max_parallel.max(1)];
+        self.tasks
+            .iter()
+            .map(|task| {
+                let (lane, free_at) = lanes
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by_key(|(_, free_at)| *free_at)
+                    .unwrap_or((0, start));
+                let end = free_at + Duration::hours(i64::from(task.effort_hours));
+                lanes[lane] = end;
+                (task, free_at, end)
+            })
+            .collect()
+    }
+
+    /// Renders the schedule as an iCalendar document with one VEVENT per task.
+    #[must_use]
+    pub fn to_ics(&self, start: DateTime<Utc>) -> String {
+        let mut ics = String::from(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Zappy//Planning//EN\r\nCALSCALE:GREGORIAN\r\n",
+        );
+        let stamp = ics_timestamp(self.generated_at);
+        for (task, begin, end) in self.timeline(start) {
+            let _ = write!(
+                ics,
+                "BEGIN:VEVENT\r\nUID:{}@zappy-planning\r\nDTSTAMP:{stamp}\r\nDTSTART:{}\r\nDTEND:{}\r\nSUMMARY:{}\r\nDESCRIPTION:{}\r\nCATEGORIES:{}\r\nEND:VEVENT\r\n",
+                task.id,
+                ics_timestamp(begin),
+                ics_timestamp(end),
+                escape_ics_text(&task.description),
+                escape_ics_text(&format!("owner={} risk={:.2}", task.owner, task.risk_score)),
+                escape_ics_text(&task.phase_label),
+            );
+        }
+        ics.push_str("END:VCALENDAR\r\n");
+        ics
+    }
 }
 
 /// Short-term planner generating actionable tasks from strategic plans.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShortTermPlanner {
     engine: ScheduleEngine,
     method: TacticalMethod,
@@ -89,10 +148,51 @@ mod tests {
         let mut long_term = LongTermPlanner::default();
         let plan = long_term
             .generate_portfolio(vec![StrategicObjective::new("stability", 70, 16)], 1)
+            .unwrap()
             .pop()
             .unwrap();
         let short_term = ShortTermPlanner::default().with_method(TacticalMethod::Sprint);
         let schedule = short_term.build_schedule(&plan);
         assert!(!schedule.tasks.is_empty());
     }
+
+    #[test]
+    fn ics_export_respects_parallelism() {
+        let mut long_term = LongTermPlanner::default();
+        let plan = long_term
+            .generate_portfolio(vec![StrategicObjective::new("launch", 80, 12)], 1)
+            .unwrap()
+            .pop()
+            .unwrap();
+        let schedule = ShortTermPlanner::new(2).build_schedule(&plan);
+        let start = Utc::now();
+        let ics = schedule.to_ics(start);
+        assert!(ics.starts_with("BEGIN:VCALENDAR"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), schedule.tasks.len());
+
+        let parse = |line: &str| {
+            chrono::NaiveDateTime::parse_from_str(line, "%Y%m%dT%H%M%SZ")
+                .unwrap()
+                .and_utc()
+        };
+        let starts = ics
+            .lines()
+            .filter_map(|line| line.strip_prefix("DTSTART:"))
+            .map(parse)
+            .collect::<Vec<_>>();
+        let ends = ics
+            .lines()
+            .filter_map(|line| line.strip_prefix("DTEND:"))
+            .map(parse)
+            .collect::<Vec<_>>();
+        assert_eq!(starts.len(), schedule.tasks.len());
+        for instant in &starts {
+            let running = starts
+                .iter()
+                .zip(&ends)
+                .filter(|(begin, end)| *begin <= instant && instant < *end)
+                .count();
+            assert!(running <= schedule.max_parallel);
+        }
+    }
 }
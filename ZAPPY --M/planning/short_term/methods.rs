@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Scheduling strategy used by the short-term planner.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum TacticalMethod {
     /// Kanban-style continuous flow.
     Kanban,
@@ -0,0 +1,124 @@
+//! Bridges strategic plans into the simulation engine for what-if analysis.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use uuid::Uuid;
+use zappy_simulationengine::{
+    advanced::report::SimulationReport, methods::SimulationMethod,
+    simul_env_generator::SimulationScenario, SimulationEngine,
+};
+
+use crate::long_term::StrategicPlan;
+
+/// Method label attached to plan simulations.
+pub const PLAN_SIMULATION_METHOD: &str = "strategic_plan";
+
+/// Engine capable of simulating scenarios derived from a plan.
+#[async_trait]
+pub trait PlanSimulator: Send + Sync {
+    /// Runs the supplied scenarios and returns the projected outcomes.
+    async fn simulate_scenarios(
+        &self,
+        method: SimulationMethod,
+        scenarios: Vec<SimulationScenario>,
+    ) -> Result<SimulationReport>;
+}
+
+#[async_trait]
+impl PlanSimulator for SimulationEngine {
+    async fn simulate_scenarios(
+        &self,
+        method: SimulationMethod,
+        scenarios: Vec<SimulationScenario>,
+    ) -> Result<SimulationReport> {
+        self.run_scenarios(method, scenarios).await
+    }
+}
+
+/// Maps each plan phase onto one simulation scenario.
+///
+/// `load` is the summed resource commitment, `risk` the phase multiplier applied to the
+/// plan risk, and `duration` the phase length as a fraction of a year.
+#[must_use]
+pub fn phase_scenarios(plan: &StrategicPlan) -> Vec<SimulationScenario> {
+    plan.phases
+        .iter()
+        .map(|phase| {
+            let mut parameters = IndexMap::new();
+            parameters.insert(
+                "load".into(),
+                phase.resources.values().sum::<f32>().clamp(0.0, 1.5),
+            );
+            parameters.insert(
+                "risk".into(),
+                (phase.risk_multiplier * plan.risk_score).clamp(0.0, 1.5),
+            );
+            parameters.insert(
+                "duration".into(),
+                f32::from(phase.end_week.saturating_sub(phase.start_week)) / 52.0,
+            );
+            SimulationScenario {
+                id: Uuid::new_v4(),
+                label: phase.label.clone(),
+                parameters,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LongTermPlanner, PlanningDirective, PlanningRuntime, ShortTermPlanner};
+    use chrono::Utc;
+    use zappy_simulationengine::advanced::report::ScenarioOutcome;
+
+    struct EchoSimulator;
+
+    #[async_trait]
+    impl PlanSimulator for EchoSimulator {
+        async fn simulate_scenarios(
+            &self,
+            method: SimulationMethod,
+            scenarios: Vec<SimulationScenario>,
+        ) -> Result<SimulationReport> {
+            Ok(SimulationReport {
+                method,
+                scenario_count: scenarios.len(),
+                insights: Vec::new(),
+                scenarios: scenarios
+                    .into_iter()
+                    .map(|scenario| ScenarioOutcome {
+                        scenario_id: scenario.id,
+                        label: scenario.label,
+                        metrics: scenario.parameters,
+                        mae: 0.0,
+                        finding: "nominal".into(),
+                        review: None,
+                    })
+                    .collect(),
+                generated_at: Utc::now(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn simulates_one_scenario_per_phase() {
+        let mut runtime = PlanningRuntime::new(
+            LongTermPlanner::default(),
+            ShortTermPlanner::default(),
+            None,
+        );
+        let plan = runtime
+            .propose_strategic_plan(vec![PlanningDirective::critical("expand region")])
+            .unwrap()
+            .unwrap();
+        let report = runtime.simulate_plan(&plan, &EchoSimulator).await.unwrap();
+        assert_eq!(report.method.label(), PLAN_SIMULATION_METHOD);
+        assert_eq!(report.scenario_count, plan.phases.len());
+        let labels: Vec<_> = report.scenarios.iter().map(|s| s.label.as_str()).collect();
+        let phases: Vec<_> = plan.phases.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(labels, phases);
+    }
+}
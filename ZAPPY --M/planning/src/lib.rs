@@ -20,12 +20,19 @@ pub mod orchestration_entry;
 /// Short-term planning engine.
 #[path = "../short_term/main.rs"]
 pub mod short_term;
+/// What-if simulation of strategic plans.
+#[path = "../simulation.rs"]
+pub mod simulation;
 /// Telemetry helpers for planning.
 #[path = "../telemetry.rs"]
 pub mod telemetry;
 
-pub use long_term::{LongTermPlanner, PlanPhase, StrategicObjective, StrategicPlan};
+pub use long_term::{
+    merge_duplicates, CostRates, LongTermPlanner, ObjectiveWeights, PlanPhase, StrategicObjective,
+    StrategicPlan,
+};
 pub use module::{PlanningDirective, PlanningSignal, PriorityBand};
-pub use orchestration_entry::PlanningRuntime;
+pub use orchestration_entry::{PlanningRuntime, PlanningSnapshot, DEFAULT_MERGE_THRESHOLD};
 pub use short_term::{ShortTermPlanner, TacticalSchedule, TacticalTask};
+pub use simulation::{phase_scenarios, PlanSimulator, PLAN_SIMULATION_METHOD};
 pub use telemetry::{PlanningTelemetry, PlanningTelemetryBuilder};
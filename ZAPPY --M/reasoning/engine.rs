@@ -58,6 +58,7 @@ pub struct InferenceResult {
 pub struct InferenceEngine {
     rng: SmallRng,
     acceptance: f32,
+    temperature: Option<f32>,
 }
 
 impl InferenceEngine {
@@ -67,9 +68,28 @@ impl InferenceEngine {
         Self {
             rng: SmallRng::from_entropy(),
             acceptance,
+            temperature: None,
         }
     }
 
+    /// Enables temperature-scaling calibration of hypothesis confidences.
+    #[must_use]
+    pub const fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Applies temperature scaling in logit space; >1 softens, <1 sharpens.
+    #[must_use]
+    pub fn calibrate(&self, confidence: f32) -> f32 {
+        let Some(temperature) = self.temperature.filter(|t| *t > f32::EPSILON) else {
+            return confidence;
+        };
+        let p = confidence.clamp(1e-4, 1.0 - 1e-4);
+        let logit = (p / (1.0 - p)).ln() / temperature;
+        1.0 / (1.0 + (-logit).exp())
+    }
+
     /// Runs inference from signals and directive.
     pub fn infer(
         &mut self,
@@ -83,7 +103,8 @@ impl InferenceEngine {
         let mut hypotheses = Vec::new();
         for chunk in signals.chunks(2) {
             let summary = format!("{} -> {} related signals", directive.prompt, chunk.len());
-            let confidence = self.sample_confidence(directive.priority.score(), chunk.len());
+            let raw = self.sample_confidence(directive.priority.score(), chunk.len());
+            let confidence = self.calibrate(raw);
             let hypothesis = ReasoningHypothesis {
                 id: Uuid::new_v4(),
                 summary,
@@ -131,4 +152,16 @@ mod tests {
         let result = engine.infer(directive, signals);
         assert!(!result.hypotheses.is_empty());
     }
+
+    #[test]
+    fn temperature_scaling_adjusts_confidence() {
+        let soft = InferenceEngine::default().with_temperature(2.0);
+        let sharp = InferenceEngine::default().with_temperature(0.5);
+        for raw in [0.2_f32, 0.8] {
+            let distance = (raw - 0.5).abs();
+            assert!((soft.calibrate(raw) - 0.5).abs() < distance);
+            assert!((sharp.calibrate(raw) - 0.5).abs() > distance);
+        }
+        assert!((InferenceEngine::default().calibrate(0.8) - 0.8).abs() < f32::EPSILON);
+    }
 }
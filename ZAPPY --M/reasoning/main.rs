@@ -1,10 +1,15 @@
 use anyhow::Result;
 use serde_json::json;
 use tokio::sync::RwLock;
+use zappy_knowledge::KnowledgeSnippet;
 
+#[cfg(feature = "ranked-hypotheses")]
+use crate::module::RankedHypothesis;
 use crate::{
     engine::{InferenceEngine, InferenceResult},
-    module::{ReasoningDirective, ReasoningHypothesis, SignalPacket, Verdict},
+    module::{
+        ReasoningDirective, ReasoningHypothesis, SignalPacket, Verdict, ACCEPTANCE_THRESHOLD,
+    },
     multidomain::MultiDomainCoordinator,
     telemetry::ReasoningTelemetry,
 };
@@ -13,6 +18,7 @@ use crate::{
 pub struct ReasoningRuntime {
     engine: RwLock<InferenceEngine>,
     coordinator: MultiDomainCoordinator,
+    acceptance_threshold: f32,
     telemetry: Option<ReasoningTelemetry>,
 }
 
@@ -25,10 +31,25 @@ impl ReasoningRuntime {
         Self {
             engine,
             coordinator,
+            acceptance_threshold: ACCEPTANCE_THRESHOLD,
             telemetry,
         }
     }
 
+    /// Sets the aggregate confidence (0-1) a hypothesis needs to be accepted.
+    #[must_use]
+    pub const fn with_acceptance_threshold(mut self, threshold: f32) -> Self {
+        self.acceptance_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Caches domain evaluations for repeated hypotheses.
+    #[must_use]
+    pub fn with_domain_cache(mut self, capacity: usize, ttl: std::time::Duration) -> Self {
+        self.coordinator = self.coordinator.with_cache(capacity, ttl);
+        self
+    }
+
     /// Runs full reasoning flow.
     pub async fn reason(
         &self,
@@ -47,19 +68,44 @@ impl ReasoningRuntime {
         Ok(best)
     }
 
+    /// Reasons over knowledge snippets, converting each into a signal packet first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reviewing the inferred hypotheses fails, as for
+    /// [`Self::reason`].
+    pub async fn reason_from_knowledge(
+        &self,
+        directive: ReasoningDirective,
+        snippets: Vec<KnowledgeSnippet>,
+    ) -> Result<Verdict> {
+        self.log(
+            "reasoning.knowledge.signals",
+            json!({ "snippets": snippets.len() }),
+        );
+        let signals = snippets.iter().map(SignalPacket::from_snippet).collect();
+        self.reason(directive, signals).await
+    }
+
     async fn select_best(&self, inference: InferenceResult) -> Result<Verdict> {
-        let mut best_hypothesis: Option<ReasoningHypothesis> = None;
-        let mut best_score = 0.0;
+        let mut reviewed = Vec::with_capacity(inference.hypotheses.len());
         for hypothesis in &inference.hypotheses {
-            let score = self.coordinator.review(hypothesis).await?;
-            if score > best_score {
-                best_score = score;
-                best_hypothesis = Some(ReasoningHypothesis {
-                    confidence: score,
-                    ..hypothesis.clone()
-                });
-            }
+            let (score, contributions) = self.coordinator.review_detailed(hypothesis).await?;
+            let reviewed_hypothesis = ReasoningHypothesis {
+                confidence: score,
+                ..hypothesis.clone()
+            };
+            reviewed.push((reviewed_hypothesis, contributions));
         }
+        // Stable sort: among equal scores the earliest hypothesis wins.
+        reviewed.sort_by(|a, b| b.0.confidence.total_cmp(&a.0.confidence));
+        let mut ranked = reviewed
+            .into_iter()
+            .filter(|(hypothesis, _)| hypothesis.confidence > 0.0);
+        let (best_hypothesis, contributions) = ranked
+            .next()
+            .map_or((None, Vec::new()), |(h, c)| (Some(h), c));
+        let best_score = best_hypothesis.as_ref().map_or(0.0, |h| h.confidence);
         if let Some(h) = &best_hypothesis {
             self.event(
                 "reasoning.verdict.hypothesis_selected",
@@ -71,14 +117,26 @@ impl ReasoningRuntime {
                 json!({ "directive_id": inference.directive.id }),
             );
         }
+        let threshold = self.acceptance_threshold;
         Ok(Verdict {
             directive_id: inference.directive.id,
             hypothesis: best_hypothesis,
-            notes: if best_score >= 0.5 {
-                "hypothesis accepted".into()
+            notes: if best_score >= threshold {
+                format!("hypothesis accepted ({best_score:.2} >= threshold {threshold:.2})")
             } else {
-                "insufficient confidence".into()
+                format!("insufficient confidence ({best_score:.2} < threshold {threshold:.2})")
             },
+            contributions,
+            threshold,
+            #[cfg(feature = "ranked-hypotheses")]
+            runners_up: ranked
+                .map(|(hypothesis, contributions)| RankedHypothesis {
+                    hypothesis_id: hypothesis.id,
+                    summary: hypothesis.summary,
+                    confidence: hypothesis.confidence,
+                    contributions,
+                })
+                .collect(),
             decided_at: chrono::Utc::now(),
         })
     }
@@ -118,4 +176,91 @@ mod tests {
         let verdict = runtime.reason(directive, signals).await.unwrap();
         assert_eq!(verdict.hypothesis.is_some(), true);
     }
+
+    #[tokio::test]
+    async fn explanation_lists_domain_contributions() {
+        let runtime = ReasoningRuntime::default();
+        let directive = ReasoningDirective::new("Assess anomaly", DirectivePriority::High);
+        let signals = vec![
+            SignalPacket::new("sensor spike", json!({ "value": 12 })),
+            SignalPacket::new("latency jump", json!({ "ms": 300 })),
+        ];
+        let verdict = runtime.reason(directive, signals).await.unwrap();
+        let explanation = verdict.explanation();
+
+        let selected = explanation.selected.expect("selected hypothesis");
+        let domains = explanation
+            .contributions
+            .iter()
+            .map(|c| c.domain.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(domains, vec!["actions", "causal"]);
+        assert!((explanation.threshold - ACCEPTANCE_THRESHOLD).abs() < f32::EPSILON);
+        assert_eq!(
+            explanation.accepted,
+            selected.confidence >= ACCEPTANCE_THRESHOLD
+        );
+        let domain_share = explanation
+            .contributions
+            .iter()
+            .map(|c| c.contribution)
+            .sum::<f32>();
+        assert!(domain_share > 0.0 && domain_share <= selected.confidence);
+        #[cfg(feature = "ranked-hypotheses")]
+        assert!(explanation
+            .runners_up
+            .iter()
+            .all(|runner| runner.confidence <= selected.confidence));
+    }
+
+    #[tokio::test]
+    async fn strict_threshold_rejects_mid_confidence_hypothesis() {
+        let reason = |runtime: ReasoningRuntime| async move {
+            let directive = ReasoningDirective::new("Assess anomaly", DirectivePriority::High);
+            let signals = vec![SignalPacket::new("sensor spike", json!({ "value": 12 }))];
+            runtime.reason(directive, signals).await.unwrap()
+        };
+        let lenient = reason(ReasoningRuntime::default().with_acceptance_threshold(0.0)).await;
+        let confidence = lenient.hypothesis.as_ref().unwrap().confidence;
+        assert!(confidence > 0.0 && confidence < 0.95, "{confidence}");
+        assert!(lenient.notes.starts_with("hypothesis accepted"));
+
+        let strict = reason(ReasoningRuntime::default().with_acceptance_threshold(0.95)).await;
+        assert!(strict.notes.starts_with("insufficient confidence"));
+        assert!(strict.notes.contains("threshold 0.95"));
+        let explanation = strict.explanation();
+        assert!(!explanation.accepted);
+        assert!((explanation.threshold - 0.95).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn knowledge_snippets_become_signals() {
+        let snippets = vec![
+            KnowledgeSnippet {
+                record_id: uuid::Uuid::new_v4(),
+                excerpt: "cache misses spike after deploys".into(),
+                score: 0.9,
+                generated_at: chrono::Utc::now(),
+                highlights: Vec::new(),
+            },
+            KnowledgeSnippet {
+                record_id: uuid::Uuid::new_v4(),
+                excerpt: "warming the cache restores latency".into(),
+                score: 0.6,
+                generated_at: chrono::Utc::now(),
+                highlights: Vec::new(),
+            },
+        ];
+        let signal = SignalPacket::from_snippet(&snippets[0]);
+        assert_eq!(signal.narrative, snippets[0].excerpt);
+        assert_eq!(signal.payload["record_id"], json!(snippets[0].record_id));
+
+        let runtime = ReasoningRuntime::default();
+        let directive = ReasoningDirective::new("Explain latency", DirectivePriority::High);
+        let verdict = runtime
+            .reason_from_knowledge(directive, snippets)
+            .await
+            .unwrap();
+        assert!(verdict.hypothesis.is_some());
+    }
 }
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
+use zappy_knowledge::KnowledgeSnippet;
 
 /// Priority tiers for reasoning directives.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -76,8 +77,26 @@ impl SignalPacket {
             observed_at: Utc::now(),
         }
     }
+
+    /// Turns a knowledge snippet into a signal carrying its record id and score.
+    #[must_use]
+    pub fn from_snippet(snippet: &KnowledgeSnippet) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            narrative: snippet.excerpt.clone(),
+            payload: serde_json::json!({
+                "source": "knowledge",
+                "record_id": snippet.record_id,
+                "score": snippet.score
+            }),
+            observed_at: snippet.generated_at,
+        }
+    }
 }
 
+/// Default aggregate confidence a hypothesis needs for the verdict to accept it.
+pub const ACCEPTANCE_THRESHOLD: f32 = 0.5;
+
 /// Hypothesis generated by the reasoning engine.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasoningHypothesis {
@@ -110,4 +129,77 @@ pub struct Verdict {
     pub notes: String,
     /// Decision timestamp.
     pub decided_at: DateTime<Utc>,
+    /// Domain scores behind the selected hypothesis.
+    #[serde(default)]
+    pub contributions: Vec<DomainContribution>,
+    /// Acceptance threshold the verdict was decided against.
+    #[serde(default = "default_threshold")]
+    pub threshold: f32,
+    /// Other reviewed hypotheses, best first.
+    #[cfg(feature = "ranked-hypotheses")]
+    #[serde(default)]
+    pub runners_up: Vec<RankedHypothesis>,
+}
+
+const fn default_threshold() -> f32 {
+    ACCEPTANCE_THRESHOLD
+}
+
+impl Verdict {
+    /// Structured account of how the verdict was reached.
+    #[must_use]
+    pub fn explanation(&self) -> Explanation {
+        Explanation {
+            selected: self.hypothesis.clone(),
+            accepted: self
+                .hypothesis
+                .as_ref()
+                .is_some_and(|h| h.confidence >= self.threshold),
+            threshold: self.threshold,
+            contributions: self.contributions.clone(),
+            #[cfg(feature = "ranked-hypotheses")]
+            runners_up: self.runners_up.clone(),
+        }
+    }
+}
+
+/// Score a single review domain assigned to a hypothesis.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DomainContribution {
+    /// Domain label.
+    pub domain: String,
+    /// Raw domain score (0-1).
+    pub score: f32,
+    /// Share of the aggregate confidence attributable to this domain.
+    pub contribution: f32,
+}
+
+/// Hypothesis that was reviewed but not selected.
+#[cfg(feature = "ranked-hypotheses")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedHypothesis {
+    /// Hypothesis id.
+    pub hypothesis_id: Uuid,
+    /// Summary text.
+    pub summary: String,
+    /// Aggregate confidence after review.
+    pub confidence: f32,
+    /// Domain scores behind the confidence.
+    pub contributions: Vec<DomainContribution>,
+}
+
+/// Structured explanation of a [`Verdict`] for audit trails and UIs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Explanation {
+    /// Selected hypothesis with its reviewed confidence.
+    pub selected: Option<ReasoningHypothesis>,
+    /// Whether the selected hypothesis cleared the threshold.
+    pub accepted: bool,
+    /// Decisive acceptance threshold.
+    pub threshold: f32,
+    /// Per-domain contributions to the selected hypothesis.
+    pub contributions: Vec<DomainContribution>,
+    /// Runner-up hypotheses, best first.
+    #[cfg(feature = "ranked-hypotheses")]
+    pub runners_up: Vec<RankedHypothesis>,
 }
@@ -0,0 +1,81 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+use crate::{module::ReasoningHypothesis, multidomain::domain::DomainOutcome};
+
+/// LRU cache of domain outcomes keyed by hypothesis content.
+pub struct DomainCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<IndexMap<HypothesisKey, (Instant, Vec<DomainOutcome>)>>,
+    hits: AtomicU64,
+}
+
+impl DomainCache {
+    /// Creates a cache holding at most `capacity` hypotheses for `ttl` each.
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            entries: Mutex::new(IndexMap::new()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns cached outcomes for `key` if present and fresh.
+    pub fn get(&self, key: &HypothesisKey) -> Option<Vec<DomainOutcome>> {
+        let mut entries = self.entries.lock();
+        let (key, (stored_at, outcomes)) = entries.shift_remove_entry(key)?;
+        if stored_at.elapsed() > self.ttl {
+            return None;
+        }
+        entries.insert(key, (stored_at, outcomes.clone()));
+        drop(entries);
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(outcomes)
+    }
+
+    /// Stores outcomes, evicting the least recently used entry when full.
+    pub fn insert(&self, key: HypothesisKey, outcomes: Vec<DomainOutcome>) {
+        let mut entries = self.entries.lock();
+        entries.shift_remove(&key);
+        if entries.len() >= self.capacity {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(key, (Instant::now(), outcomes));
+    }
+
+    /// Total cache hits served so far.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+/// Content of a hypothesis, ignoring its per-cycle id.
+///
+/// Entries are compared field by field on lookup, so hash collisions never share outcomes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HypothesisKey {
+    summary: String,
+    confidence_bits: u32,
+    supporting_signals: Vec<Uuid>,
+}
+
+impl HypothesisKey {
+    /// Captures the fields that determine a hypothesis' domain outcomes.
+    #[must_use]
+    pub fn of(hypothesis: &ReasoningHypothesis) -> Self {
+        Self {
+            summary: hypothesis.summary.clone(),
+            confidence_bits: hypothesis.confidence.to_bits(),
+            supporting_signals: hypothesis.supporting_signals.clone(),
+        }
+    }
+}
@@ -12,6 +12,13 @@ pub fn aggregate_confidence(hypothesis: &ReasoningHypothesis, domain_scores: &[f
     ((hypothesis.confidence * 0.6) + avg * 0.4).clamp(0.0, 1.0)
 }
 
+/// Portion of [`aggregate_confidence`] contributed by one of `domains` domain scores.
+#[must_use]
+pub fn domain_contribution(score: f32, domains: usize) -> f32 {
+    let domains = u16::try_from(domains.max(1)).unwrap_or(u16::MAX);
+    score * 0.4 / f32::from(domains)
+}
+
 /// Builds metadata payload for telemetry.
 #[must_use]
 pub fn telemetry_payload(hypothesis: &ReasoningHypothesis, aggregate: f32) -> serde_json::Value {
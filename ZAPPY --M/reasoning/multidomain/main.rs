@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use anyhow::Result;
 
@@ -6,19 +6,28 @@ use anyhow::Result;
 pub mod actions;
 /// Advanced/causal reasoning reviewers.
 pub mod advanced;
+/// Content-addressed cache of domain outcomes.
+pub mod cache;
 /// Shared trait definitions for domains.
 pub mod domain;
 /// Helper functions for multi-domain aggregation.
 pub mod helper;
 /// Hypothesis review orchestration.
 pub mod reviewer;
+/// Declarative rule-based domain.
+pub mod rules;
 
-use crate::{module::ReasoningHypothesis, telemetry::ReasoningTelemetry};
+use crate::{
+    module::{DomainContribution, ReasoningHypothesis},
+    telemetry::ReasoningTelemetry,
+};
 use actions::ActionsDomain;
 use advanced::CausalDomain;
 use domain::ReasoningDomain;
 use reviewer::HypothesisReviewer;
 
+pub use rules::{DomainRule, RuleBasedDomain, RuleCondition};
+
 /// Coordinates multi-domain reasoning reviews.
 pub struct MultiDomainCoordinator {
     reviewer: HypothesisReviewer,
@@ -37,8 +46,34 @@ impl MultiDomainCoordinator {
         }
     }
 
-    /// Reviews a hypothesis and returns aggregate confidence.
+    /// Registers an additional domain, such as a [`RuleBasedDomain`], next to the defaults.
+    #[must_use]
+    pub fn with_domain(mut self, domain: Arc<dyn ReasoningDomain>) -> Self {
+        self.reviewer = self.reviewer.with_domain(domain);
+        self
+    }
+
+    /// Caches domain outcomes for up to `capacity` hypotheses, each for `ttl`.
+    #[must_use]
+    pub fn with_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.reviewer = self.reviewer.with_cache(capacity, ttl);
+        self
+    }
+
+    /// Reviews a hypothesis across all domains concurrently and returns aggregate confidence.
     pub async fn review(&self, hypothesis: &ReasoningHypothesis) -> Result<f32> {
         self.reviewer.review(hypothesis).await
     }
+
+    /// Reviews a hypothesis, returning aggregate confidence and per-domain contributions.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`HypothesisReviewer::review_detailed`].
+    pub async fn review_detailed(
+        &self,
+        hypothesis: &ReasoningHypothesis,
+    ) -> Result<(f32, Vec<DomainContribution>)> {
+        self.reviewer.review_detailed(hypothesis).await
+    }
 }
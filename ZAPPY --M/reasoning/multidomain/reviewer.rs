@@ -1,13 +1,14 @@
-use std::sync::Arc;
+use std::{panic::AssertUnwindSafe, sync::Arc, time::Duration};
 
 use anyhow::Result;
-use futures::future::join_all;
+use futures::{future::join_all, FutureExt};
 
 use crate::{
-    module::ReasoningHypothesis,
+    module::{DomainContribution, ReasoningHypothesis},
     multidomain::{
+        cache::{DomainCache, HypothesisKey},
         domain::{DomainOutcome, ReasoningDomain},
-        helper::{aggregate_confidence, telemetry_payload},
+        helper::{aggregate_confidence, domain_contribution, telemetry_payload},
     },
     telemetry::ReasoningTelemetry,
 };
@@ -16,6 +17,7 @@ use crate::{
 pub struct HypothesisReviewer {
     domains: Vec<Arc<dyn ReasoningDomain>>,
     telemetry: Option<ReasoningTelemetry>,
+    cache: Option<DomainCache>,
 }
 
 impl HypothesisReviewer {
@@ -25,20 +27,68 @@ impl HypothesisReviewer {
         domains: Vec<Arc<dyn ReasoningDomain>>,
         telemetry: Option<ReasoningTelemetry>,
     ) -> Self {
-        Self { domains, telemetry }
+        Self {
+            domains,
+            telemetry,
+            cache: None,
+        }
+    }
+
+    /// Adds a domain to every subsequent review.
+    #[must_use]
+    pub fn with_domain(mut self, domain: Arc<dyn ReasoningDomain>) -> Self {
+        self.domains.push(domain);
+        self
+    }
+
+    /// Reuses domain outcomes for identical hypotheses within `ttl`.
+    #[must_use]
+    pub fn with_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.cache = Some(DomainCache::new(capacity, ttl));
+        self
+    }
+
+    /// Number of reviews served from the cache.
+    #[must_use]
+    pub fn cache_hits(&self) -> u64 {
+        self.cache.as_ref().map_or(0, DomainCache::hits)
     }
 
     /// Runs the review pipeline.
     pub async fn review(&self, hypothesis: &ReasoningHypothesis) -> Result<f32> {
-        let futures = self
-            .domains
-            .iter()
-            .map(|domain| {
-                let domain = Arc::clone(domain);
-                async move { domain.evaluate(hypothesis).await }
-            })
-            .collect::<Vec<_>>();
-        let outcomes: Vec<DomainOutcome> = join_all(futures).await;
+        Ok(self.review_detailed(hypothesis).await?.0)
+    }
+
+    /// Runs the review pipeline, also returning each domain's contribution.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible: a domain that panics is left out of the aggregate instead
+    /// of failing the review. The `Result` mirrors [`Self::review`].
+    pub async fn review_detailed(
+        &self,
+        hypothesis: &ReasoningHypothesis,
+    ) -> Result<(f32, Vec<DomainContribution>)> {
+        let key = HypothesisKey::of(hypothesis);
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(&key));
+        let outcomes = if let Some(outcomes) = cached {
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.event(
+                    "reasoning.domain_cache.hit",
+                    serde_json::json!({
+                        "hypothesis_id": hypothesis.id,
+                        "hits": self.cache_hits()
+                    }),
+                );
+            }
+            outcomes
+        } else {
+            let (outcomes, complete) = self.evaluate_domains(hypothesis).await;
+            if let Some(cache) = self.cache.as_ref().filter(|_| complete) {
+                cache.insert(key, outcomes.clone());
+            }
+            outcomes
+        };
         let scores: Vec<f32> = outcomes.iter().map(|o| o.score).collect();
         let aggregate = aggregate_confidence(hypothesis, &scores);
         if let Some(tel) = &self.telemetry {
@@ -50,6 +100,240 @@ impl HypothesisReviewer {
             );
             let _ = tel.event("reasoning.hypothesis.reviewed", payload);
         }
-        Ok(aggregate)
+        let contributions = outcomes
+            .iter()
+            .map(|outcome| DomainContribution {
+                domain: outcome.domain.clone(),
+                score: outcome.score,
+                contribution: domain_contribution(outcome.score, scores.len()),
+            })
+            .collect();
+        Ok((aggregate, contributions))
+    }
+
+    /// Evaluates every domain concurrently, keeping outcomes in domain order.
+    ///
+    /// A domain that panics is left out of the aggregate instead of failing the review;
+    /// the returned flag is false when that happened, so partial results are not cached.
+    async fn evaluate_domains(
+        &self,
+        hypothesis: &ReasoningHypothesis,
+    ) -> (Vec<DomainOutcome>, bool) {
+        let futures = self
+            .domains
+            .iter()
+            .map(|domain| {
+                let domain = Arc::clone(domain);
+                async move {
+                    AssertUnwindSafe(domain.evaluate(hypothesis))
+                        .catch_unwind()
+                        .await
+                        .map_err(|_| domain.label().to_string())
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut outcomes = Vec::with_capacity(futures.len());
+        let mut complete = true;
+        for result in join_all(futures).await {
+            match result {
+                Ok(outcome) => outcomes.push(outcome),
+                Err(domain) => {
+                    complete = false;
+                    if let Some(tel) = &self.telemetry {
+                        let _ = tel.log(
+                            shared_logging::LogLevel::Error,
+                            "reasoning.domain.panicked",
+                            serde_json::json!({
+                                "hypothesis_id": hypothesis.id,
+                                "domain": domain,
+                            }),
+                        );
+                    }
+                }
+            }
+        }
+        (outcomes, complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::Barrier;
+    use uuid::Uuid;
+
+    struct CountingDomain {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ReasoningDomain for CountingDomain {
+        fn label(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn evaluate(&self, hypothesis: &ReasoningHypothesis) -> DomainOutcome {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            DomainOutcome {
+                domain: self.label().into(),
+                score: hypothesis.confidence,
+                metadata: serde_json::Value::Null,
+            }
+        }
+    }
+
+    /// Waits for every sibling domain to start, so sequential evaluation would deadlock.
+    struct RendezvousDomain {
+        label: &'static str,
+        barrier: Arc<Barrier>,
+        delay: Duration,
+        score: f32,
+    }
+
+    #[async_trait]
+    impl ReasoningDomain for RendezvousDomain {
+        fn label(&self) -> &'static str {
+            self.label
+        }
+
+        async fn evaluate(&self, _hypothesis: &ReasoningHypothesis) -> DomainOutcome {
+            self.barrier.wait().await;
+            tokio::time::sleep(self.delay).await;
+            DomainOutcome {
+                domain: self.label.into(),
+                score: self.score,
+                metadata: serde_json::Value::Null,
+            }
+        }
+    }
+
+    struct PanickingDomain;
+
+    #[async_trait]
+    impl ReasoningDomain for PanickingDomain {
+        fn label(&self) -> &'static str {
+            "panicking"
+        }
+
+        async fn evaluate(&self, _hypothesis: &ReasoningHypothesis) -> DomainOutcome {
+            panic!("domain bug");
+        }
+    }
+
+    fn hypothesis() -> ReasoningHypothesis {
+        ReasoningHypothesis {
+            id: Uuid::new_v4(),
+            summary: "latency regression after deploy".into(),
+            confidence: 0.7,
+            supporting_signals: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn identical_hypotheses_reuse_domain_outcomes() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let reviewer = HypothesisReviewer::new(
+            vec![Arc::new(CountingDomain {
+                calls: Arc::clone(&calls),
+            })],
+            None,
+        )
+        .with_cache(8, Duration::from_secs(30));
+        let first = reviewer.review(&hypothesis()).await.unwrap();
+        let second = reviewer.review(&hypothesis()).await.unwrap();
+        assert!((first - second).abs() < f32::EPSILON);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(reviewer.cache_hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn domains_are_evaluated_concurrently_in_stable_order() {
+        let barrier = Arc::new(Barrier::new(2));
+        let reviewer = HypothesisReviewer::new(
+            vec![
+                Arc::new(RendezvousDomain {
+                    label: "slow",
+                    barrier: Arc::clone(&barrier),
+                    delay: Duration::from_millis(50),
+                    score: 0.9,
+                }),
+                Arc::new(PanickingDomain),
+                Arc::new(RendezvousDomain {
+                    label: "fast",
+                    barrier,
+                    delay: Duration::ZERO,
+                    score: 0.1,
+                }),
+            ],
+            None,
+        );
+        let (_, contributions) = tokio::time::timeout(
+            Duration::from_secs(5),
+            reviewer.review_detailed(&hypothesis()),
+        )
+        .await
+        .expect("domains should not run one after another")
+        .unwrap();
+        let domains = contributions
+            .iter()
+            .map(|contribution| contribution.domain.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(domains, vec!["slow", "fast"]);
+    }
+
+    #[tokio::test]
+    async fn reviews_with_panicked_domains_are_not_cached() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let reviewer = HypothesisReviewer::new(
+            vec![
+                Arc::new(CountingDomain {
+                    calls: Arc::clone(&calls),
+                }),
+                Arc::new(PanickingDomain),
+            ],
+            None,
+        )
+        .with_cache(8, Duration::from_secs(30));
+        reviewer.review(&hypothesis()).await.unwrap();
+        reviewer.review(&hypothesis()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(reviewer.cache_hits(), 0);
+    }
+
+    #[tokio::test]
+    async fn hypotheses_with_different_content_are_not_shared() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let reviewer = HypothesisReviewer::new(
+            vec![Arc::new(CountingDomain {
+                calls: Arc::clone(&calls),
+            })],
+            None,
+        )
+        .with_cache(8, Duration::from_secs(30));
+        let mut other = hypothesis();
+        other.supporting_signals.push(Uuid::new_v4());
+        reviewer.review(&hypothesis()).await.unwrap();
+        reviewer.review(&other).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(reviewer.cache_hits(), 0);
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_reevaluated() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let reviewer = HypothesisReviewer::new(
+            vec![Arc::new(CountingDomain {
+                calls: Arc::clone(&calls),
+            })],
+            None,
+        )
+        .with_cache(8, Duration::ZERO);
+        reviewer.review(&hypothesis()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        reviewer.review(&hypothesis()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(reviewer.cache_hits(), 0);
     }
 }
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    module::ReasoningHypothesis,
+    multidomain::domain::{DomainOutcome, ReasoningDomain},
+};
+
+/// Condition evaluated against a hypothesis; every populated field must hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleCondition {
+    /// Case-insensitive substring required in the hypothesis summary.
+    #[serde(default)]
+    pub summary_contains: Option<String>,
+    /// Minimum hypothesis confidence.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Maximum hypothesis confidence.
+    #[serde(default)]
+    pub max_confidence: Option<f32>,
+    /// Minimum number of supporting signals.
+    #[serde(default)]
+    pub min_signals: Option<usize>,
+}
+
+impl RuleCondition {
+    /// Returns true when the hypothesis satisfies the condition.
+    #[must_use]
+    pub fn matches(&self, hypothesis: &ReasoningHypothesis) -> bool {
+        self.summary_contains.as_ref().is_none_or(|needle| {
+            hypothesis
+                .summary
+                .to_lowercase()
+                .contains(&needle.to_lowercase())
+        }) && self
+            .min_confidence
+            .is_none_or(|min| hypothesis.confidence >= min)
+            && self
+                .max_confidence
+                .is_none_or(|max| hypothesis.confidence <= max)
+            && self
+                .min_signals
+                .is_none_or(|min| hypothesis.supporting_signals.len() >= min)
+    }
+}
+
+/// Declarative if-then rule adjusting the domain score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainRule {
+    /// Rule name reported when it fires.
+    pub name: String,
+    /// Condition that must hold.
+    #[serde(default)]
+    pub when: RuleCondition,
+    /// Score adjustment applied when the rule fires.
+    pub adjustment: f32,
+}
+
+/// Deterministic domain scoring hypotheses against declarative rules.
+#[derive(Debug, Clone)]
+pub struct RuleBasedDomain {
+    label: String,
+    rules: Vec<DomainRule>,
+}
+
+impl RuleBasedDomain {
+    /// Creates a domain from rules.
+    #[must_use]
+    pub fn new(label: impl Into<String>, rules: Vec<DomainRule>) -> Self {
+        Self {
+            label: label.into(),
+            rules,
+        }
+    }
+
+    /// Parses rules from a JSON array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `raw` is not a JSON array of valid rules.
+    pub fn from_json(label: impl Into<String>, raw: &str) -> Result<Self> {
+        let rules = serde_json::from_str(raw).context("invalid reasoning rule set")?;
+        Ok(Self::new(label, rules))
+    }
+
+    /// Loads rules from a JSON config file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or [`Self::from_json`] rejects it.
+    pub fn load(label: impl Into<String>, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read rules from {}", path.display()))?;
+        Self::from_json(label, &raw)
+    }
+
+    /// Returns configured rules.
+    #[must_use]
+    pub fn rules(&self) -> &[DomainRule] {
+        &self.rules
+    }
+}
+
+#[async_trait]
+impl ReasoningDomain for RuleBasedDomain {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    async fn evaluate(&self, hypothesis: &ReasoningHypothesis) -> DomainOutcome {
+        let fired = self
+            .rules
+            .iter()
+            .filter(|rule| rule.when.matches(hypothesis))
+            .collect::<Vec<_>>();
+        let adjustment = fired.iter().map(|rule| rule.adjustment).sum::<f32>();
+        DomainOutcome {
+            domain: self.label.clone(),
+            score: (hypothesis.confidence + adjustment).clamp(0.0, 1.0),
+            metadata: json!({
+                "hypothesis_id": hypothesis.id,
+                "fired_rules": fired.iter().map(|rule| rule.name.as_str()).collect::<Vec<_>>(),
+                "adjustment": adjustment,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn fired_rules_are_reported() {
+        let domain = RuleBasedDomain::from_json(
+            "rules",
+            r#"[
+                { "name": "latency-incident", "when": { "summary_contains": "latency" }, "adjustment": 0.2 },
+                { "name": "weak-evidence", "when": { "max_confidence": 0.1 }, "adjustment": -0.3 }
+            ]"#,
+        )
+        .unwrap();
+        let hypothesis = ReasoningHypothesis {
+            id: Uuid::new_v4(),
+            summary: "Latency spike -> 2 related signals".into(),
+            confidence: 0.5,
+            supporting_signals: vec![Uuid::new_v4()],
+        };
+        let outcome = domain.evaluate(&hypothesis).await;
+        assert_eq!(outcome.metadata["fired_rules"], json!(["latency-incident"]));
+        assert!((outcome.score - 0.7).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn coordinator_reviews_registered_rule_domains() {
+        let domain = RuleBasedDomain::new("rules", Vec::new());
+        let coordinator = crate::multidomain::MultiDomainCoordinator::with_defaults(None)
+            .with_domain(std::sync::Arc::new(domain));
+        let hypothesis = ReasoningHypothesis {
+            id: Uuid::new_v4(),
+            summary: "Latency spike".into(),
+            confidence: 0.5,
+            supporting_signals: Vec::new(),
+        };
+        let (_, contributions) = coordinator.review_detailed(&hypothesis).await.unwrap();
+        assert!(contributions
+            .iter()
+            .any(|contribution| contribution.domain == "rules"));
+    }
+}
@@ -29,6 +29,10 @@ pub mod multidomain;
 pub mod runtime;
 
 pub use engine::{InferenceEngine, InferenceResult, SignalGraph};
-pub use module::{ReasoningDirective, ReasoningHypothesis, SignalPacket, Verdict};
+#[cfg(feature = "ranked-hypotheses")]
+pub use module::RankedHypothesis;
+pub use module::{
+    DomainContribution, Explanation, ReasoningDirective, ReasoningHypothesis, SignalPacket, Verdict,
+};
 pub use runtime::ReasoningRuntime;
 pub use telemetry::{ReasoningTelemetry, ReasoningTelemetryBuilder};
@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 use rand::Rng;
+use semver::Version;
 use serde_json::json;
 use uuid::Uuid;
 
@@ -8,16 +11,74 @@ use crate::{
     module::{UpgradeDirective, UpgradeFinding},
 };
 
+/// Post-upgrade health probe returning a severity (0 = healthy, 1 = down) per target.
+pub trait HealthProbe: Send + Sync {
+    /// Probes `target` after the upgrade was applied to it.
+    fn severity(&self, target: &str) -> f32;
+}
+
+impl<F> HealthProbe for F
+where
+    F: Fn(&str) -> f32 + Send + Sync,
+{
+    fn severity(&self, target: &str) -> f32 {
+        self(target)
+    }
+}
+
 /// Performs system diagnostics prior to an upgrade.
 pub struct UpgradeChecker {
     telemetry: Option<UpgradeTelemetry>,
+    probe: Option<Arc<dyn HealthProbe>>,
 }
 
 impl UpgradeChecker {
     /// Creates checker.
     #[must_use]
     pub fn new(telemetry: Option<UpgradeTelemetry>) -> Self {
-        Self { telemetry }
+        Self {
+            telemetry,
+            probe: None,
+        }
+    }
+
+    /// Uses `probe` for post-upgrade health checks; without one every target reports healthy.
+    #[must_use]
+    pub fn with_health_probe(mut self, probe: Arc<dyn HealthProbe>) -> Self {
+        self.probe = Some(probe);
+        self
+    }
+
+    /// Health-checks upgraded `targets`, returning one finding per target.
+    #[must_use]
+    pub fn health_check(
+        &self,
+        directive: &UpgradeDirective,
+        targets: &[String],
+    ) -> Vec<UpgradeFinding> {
+        let findings: Vec<_> = targets
+            .iter()
+            .map(|target| {
+                let severity = self
+                    .probe
+                    .as_ref()
+                    .map_or(0.0, |probe| probe.severity(target));
+                UpgradeFinding {
+                    id: Uuid::new_v4(),
+                    severity,
+                    message: format!("health {target} severity {severity:.2}"),
+                    remediation: format!("roll back {target}"),
+                }
+            })
+            .collect();
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.log(
+                shared_logging::LogLevel::Info,
+                "self_upgrade.checker.health",
+                json!({ "directive": directive.id, "targets": targets }),
+            );
+        }
+        findings
     }
 
     /// Runs diagnostics returning findings.
@@ -44,6 +105,59 @@ impl UpgradeChecker {
     }
 }
 
+/// Parses a version leniently, accepting a `v` prefix and missing minor/patch parts.
+#[must_use]
+pub fn parse_version(raw: &str) -> Option<Version> {
+    let trimmed = raw.trim().trim_start_matches(['v', 'V']);
+    let (core, rest) = trimmed
+        .find(['-', '+'])
+        .map_or((trimmed, ""), |idx| trimmed.split_at(idx));
+    let parts = core.split('.').count();
+    let padded = match parts {
+        1 => format!("{core}.0.0{rest}"),
+        2 => format!("{core}.0{rest}"),
+        _ => trimmed.to_string(),
+    };
+    Version::parse(&padded).ok()
+}
+
+/// Validates a `current -> target` transition against semver rules.
+///
+/// Downgrades and skipped breaking releases (major, or minor while on `0.x`) are illegal.
+#[must_use]
+pub fn version_constraints(current: &str, target: &str) -> Vec<UpgradeFinding> {
+    let violation = |message: String, remediation: &str| UpgradeFinding {
+        id: Uuid::new_v4(),
+        severity: 1.0,
+        message,
+        remediation: remediation.into(),
+    };
+    let (Some(from), Some(to)) = (parse_version(current), parse_version(target)) else {
+        return vec![violation(
+            format!("cannot parse version transition {current} -> {target}"),
+            "use semantic versions such as 1.2.0",
+        )];
+    };
+    if to < from {
+        return vec![violation(
+            format!("downgrade from {from} to {to} is not allowed"),
+            "roll back through the release process instead",
+        )];
+    }
+    let skipped = if from.major == 0 && to.major == 0 {
+        to.minor > from.minor + 1
+    } else {
+        to.major > from.major + 1
+    };
+    if skipped {
+        return vec![violation(
+            format!("upgrade from {from} to {to} skips a breaking release"),
+            "upgrade through each intermediate breaking release",
+        )];
+    }
+    Vec::new()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +169,15 @@ mod tests {
         let findings = checker.run(&directive).unwrap();
         assert!(!findings.is_empty());
     }
+
+    #[test]
+    fn version_constraints_follow_semver() {
+        assert_eq!(parse_version("v3"), Some(Version::new(3, 0, 0)));
+        assert!(version_constraints("1.4.2", "v2").is_empty());
+        assert!(version_constraints("0.1.0", "0.2.0").is_empty());
+        assert_eq!(version_constraints("1.0.0", "v3").len(), 1);
+        assert_eq!(version_constraints("0.1.0", "0.3.0").len(), 1);
+        assert_eq!(version_constraints("2.0.0", "1.9.0").len(), 1);
+        assert_eq!(version_constraints("1.0.0", "latest").len(), 1);
+    }
 }
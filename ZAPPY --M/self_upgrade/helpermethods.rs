@@ -1,6 +1,8 @@
 use std::{fmt, path::PathBuf, sync::Arc};
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use shared_event_bus::{EventPublisher, EventRecord};
 use shared_logging::{JsonLogger, LogLevel, LogRecord};
@@ -139,6 +141,7 @@ impl UpgradeTelemetry {
                 event_type: event_type.into(),
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 payload,
+                sequence: 0,
             })?;
         }
         Ok(())
@@ -153,6 +156,113 @@ pub fn read_json(path: impl AsRef<std::path::Path>) -> Result<Value> {
     Ok(value)
 }
 
+/// Age after which a lock is considered abandoned even if its process still appears alive.
+const STALE_LOCK_AFTER_SECS: i64 = 60 * 60;
+
+/// Contents of an upgrade lockfile.
+#[derive(Debug, Serialize, Deserialize)]
+struct LockHolder {
+    pid: u32,
+    owner: Uuid,
+    acquired_at: DateTime<Utc>,
+}
+
+impl LockHolder {
+    /// Whether the holder crashed or outlived [`STALE_LOCK_AFTER_SECS`].
+    fn is_gone(&self) -> bool {
+        Utc::now() - self.acquired_at > chrono::Duration::seconds(STALE_LOCK_AFTER_SECS)
+            || !process_alive(self.pid)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_alive(pid: u32) -> bool {
+    std::path::Path::new("/proc").join(pid.to_string()).exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+const fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Exclusive lockfile guarding an in-flight upgrade; removed on drop (including unwinding).
+///
+/// The file records the holder's PID and acquisition time so a lock left behind by a
+/// crashed process (or one older than an hour) is reclaimed instead of blocking upgrades
+/// forever.
+#[derive(Debug)]
+pub struct UpgradeLock {
+    path: PathBuf,
+}
+
+impl UpgradeLock {
+    /// Attempts to take the lock, returning `Ok(None)` when a live upgrade holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock directory or file cannot be created or written.
+    pub fn try_acquire(path: impl Into<PathBuf>, owner: Uuid) -> Result<Option<Self>> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if let Some(lock) = Self::create(&path, owner)? {
+            return Ok(Some(lock));
+        }
+        let stale = std::fs::read(&path)
+            .ok()
+            .and_then(|data| serde_json::from_slice::<LockHolder>(&data).ok())
+            .is_some_and(|holder| holder.is_gone());
+        if !stale {
+            return Ok(None);
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(err).with_context(|| format!("reclaiming lock {}", path.display()))
+            }
+        }
+        Self::create(&path, owner)
+    }
+
+    fn create(path: &std::path::Path, owner: Uuid) -> Result<Option<Self>> {
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+        {
+            Ok(file) => {
+                let holder = LockHolder {
+                    pid: std::process::id(),
+                    owner,
+                    acquired_at: Utc::now(),
+                };
+                let lock = Self {
+                    path: path.to_path_buf(),
+                };
+                serde_json::to_writer(file, &holder)
+                    .with_context(|| format!("writing lock {}", path.display()))?;
+                Ok(Some(lock))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("creating lock {}", path.display())),
+        }
+    }
+
+    /// Path of the lockfile.
+    #[must_use]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl Drop for UpgradeLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,4 +291,72 @@ mod tests {
             .contains("upgrade.start"));
         assert_eq!(bus.snapshot().len(), 1);
     }
+
+    #[test]
+    fn lock_is_exclusive_and_released_on_panic() {
+        let tmp = tempdir().unwrap();
+        let path = tmp.path().join("upgrade.lock");
+        let held = UpgradeLock::try_acquire(&path, Uuid::new_v4()).unwrap();
+        assert!(held.is_some());
+        assert!(UpgradeLock::try_acquire(&path, Uuid::new_v4())
+            .unwrap()
+            .is_none());
+        drop(held);
+
+        let panic_path = path.clone();
+        let outcome = std::thread::spawn(move || {
+            let _lock = UpgradeLock::try_acquire(&panic_path, Uuid::new_v4()).unwrap();
+            panic!("upgrade failed mid-flight");
+        })
+        .join();
+        assert!(outcome.is_err());
+        assert!(!path.exists());
+    }
+
+    fn write_holder(path: &std::path::Path, pid: u32, acquired_at: DateTime<Utc>) {
+        let holder = LockHolder {
+            pid,
+            owner: Uuid::new_v4(),
+            acquired_at,
+        };
+        std::fs::write(path, serde_json::to_vec(&holder).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn lock_records_holder_and_reclaims_abandoned_locks() {
+        let tmp = tempdir().unwrap();
+        let path = tmp.path().join("upgrade.lock");
+        let owner = Uuid::new_v4();
+        let held = UpgradeLock::try_acquire(&path, owner).unwrap().unwrap();
+        let holder: LockHolder = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(holder.pid, std::process::id());
+        assert_eq!(holder.owner, owner);
+        drop(held);
+
+        write_holder(&path, std::process::id(), Utc::now());
+        assert!(UpgradeLock::try_acquire(&path, Uuid::new_v4())
+            .unwrap()
+            .is_none());
+
+        write_holder(
+            &path,
+            std::process::id(),
+            Utc::now() - chrono::Duration::hours(2),
+        );
+        assert!(UpgradeLock::try_acquire(&path, Uuid::new_v4())
+            .unwrap()
+            .is_some());
+
+        #[cfg(target_os = "linux")]
+        {
+            let mut child = std::process::Command::new("true").spawn().unwrap();
+            let dead = child.id();
+            child.wait().unwrap();
+            write_holder(&path, dead, Utc::now());
+            assert!(UpgradeLock::try_acquire(&path, Uuid::new_v4())
+                .unwrap()
+                .is_some());
+        }
+        assert!(!path.exists());
+    }
 }
@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 use serde_json::json;
 
 use crate::{
-    checker::UpgradeChecker,
-    helpermethods::UpgradeTelemetry,
-    module::{UpgradeDirective, UpgradePlan, UpgradeStatus},
+    checker::{HealthProbe, UpgradeChecker},
+    helpermethods::{UpgradeLock, UpgradeTelemetry},
+    module::{
+        CanaryPolicy, PhaseOutcome, RolloutPhase, RolloutStage, UpgradeDirective, UpgradePlan,
+        UpgradeStatus,
+    },
     planner::UpgradePlanner,
     reporter::UpgradeReporter,
     reviewer::UpgradeReviewer,
@@ -15,6 +20,8 @@ pub struct SelfUpgradeRuntime {
     telemetry: Option<UpgradeTelemetry>,
     planner: UpgradePlanner,
     reporter: UpgradeReporter,
+    lock_path: std::path::PathBuf,
+    canary: Option<CanaryPolicy>,
 }
 
 impl SelfUpgradeRuntime {
@@ -25,8 +32,33 @@ impl SelfUpgradeRuntime {
     }
 
     /// Processes directive and emits plan/report.
+    ///
+    /// Only one upgrade may run at a time; concurrent calls are rejected.
+    /// Plans blocked by the planner are reported and returned without executing.
     pub fn execute(&self, directive: UpgradeDirective) -> Result<UpgradePlan> {
+        let Some(_lock) = UpgradeLock::try_acquire(&self.lock_path, directive.id)? else {
+            let mut plan = UpgradePlan::new(directive.id, Vec::new());
+            plan.status = UpgradeStatus::Rejected;
+            plan.reason = Some("upgrade in progress".into());
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.event(
+                    "self_upgrade.execution.rejected",
+                    json!({ "directive": directive.id, "reason": plan.reason }),
+                );
+            }
+            return Ok(plan);
+        };
         let mut plan = self.planner.plan(&directive)?;
+        if plan.status == UpgradeStatus::Blocked {
+            self.reporter.write(&directive, &plan, "upgrade blocked")?;
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.event(
+                    "self_upgrade.execution.blocked",
+                    json!({ "directive": directive.id }),
+                );
+            }
+            return Ok(plan);
+        }
         plan.status = UpgradeStatus::InProgress;
         if let Some(tel) = &self.telemetry {
             let _ = tel.event(
@@ -34,8 +66,30 @@ impl SelfUpgradeRuntime {
                 json!({ "directive": directive.id }),
             );
         }
+        let remaining = match self.canary {
+            Some(policy) if !directive.targets.is_empty() => {
+                let (canary, rest) = directive
+                    .targets
+                    .split_at(policy.canary_size(directive.targets.len()));
+                if !self.run_canary(&directive, &mut plan, canary, policy) {
+                    self.reporter
+                        .write(&directive, &plan, "canary failed; rollback recorded")?;
+                    return Ok(plan);
+                }
+                rest
+            }
+            _ => directive.targets.as_slice(),
+        };
         // Simulated execution delay.
         std::thread::sleep(std::time::Duration::from_millis(50));
+        if !remaining.is_empty() {
+            plan.phases.push(RolloutPhase {
+                stage: RolloutStage::Full,
+                targets: remaining.to_vec(),
+                outcome: PhaseOutcome::Passed,
+                findings: Vec::new(),
+            });
+        }
         plan.status = UpgradeStatus::Completed;
         self.reporter
             .write(&directive, &plan, "upgrade completed")?;
@@ -53,12 +107,67 @@ impl SelfUpgradeRuntime {
     pub fn telemetry(&self) -> Option<&UpgradeTelemetry> {
         self.telemetry.as_ref()
     }
+
+    /// Upgrades and health-checks the canary targets, recording a rollback phase on failure.
+    ///
+    /// Returns whether the rollout may proceed to the remaining targets.
+    fn run_canary(
+        &self,
+        directive: &UpgradeDirective,
+        plan: &mut UpgradePlan,
+        canary: &[String],
+        policy: CanaryPolicy,
+    ) -> bool {
+        let findings = self.planner.checker().health_check(directive, canary);
+        let passed = findings
+            .iter()
+            .all(|finding| finding.severity <= policy.max_severity);
+        plan.phases.push(RolloutPhase {
+            stage: RolloutStage::Canary,
+            targets: canary.to_vec(),
+            outcome: if passed {
+                PhaseOutcome::Passed
+            } else {
+                PhaseOutcome::Failed
+            },
+            findings: findings.clone(),
+        });
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.event(
+                "self_upgrade.canary.checked",
+                json!({ "directive": directive.id, "targets": canary, "passed": passed }),
+            );
+        }
+        if passed {
+            return true;
+        }
+        plan.phases.push(RolloutPhase {
+            stage: RolloutStage::Rollback,
+            targets: canary.to_vec(),
+            outcome: PhaseOutcome::Passed,
+            findings: Vec::new(),
+        });
+        plan.findings.extend(findings);
+        plan.status = UpgradeStatus::RolledBack;
+        plan.reason = Some("canary health check failed".into());
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.event(
+                "self_upgrade.canary.rolled_back",
+                json!({ "directive": directive.id, "targets": canary }),
+            );
+        }
+        false
+    }
 }
 
 /// Builder for `SelfUpgradeRuntime`.
 pub struct SelfUpgradeRuntimeBuilder {
     telemetry: Option<UpgradeTelemetry>,
     report_dir: std::path::PathBuf,
+    current_version: Option<String>,
+    canary: Option<CanaryPolicy>,
+    health_probe: Option<Arc<dyn HealthProbe>>,
+    review_threshold: Option<f32>,
 }
 
 impl SelfUpgradeRuntimeBuilder {
@@ -76,17 +185,57 @@ impl SelfUpgradeRuntimeBuilder {
         self
     }
 
+    /// Sets the running version used to validate directive targets.
+    #[must_use]
+    pub fn current_version(mut self, version: impl Into<String>) -> Self {
+        self.current_version = Some(version.into());
+        self
+    }
+
+    /// Rolls upgrades out to a canary subset of the directive targets first.
+    #[must_use]
+    pub const fn canary(mut self, policy: CanaryPolicy) -> Self {
+        self.canary = Some(policy);
+        self
+    }
+
+    /// Sets the probe used to health-check upgraded targets.
+    #[must_use]
+    pub fn health_probe(mut self, probe: impl HealthProbe + 'static) -> Self {
+        self.health_probe = Some(Arc::new(probe));
+        self
+    }
+
+    /// Sets the maximum diagnostic severity the reviewer accepts before blocking a plan.
+    #[must_use]
+    pub const fn review_threshold(mut self, threshold: f32) -> Self {
+        self.review_threshold = Some(threshold);
+        self
+    }
+
     /// Builds runtime.
     pub fn build(self) -> Result<SelfUpgradeRuntime> {
         let telemetry = self.telemetry;
-        let checker = UpgradeChecker::new(telemetry.clone());
-        let reviewer = UpgradeReviewer::new(telemetry.clone());
-        let planner = UpgradePlanner::new(checker, reviewer, telemetry.clone());
+        let mut checker = UpgradeChecker::new(telemetry.clone());
+        if let Some(probe) = self.health_probe {
+            checker = checker.with_health_probe(probe);
+        }
+        let mut reviewer = UpgradeReviewer::new(telemetry.clone());
+        if let Some(threshold) = self.review_threshold {
+            reviewer = reviewer.with_threshold(threshold);
+        }
+        let mut planner = UpgradePlanner::new(checker, reviewer, telemetry.clone());
+        if let Some(version) = self.current_version {
+            planner = planner.with_current_version(version);
+        }
+        let lock_path = self.report_dir.join("upgrade.lock");
         let reporter = UpgradeReporter::new(self.report_dir, telemetry.clone());
         Ok(SelfUpgradeRuntime {
             telemetry,
             planner,
             reporter,
+            lock_path,
+            canary: self.canary,
         })
     }
 }
@@ -96,6 +245,10 @@ impl Default for SelfUpgradeRuntimeBuilder {
         Self {
             telemetry: None,
             report_dir: std::path::PathBuf::from("logs/self_upgrade"),
+            current_version: None,
+            canary: None,
+            health_probe: None,
+            review_threshold: None,
         }
     }
 }
@@ -107,6 +260,7 @@ mod tests {
     use shared_event_bus::MemoryEventBus;
     use std::sync::Arc;
     use tempfile::tempdir;
+    use uuid::Uuid;
 
     fn telemetry() -> UpgradeTelemetry {
         UpgradeTelemetryBuilder::new("self-upgrade")
@@ -121,11 +275,124 @@ mod tests {
         let runtime = SelfUpgradeRuntime::builder()
             .telemetry(telemetry())
             .report_dir(tempdir().unwrap().path())
+            .review_threshold(1.0)
+            .build()
+            .unwrap();
+        let plan = runtime
+            .execute(UpgradeDirective::new("upgrade", "v3", 90))
+            .unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Completed);
+    }
+
+    #[test]
+    fn blocked_version_jump_is_never_completed() {
+        let dir = tempdir().unwrap();
+        let runtime = SelfUpgradeRuntime::builder()
+            .report_dir(dir.path())
+            .current_version("1.0.0")
+            .review_threshold(1.0)
             .build()
             .unwrap();
         let plan = runtime
             .execute(UpgradeDirective::new("upgrade", "v3", 90))
             .unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Blocked);
+        assert!(plan.phases.is_empty());
+        assert!(!dir.path().join("upgrade.lock").exists());
+    }
+
+    #[test]
+    fn failed_canary_is_rolled_back_before_full_rollout() {
+        let runtime = SelfUpgradeRuntime::builder()
+            .report_dir(tempdir().unwrap().path())
+            .review_threshold(1.0)
+            .canary(CanaryPolicy::new(0.25))
+            .health_probe(|target: &str| if target == "node-1" { 0.9 } else { 0.0 })
+            .build()
+            .unwrap();
+        let directive = UpgradeDirective::new("upgrade", "v2", 80)
+            .with_targets(["node-1", "node-2", "node-3", "node-4"]);
+
+        let plan = runtime.execute(directive).unwrap();
+        assert_eq!(plan.status, UpgradeStatus::RolledBack);
+        let stages: Vec<_> = plan.phases.iter().map(|phase| phase.stage).collect();
+        assert_eq!(stages, [RolloutStage::Canary, RolloutStage::Rollback]);
+        assert_eq!(plan.phases[0].targets, ["node-1"]);
+        assert_eq!(plan.phases[0].outcome, PhaseOutcome::Failed);
+        assert_eq!(plan.phases[1].targets, ["node-1"]);
+    }
+
+    #[test]
+    fn blocked_plan_skips_canary_and_rollout() {
+        let runtime = SelfUpgradeRuntime::builder()
+            .report_dir(tempdir().unwrap().path())
+            .current_version("1.0.0")
+            .canary(CanaryPolicy::new(0.5))
+            .build()
+            .unwrap();
+        let directive =
+            UpgradeDirective::new("upgrade", "v3", 80).with_targets(["node-1", "node-2"]);
+
+        let plan = runtime.execute(directive).unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Blocked);
+        assert!(plan.phases.is_empty());
+    }
+
+    #[test]
+    fn healthy_canary_proceeds_to_full_rollout() {
+        let runtime = SelfUpgradeRuntime::builder()
+            .report_dir(tempdir().unwrap().path())
+            .review_threshold(1.0)
+            .canary(CanaryPolicy::new(0.5))
+            .build()
+            .unwrap();
+        let directive =
+            UpgradeDirective::new("upgrade", "v2", 80).with_targets(["node-1", "node-2", "node-3"]);
+
+        let plan = runtime.execute(directive).unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Completed);
+        assert_eq!(plan.phases.len(), 2);
+        assert_eq!(plan.phases[0].targets, ["node-1", "node-2"]);
+        assert_eq!(plan.phases[1].stage, RolloutStage::Full);
+        assert_eq!(plan.phases[1].targets, ["node-3"]);
+    }
+
+    #[test]
+    fn concurrent_execute_is_rejected() {
+        let dir = tempdir().unwrap();
+        let runtime = Arc::new(
+            SelfUpgradeRuntime::builder()
+                .report_dir(dir.path())
+                .review_threshold(1.0)
+                .build()
+                .unwrap(),
+        );
+        let lock_path = dir.path().join("upgrade.lock");
+        let acquired = Arc::new(std::sync::Barrier::new(2));
+        let rejected = Arc::new(std::sync::Barrier::new(2));
+        let holder = {
+            let (acquired, rejected) = (Arc::clone(&acquired), Arc::clone(&rejected));
+            let lock_path = lock_path.clone();
+            std::thread::spawn(move || {
+                let lock = UpgradeLock::try_acquire(&lock_path, Uuid::new_v4()).unwrap();
+                acquired.wait();
+                rejected.wait();
+                drop(lock);
+            })
+        };
+        acquired.wait();
+        let plan = runtime
+            .execute(UpgradeDirective::new("upgrade", "v2", 80))
+            .unwrap();
+        rejected.wait();
+        holder.join().unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Rejected);
+        assert_eq!(plan.reason.as_deref(), Some("upgrade in progress"));
+
+        let plan = runtime
+            .execute(UpgradeDirective::new("upgrade", "v2", 80))
+            .unwrap();
         assert_eq!(plan.status, UpgradeStatus::Completed);
+        assert!(!lock_path.exists());
     }
 }
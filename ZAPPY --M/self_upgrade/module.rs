@@ -14,6 +14,9 @@ pub struct UpgradeDirective {
     pub target: String,
     /// Priority 0-100.
     pub priority: u8,
+    /// Hosts or components the upgrade is rolled out to.
+    #[serde(default)]
+    pub targets: Vec<String>,
 }
 
 impl UpgradeDirective {
@@ -25,8 +28,20 @@ impl UpgradeDirective {
             description: description.into(),
             target: target.into(),
             priority,
+            targets: Vec::new(),
         }
     }
+
+    /// Sets the rollout targets.
+    #[must_use]
+    pub fn with_targets<I, S>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.targets = targets.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 /// Diagnostic finding produced by the checker.
@@ -53,6 +68,89 @@ pub enum UpgradeStatus {
     Completed,
     /// Blocked by reviewer.
     Blocked,
+    /// Rejected before planning (e.g. another upgrade in progress).
+    Rejected,
+    /// Canary failed its health checks; a rollback phase was recorded.
+    RolledBack,
+}
+
+/// Stage of a staged rollout.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloutStage {
+    /// Upgrade applied to a subset of targets and health checked.
+    Canary,
+    /// Upgrade applied to the remaining targets.
+    Full,
+    /// Records that the canary targets must be reverted after a failed health check.
+    ///
+    /// The runtime does not revert targets itself; callers act on this phase.
+    Rollback,
+}
+
+/// Result of a rollout phase.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PhaseOutcome {
+    /// Phase applied and (for canaries) passed health checks.
+    Passed,
+    /// Canary health checks failed.
+    Failed,
+}
+
+/// One staged phase of an upgrade rollout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolloutPhase {
+    /// Rollout stage.
+    pub stage: RolloutStage,
+    /// Targets touched in this phase.
+    pub targets: Vec<String>,
+    /// Phase result.
+    pub outcome: PhaseOutcome,
+    /// Health-check findings raised during the phase.
+    #[serde(default)]
+    pub findings: Vec<UpgradeFinding>,
+}
+
+/// Canary settings: how many targets go first and how much health-check severity is tolerated.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CanaryPolicy {
+    /// Fraction of targets (0-1] upgraded in the canary phase; at least one target is used.
+    pub fraction: f32,
+    /// Highest health-check severity that still lets the rollout proceed.
+    pub max_severity: f32,
+}
+
+impl CanaryPolicy {
+    /// Creates a policy upgrading `fraction` of targets first, tolerating severity up to 0.5.
+    #[must_use]
+    pub const fn new(fraction: f32) -> Self {
+        Self {
+            fraction,
+            max_severity: 0.5,
+        }
+    }
+
+    /// Overrides the tolerated health-check severity.
+    #[must_use]
+    pub const fn max_severity(mut self, severity: f32) -> Self {
+        self.max_severity = severity;
+        self
+    }
+
+    /// Number of canary targets out of `total`.
+    #[must_use]
+    pub fn canary_size(&self, total: usize) -> usize {
+        if total == 0 {
+            return 0;
+        }
+        let total_u16 = u16::try_from(total).unwrap_or(u16::MAX);
+        let size = (f32::from(total_u16) * self.fraction.clamp(0.0, 1.0)).ceil();
+        // `size` lies within 0..=total_u16, so the cast cannot truncate.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let size = size as usize;
+        size.clamp(1, total)
+    }
 }
 
 /// Action executed as part of an upgrade plan.
@@ -75,6 +173,15 @@ pub struct UpgradePlan {
     pub actions: Vec<UpgradeAction>,
     /// Current status.
     pub status: UpgradeStatus,
+    /// Findings that blocked or qualified the plan.
+    #[serde(default)]
+    pub findings: Vec<UpgradeFinding>,
+    /// Reason recorded when the plan was rejected or rolled back.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Staged rollout phases, in execution order.
+    #[serde(default)]
+    pub phases: Vec<RolloutPhase>,
     /// Generated timestamp.
     pub generated_at: DateTime<Utc>,
 }
@@ -87,6 +194,9 @@ impl UpgradePlan {
             directive_id,
             actions,
             status: UpgradeStatus::Pending,
+            findings: Vec::new(),
+            reason: None,
+            phases: Vec::new(),
             generated_at: Utc::now(),
         }
     }
@@ -22,6 +22,13 @@ impl UpgradeReviewer {
         }
     }
 
+    /// Sets the maximum finding severity a plan may carry and still be accepted.
+    #[must_use]
+    pub const fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
     /// Accepts or blocks a plan based on heuristics.
     pub fn review(&self, plan: &mut UpgradePlan, max_severity: f32) -> Result<bool> {
         let accepted = max_severity <= self.threshold;
@@ -2,9 +2,9 @@ use anyhow::Result;
 use serde_json::json;
 
 use crate::{
-    checker::UpgradeChecker,
+    checker::{version_constraints, UpgradeChecker},
     helpermethods::UpgradeTelemetry,
-    module::{UpgradeAction, UpgradeDirective, UpgradePlan},
+    module::{UpgradeAction, UpgradeDirective, UpgradePlan, UpgradeStatus},
     reviewer::UpgradeReviewer,
 };
 
@@ -13,6 +13,7 @@ pub struct UpgradePlanner {
     checker: UpgradeChecker,
     reviewer: UpgradeReviewer,
     telemetry: Option<UpgradeTelemetry>,
+    current_version: Option<String>,
 }
 
 impl UpgradePlanner {
@@ -27,9 +28,23 @@ impl UpgradePlanner {
             checker,
             reviewer,
             telemetry,
+            current_version: None,
         }
     }
 
+    /// Enables semver validation of directive targets against the running version.
+    #[must_use]
+    pub fn with_current_version(mut self, version: impl Into<String>) -> Self {
+        self.current_version = Some(version.into());
+        self
+    }
+
+    /// Checker used for diagnostics and post-upgrade health checks.
+    #[must_use]
+    pub const fn checker(&self) -> &UpgradeChecker {
+        &self.checker
+    }
+
     /// Creates plan from directive, returning accepted plan.
     pub fn plan(&self, directive: &UpgradeDirective) -> Result<UpgradePlan> {
         let findings = self.checker.run(directive)?;
@@ -47,8 +62,33 @@ impl UpgradePlanner {
             estimate_secs: 180,
         });
         let mut plan = UpgradePlan::new(directive.id, actions);
-        let max_severity = findings.iter().map(|f| f.severity).fold(0.0, f32::max);
-        let accepted = self.reviewer.review(&mut plan, max_severity)?;
+        let violations = self
+            .current_version
+            .as_deref()
+            .map(|current| version_constraints(current, &directive.target))
+            .unwrap_or_default();
+        let max_severity = findings
+            .iter()
+            .chain(&violations)
+            .map(|f| f.severity)
+            .fold(0.0, f32::max);
+        let mut accepted = self.reviewer.review(&mut plan, max_severity)?;
+        if !violations.is_empty() {
+            accepted = false;
+            plan.status = UpgradeStatus::Blocked;
+            if let Some(tel) = &self.telemetry {
+                let _ = tel.event(
+                    "self_upgrade.plan.version_blocked",
+                    json!({
+                        "directive": directive.id,
+                        "current": self.current_version,
+                        "target": directive.target
+                    }),
+                );
+            }
+        }
+        plan.findings = findings;
+        plan.findings.extend(violations);
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 shared_logging::LogLevel::Info,
@@ -88,4 +128,19 @@ mod tests {
         let plan = planner.plan(&directive).unwrap();
         assert!(!plan.actions.is_empty());
     }
+
+    #[test]
+    fn planner_blocks_major_version_jump() {
+        let planner =
+            UpgradePlanner::new(UpgradeChecker::new(None), UpgradeReviewer::new(None), None)
+                .with_current_version("1.2.0");
+        let plan = planner
+            .plan(&UpgradeDirective::new("upgrade", "v3", 90))
+            .unwrap();
+        assert_eq!(plan.status, UpgradeStatus::Blocked);
+        assert!(plan
+            .findings
+            .iter()
+            .any(|finding| finding.message.contains("skips a breaking release")));
+    }
 }
@@ -30,6 +30,10 @@ pub mod reviewer;
 #[path = "../main.rs"]
 pub mod runtime;
 
+pub use checker::HealthProbe;
 pub use helpermethods::{UpgradeTelemetry, UpgradeTelemetryBuilder};
-pub use module::{UpgradeAction, UpgradeDirective, UpgradeFinding, UpgradePlan, UpgradeStatus};
+pub use module::{
+    CanaryPolicy, PhaseOutcome, RolloutPhase, RolloutStage, UpgradeAction, UpgradeDirective,
+    UpgradeFinding, UpgradePlan, UpgradeStatus,
+};
 pub use runtime::{SelfUpgradeRuntime, SelfUpgradeRuntimeBuilder};
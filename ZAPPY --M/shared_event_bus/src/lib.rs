@@ -13,9 +13,15 @@ use async_trait::async_trait;
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::broadcast};
+use tokio::{
+    fs::OpenOptions,
+    io::AsyncWriteExt,
+    sync::broadcast::{self, error::RecvError},
+};
 
 /// Generic event record encoded as JSON.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +37,78 @@ pub struct EventRecord {
     /// Arbitrary JSON payload.
     #[serde(default)]
     pub payload: serde_json::Value,
+    /// Monotonic sequence assigned by [`MemoryEventBus`] on publish (0 when unassigned).
+    #[serde(default)]
+    pub sequence: u64,
+}
+
+impl EventRecord {
+    /// Creates a record with a fresh `evt-` id and the current timestamp.
+    #[must_use]
+    pub fn new(
+        source: impl Into<String>,
+        event_type: impl Into<String>,
+        payload: serde_json::Value,
+    ) -> Self {
+        Self {
+            id: format!("evt-{}", uuid::Uuid::new_v4()),
+            source: source.into(),
+            event_type: event_type.into(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            payload,
+            sequence: 0,
+        }
+    }
+}
+
+/// Names the `event_type` a strongly-typed payload is published under.
+pub trait EventType {
+    /// Event type string (e.g., `training.progress`).
+    const EVENT_TYPE: &'static str;
+}
+
+/// Conversion of a typed payload into an [`EventRecord`].
+pub trait IntoEvent {
+    /// Serializes `self` as the payload of a record emitted by `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized to JSON.
+    fn into_event(self, source: &str) -> Result<EventRecord>;
+}
+
+impl<T: Serialize + EventType> IntoEvent for T {
+    fn into_event(self, source: &str) -> Result<EventRecord> {
+        Ok(EventRecord::new(
+            source,
+            T::EVENT_TYPE,
+            serde_json::to_value(self)?,
+        ))
+    }
+}
+
+/// Extension for publishing typed payloads without hand-building records.
+#[async_trait]
+pub trait TypedEventPublisher {
+    /// Wraps `payload` in an [`EventRecord`] and publishes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized to JSON or the underlying
+    /// publisher rejects the record.
+    async fn publish_typed<T>(&self, source: &str, payload: T) -> Result<()>
+    where
+        T: Serialize + EventType + Send;
+}
+
+#[async_trait]
+impl<P: EventPublisher + ?Sized> TypedEventPublisher for P {
+    async fn publish_typed<T>(&self, source: &str, payload: T) -> Result<()>
+    where
+        T: Serialize + EventType + Send,
+    {
+        self.publish(payload.into_event(source)?).await
+    }
 }
 
 /// Event publisher interface.
@@ -52,6 +130,7 @@ pub trait EventSubscriber: Send + Sync {
 pub struct MemoryEventBus {
     sender: broadcast::Sender<EventRecord>,
     backlog: Arc<Mutex<VecDeque<EventRecord>>>,
+    sequence: Arc<Mutex<u64>>,
 }
 
 impl MemoryEventBus {
@@ -62,6 +141,17 @@ impl MemoryEventBus {
         Self {
             sender,
             backlog: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            sequence: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// Subscribes with gap detection based on event sequence numbers.
+    #[must_use]
+    pub fn subscribe_sequenced(&self) -> SequencedReceiver {
+        let sequence = self.sequence.lock();
+        SequencedReceiver {
+            inner: self.sender.subscribe(),
+            expected: *sequence + 1,
         }
     }
 
@@ -72,10 +162,51 @@ impl MemoryEventBus {
     }
 }
 
+/// Event delivered by a [`SequencedReceiver`] with the number of sequences missed before it.
+#[derive(Debug, Clone)]
+pub struct SequencedEvent {
+    /// The delivered event.
+    pub event: EventRecord,
+    /// Sequences skipped since the previous delivery (dropped for lagging).
+    pub missed: u64,
+}
+
+/// Receiver wrapper that surfaces dropped events instead of hiding them.
+#[derive(Debug)]
+pub struct SequencedReceiver {
+    inner: broadcast::Receiver<EventRecord>,
+    expected: u64,
+}
+
+impl SequencedReceiver {
+    /// Receives the next event, reporting how many sequences were skipped before it.
+    ///
+    /// Lagging is folded into [`SequencedEvent::missed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecvError::Closed`] once every sender has been dropped; lagging is
+    /// never returned as an error.
+    pub async fn recv(&mut self) -> Result<SequencedEvent, RecvError> {
+        loop {
+            match self.inner.recv().await {
+                Ok(event) => {
+                    let missed = event.sequence.saturating_sub(self.expected);
+                    self.expected = event.sequence + 1;
+                    return Ok(SequencedEvent { event, missed });
+                }
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return Err(RecvError::Closed),
+            }
+        }
+    }
+}
+
 /// File-backed publisher useful for durable event logs.
 #[derive(Debug, Clone)]
 pub struct FileEventPublisher {
     path: PathBuf,
+    dead_letter: Option<PathBuf>,
 }
 
 impl FileEventPublisher {
@@ -85,13 +216,130 @@ impl FileEventPublisher {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        Ok(Self { path })
+        Ok(Self {
+            path,
+            dead_letter: None,
+        })
+    }
+
+    /// Preserves events that fail to publish by appending them to `path` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead-letter directory cannot be created.
+    pub fn with_dead_letter(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        self.dead_letter = Some(path);
+        Ok(self)
+    }
+}
+
+/// Reads a [`FileEventPublisher`] log with at-least-once delivery.
+///
+/// The byte offset of the last committed event is persisted to a sidecar file, so a
+/// restarted consumer resumes after it; events polled but not committed are delivered
+/// again.
+#[derive(Debug, Clone)]
+pub struct FileEventConsumer {
+    log: PathBuf,
+    offset_path: PathBuf,
+    position: u64,
+    committed: u64,
+}
+
+impl FileEventConsumer {
+    /// Opens a consumer of `log`, resuming from the offset stored in `offset_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset file exists but cannot be read or does not
+    /// contain a valid offset.
+    pub fn open(log: impl AsRef<Path>, offset_path: impl AsRef<Path>) -> Result<Self> {
+        let offset_path = offset_path.as_ref().to_path_buf();
+        let committed = match std::fs::read_to_string(&offset_path) {
+            Ok(raw) => raw.trim().parse()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            log: log.as_ref().to_path_buf(),
+            offset_path,
+            position: committed,
+            committed,
+        })
+    }
+
+    /// Returns the next event after the last polled one, if fully written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be opened or read, or if the next complete
+    /// line is not a valid [`EventRecord`].
+    pub fn poll(&mut self) -> Result<Option<EventRecord>> {
+        let mut reader = match File::open(&self.log) {
+            Ok(file) => BufReader::new(file),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        reader.seek(SeekFrom::Start(self.position))?;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 || !line.ends_with('\n') {
+                return Ok(None);
+            }
+            self.position += read as u64;
+            if !line.trim().is_empty() {
+                return Ok(Some(serde_json::from_str(&line)?));
+            }
+        }
+    }
+
+    /// Acknowledges every polled event, persisting the offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset directory cannot be created or the offset file
+    /// cannot be written and moved into place.
+    pub fn commit(&mut self) -> Result<()> {
+        if let Some(parent) = self.offset_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let staging = self.offset_path.with_extension("tmp");
+        std::fs::write(&staging, self.position.to_string())?;
+        std::fs::rename(&staging, &self.offset_path)?;
+        self.committed = self.position;
+        Ok(())
+    }
+
+    /// Byte offset of the last committed event.
+    #[must_use]
+    pub const fn committed_offset(&self) -> u64 {
+        self.committed
     }
 }
 
+async fn append_line(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(data).await?;
+    file.write_all(b"\n").await?;
+    file.flush().await
+}
+
 #[async_trait]
 impl EventPublisher for MemoryEventBus {
-    async fn publish(&self, event: EventRecord) -> Result<()> {
+    async fn publish(&self, mut event: EventRecord) -> Result<()> {
+        let mut sequence = self.sequence.lock();
+        *sequence += 1;
+        event.sequence = *sequence;
         {
             let mut backlog = self.backlog.lock();
             backlog.push_back(event.clone());
@@ -99,7 +347,9 @@ impl EventPublisher for MemoryEventBus {
                 backlog.pop_front();
             }
         }
+        // Sending under the sequence lock keeps delivery order equal to sequence order.
         let _ = self.sender.send(event);
+        drop(sequence);
         Ok(())
     }
 }
@@ -114,15 +364,13 @@ impl EventSubscriber for MemoryEventBus {
 #[async_trait]
 impl EventPublisher for FileEventPublisher {
     async fn publish(&self, event: EventRecord) -> Result<()> {
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.path)
-            .await?;
         let data = serde_json::to_vec(&event)?;
-        file.write_all(&data).await?;
-        file.write_all(b"\n").await?;
-        file.flush().await?;
+        if let Err(err) = append_line(&self.path, &data).await {
+            if let Some(dead_letter) = &self.dead_letter {
+                let _ = append_line(dead_letter, &data).await;
+            }
+            return Err(err.into());
+        }
         Ok(())
     }
 }
@@ -140,6 +388,7 @@ mod tests {
             event_type: "unit.test".into(),
             timestamp: "2025-11-20T00:00:00Z".into(),
             payload: serde_json::json!({"value": 1}),
+            sequence: 0,
         }
     }
 
@@ -152,6 +401,61 @@ mod tests {
             bus.publish(sample_event()).await.unwrap();
             let event = rx.recv().await.unwrap();
             assert_eq!(event.event_type, "unit.test");
+            assert_eq!(event.sequence, 1);
+        });
+    }
+
+    #[derive(Serialize)]
+    struct JobFinished {
+        job_id: String,
+        loss: f64,
+    }
+
+    impl EventType for JobFinished {
+        const EVENT_TYPE: &'static str = "training.job.finished";
+    }
+
+    #[test]
+    fn typed_payloads_become_event_records() {
+        let bus = MemoryEventBus::new(4);
+        Runtime::new()
+            .unwrap()
+            .block_on(bus.publish_typed(
+                "trainer",
+                JobFinished {
+                    job_id: "job-7".into(),
+                    loss: 0.25,
+                },
+            ))
+            .unwrap();
+
+        let event = bus.snapshot().pop().unwrap();
+        assert_eq!(event.source, "trainer");
+        assert_eq!(event.event_type, "training.job.finished");
+        assert_eq!(
+            event.payload,
+            serde_json::json!({ "job_id": "job-7", "loss": 0.25 })
+        );
+        assert!(event.id.starts_with("evt-"));
+        assert!(chrono::DateTime::parse_from_rfc3339(&event.timestamp).is_ok());
+        assert_eq!(event.sequence, 1);
+    }
+
+    #[test]
+    fn lagging_subscriber_sees_sequence_gap() {
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async {
+            let bus = MemoryEventBus::new(2);
+            let mut rx = bus.subscribe_sequenced();
+            for _ in 0..5 {
+                bus.publish(sample_event()).await.unwrap();
+            }
+            let first = rx.recv().await.unwrap();
+            assert_eq!(first.event.sequence, 4);
+            assert_eq!(first.missed, 3);
+            let second = rx.recv().await.unwrap();
+            assert_eq!(second.event.sequence, 5);
+            assert_eq!(second.missed, 0);
         });
     }
 
@@ -167,4 +471,52 @@ mod tests {
             assert!(content.contains("unit.test"));
         });
     }
+
+    #[test]
+    fn consumer_resumes_after_last_commit() {
+        let rt = Runtime::new().unwrap();
+        let dir = tempdir().unwrap();
+        let log = dir.path().join("events.log");
+        let offsets = dir.path().join("events.offset");
+        let publisher = FileEventPublisher::new(&log).unwrap();
+        rt.block_on(async {
+            for idx in 0..4 {
+                let mut event = sample_event();
+                event.id = format!("event-{idx}");
+                publisher.publish(event).await.unwrap();
+            }
+        });
+
+        let mut consumer = FileEventConsumer::open(&log, &offsets).unwrap();
+        assert_eq!(consumer.poll().unwrap().unwrap().id, "event-0");
+        assert_eq!(consumer.poll().unwrap().unwrap().id, "event-1");
+        consumer.commit().unwrap();
+        assert_eq!(consumer.poll().unwrap().unwrap().id, "event-2");
+        drop(consumer);
+
+        let mut restarted = FileEventConsumer::open(&log, &offsets).unwrap();
+        assert!(restarted.committed_offset() > 0);
+        let ids = std::iter::from_fn(|| restarted.poll().unwrap())
+            .map(|event| event.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["event-2", "event-3"]);
+    }
+
+    #[test]
+    fn failed_writes_land_in_dead_letter() {
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async {
+            let dir = tempdir().unwrap();
+            let dead_letter = dir.path().join("dead/events.log");
+            let publisher = FileEventPublisher::new(dir.path().join("events.log"))
+                .unwrap()
+                .with_dead_letter(&dead_letter)
+                .unwrap();
+            std::fs::create_dir(dir.path().join("events.log")).unwrap();
+            assert!(publisher.publish(sample_event()).await.is_err());
+            let content = std::fs::read_to_string(dead_letter).unwrap();
+            let preserved: EventRecord = serde_json::from_str(content.trim()).unwrap();
+            assert_eq!(preserved.id, "event-1");
+        });
+    }
 }
@@ -12,15 +12,17 @@ use std::{
     fs::{self, File},
     io::Write,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
 };
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
-/// Log severity level.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+/// Log severity level, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum LogLevel {
     /// Debug information.
@@ -33,6 +35,30 @@ pub enum LogLevel {
     Error,
 }
 
+impl LogLevel {
+    /// OpenTelemetry severity number (the lowest value of the matching severity range).
+    #[must_use]
+    pub const fn severity_number(self) -> u8 {
+        match self {
+            Self::Debug => 5,
+            Self::Info => 9,
+            Self::Warn => 13,
+            Self::Error => 17,
+        }
+    }
+
+    /// Upper-case label, matching the serialized form.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
 /// Structured log record.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogRecord {
@@ -61,6 +87,74 @@ impl LogRecord {
             metadata: serde_json::Map::new(),
         }
     }
+
+    /// Attaches a metadata field, replacing any previous value under `key`.
+    #[must_use]
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Maps the record onto the OpenTelemetry log data model (OTLP/JSON encoding).
+    ///
+    /// The emitting module becomes the `module` attribute, followed by every metadata
+    /// field; nested objects and arrays map to `kvlistValue` / `arrayValue`.
+    #[must_use]
+    pub fn to_otel(&self) -> Value {
+        let time = self
+            .timestamp
+            .timestamp_nanos_opt()
+            .unwrap_or_default()
+            .to_string();
+        let mut attributes = vec![otel_attribute("module", &Value::from(self.module.as_str()))];
+        attributes.extend(
+            self.metadata
+                .iter()
+                .map(|(key, value)| otel_attribute(key, value)),
+        );
+        json!({
+            "timeUnixNano": time,
+            "observedTimeUnixNano": time,
+            "severityNumber": self.level.severity_number(),
+            "severityText": self.level.as_str(),
+            "body": { "stringValue": self.message },
+            "attributes": attributes,
+        })
+    }
+}
+
+fn otel_attribute(key: &str, value: &Value) -> Value {
+    json!({ "key": key, "value": otel_any_value(value) })
+}
+
+fn otel_any_value(value: &Value) -> Value {
+    match value {
+        Value::Null => json!({}),
+        Value::Bool(flag) => json!({ "boolValue": flag }),
+        Value::Number(number) => number.as_i64().map_or_else(
+            || json!({ "doubleValue": number.as_f64() }),
+            |int| json!({ "intValue": int.to_string() }),
+        ),
+        Value::String(text) => json!({ "stringValue": text }),
+        Value::Array(items) => json!({
+            "arrayValue": { "values": items.iter().map(otel_any_value).collect::<Vec<_>>() }
+        }),
+        Value::Object(fields) => json!({
+            "kvlistValue": {
+                "values": fields
+                    .iter()
+                    .map(|(key, value)| otel_attribute(key, value))
+                    .collect::<Vec<_>>()
+            }
+        }),
+    }
+}
+
+/// Sampling applied to low-severity records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sampling {
+    level: LogLevel,
+    one_in: u64,
 }
 
 /// Thread-safe JSON logger with append-only semantics.
@@ -68,6 +162,11 @@ impl LogRecord {
 pub struct JsonLogger {
     path: PathBuf,
     writer: Mutex<File>,
+    sampling: Option<Sampling>,
+    sampled: AtomicU64,
+    durable: bool,
+    #[cfg(test)]
+    synced: AtomicU64,
 }
 
 impl JsonLogger {
@@ -84,18 +183,66 @@ impl JsonLogger {
         Ok(Self {
             path,
             writer: Mutex::new(file),
+            sampling: None,
+            sampled: AtomicU64::new(0),
+            durable: false,
+            #[cfg(test)]
+            synced: AtomicU64::new(0),
         })
     }
 
-    /// Writes a log record as JSON line.
+    /// Writes only one in `one_in` records at or below `level`; more severe records
+    /// are always written. The first eligible record is kept.
+    #[must_use]
+    pub fn with_sampling(mut self, level: LogLevel, one_in: u64) -> Self {
+        self.sampling = Some(Sampling {
+            level,
+            one_in: one_in.max(1),
+        });
+        self
+    }
+
+    /// Syncs every written record to disk before [`Self::log`] returns.
+    ///
+    /// Meant for durability-critical logs such as audit trails; each record then
+    /// costs an `fsync`.
+    #[must_use]
+    pub const fn with_durability(mut self, durable: bool) -> Self {
+        self.durable = durable;
+        self
+    }
+
+    /// Writes a log record as JSON line (unless dropped by sampling).
     pub fn log(&self, record: &LogRecord) -> Result<()> {
+        if let Some(sampling) = self.sampling {
+            if record.level <= sampling.level
+                && !self
+                    .sampled
+                    .fetch_add(1, Ordering::Relaxed)
+                    .is_multiple_of(sampling.one_in)
+            {
+                return Ok(());
+            }
+        }
         let mut writer = self.writer.lock();
         serde_json::to_writer(&mut *writer, record)?;
         writer.write_all(b"\n")?;
         writer.flush()?;
+        if self.durable {
+            writer.sync_data()?;
+            #[cfg(test)]
+            self.synced.fetch_add(1, Ordering::Relaxed);
+        }
+        drop(writer);
         Ok(())
     }
 
+    /// Number of records synced to disk by a durable logger.
+    #[cfg(test)]
+    fn synced_records(&self) -> u64 {
+        self.synced.load(Ordering::Relaxed)
+    }
+
     /// Returns the underlying file path (useful for tests).
     #[must_use]
     pub fn path(&self) -> &Path {
@@ -118,4 +265,118 @@ mod tests {
         let content = fs::read_to_string(logger.path()).unwrap();
         assert!(content.contains("\"message\":\"hello\""));
     }
+
+    #[test]
+    fn only_durable_loggers_sync_records() {
+        let dir = tempdir().unwrap();
+        let durable = JsonLogger::new(dir.path().join("audit.log"))
+            .unwrap()
+            .with_durability(true);
+        let relaxed = JsonLogger::new(dir.path().join("debug.log")).unwrap();
+        for idx in 0..3 {
+            let record = LogRecord::new("audit", LogLevel::Info, format!("entry {idx}"));
+            durable.log(&record).unwrap();
+            relaxed.log(&record).unwrap();
+        }
+        assert_eq!(durable.synced_records(), 3);
+        assert_eq!(relaxed.synced_records(), 0);
+        let content = fs::read_to_string(durable.path()).unwrap();
+        assert_eq!(content.lines().count(), 3);
+    }
+
+    #[test]
+    fn durable_records_survive_dropping_the_logger_unflushed() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("audit.log");
+        let durable = JsonLogger::new(&path).unwrap().with_durability(true);
+        for idx in 0..5 {
+            durable
+                .log(&LogRecord::new(
+                    "audit",
+                    LogLevel::Info,
+                    format!("entry {idx}"),
+                ))
+                .unwrap();
+        }
+        drop(durable);
+
+        let reopened = JsonLogger::new(&path).unwrap();
+        let messages: Vec<String> = fs::read_to_string(reopened.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<LogRecord>(line).unwrap().message)
+            .collect();
+        assert_eq!(
+            messages,
+            (0..5).map(|idx| format!("entry {idx}")).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn chained_fields_are_serialized() {
+        let record = LogRecord::new("module", LogLevel::Warn, "slow")
+            .field("latency_ms", 420)
+            .field("region", "eu-west")
+            .field("retried", true);
+        let encoded = serde_json::to_value(&record).unwrap();
+        assert_eq!(encoded["metadata"]["latency_ms"], 420);
+        assert_eq!(encoded["metadata"]["region"], "eu-west");
+        assert_eq!(encoded["metadata"]["retried"], true);
+    }
+
+    #[test]
+    fn otel_mapping_uses_severity_numbers_and_typed_attributes() {
+        let record = LogRecord::new("planner", LogLevel::Warn, "slow")
+            .field("latency_ms", 420)
+            .field("ratio", 0.5)
+            .field("tags", serde_json::json!(["a"]));
+        let otel = record.to_otel();
+
+        assert_eq!(otel["severityNumber"], 13);
+        assert_eq!(otel["severityText"], "WARN");
+        assert_eq!(otel["body"]["stringValue"], "slow");
+        assert_eq!(
+            otel["timeUnixNano"],
+            record.timestamp.timestamp_nanos_opt().unwrap().to_string()
+        );
+        assert_eq!(
+            otel["attributes"],
+            serde_json::json!([
+                { "key": "module", "value": { "stringValue": "planner" } },
+                { "key": "latency_ms", "value": { "intValue": "420" } },
+                { "key": "ratio", "value": { "doubleValue": 0.5 } },
+                {
+                    "key": "tags",
+                    "value": { "arrayValue": { "values": [{ "stringValue": "a" }] } }
+                },
+            ])
+        );
+        assert_eq!(LogLevel::Error.severity_number(), 17);
+    }
+
+    #[test]
+    fn sampling_thins_debug_but_keeps_errors() {
+        let dir = tempdir().unwrap();
+        let logger = JsonLogger::new(dir.path().join("sampled.log"))
+            .unwrap()
+            .with_sampling(LogLevel::Debug, 100);
+        for idx in 0..1000 {
+            logger
+                .log(&LogRecord::new(
+                    "loop",
+                    LogLevel::Debug,
+                    format!("tick {idx}"),
+                ))
+                .unwrap();
+        }
+        for _ in 0..5 {
+            logger
+                .log(&LogRecord::new("loop", LogLevel::Error, "boom"))
+                .unwrap();
+        }
+        let content = fs::read_to_string(logger.path()).unwrap();
+        let debug = content.matches("\"DEBUG\"").count();
+        assert!((9..=11).contains(&debug), "{debug}");
+        assert_eq!(content.matches("\"ERROR\"").count(), 5);
+    }
 }
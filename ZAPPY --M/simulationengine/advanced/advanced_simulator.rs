@@ -1,7 +1,11 @@
 use anyhow::Result;
 use serde_json::json;
 
-use crate::{helper::SimulationTelemetry, methods::SimulationMethod, simulator::Simulator};
+use crate::{
+    helper::SimulationTelemetry,
+    methods::SimulationMethod,
+    simulator::{SimulationBatch, Simulator},
+};
 
 use super::{report::SimulationReportBuilder, thinking::ScenarioThinker};
 
@@ -27,18 +31,48 @@ impl AdvancedSimulator {
         }
     }
 
+    /// Registers a custom generator on the underlying simulator.
+    pub fn register_generator(
+        &mut self,
+        name: impl Into<String>,
+        generator: std::sync::Arc<dyn crate::simul_env_generator::ScenarioGenerator>,
+    ) {
+        self.simulator.register_generator(name, generator);
+    }
+
     /// Runs simulation with thinking/reporting pipeline.
     pub async fn run(
         &self,
         method: SimulationMethod,
         count: usize,
     ) -> Result<super::report::SimulationReport> {
-        let batch = self.simulator.run(method, count).await?;
-        let insights = self.thinker.analyze(&batch)?;
+        let batch = self.simulator.run(method.clone(), count).await?;
+        self.report(&batch)
+    }
+
+    /// Runs the thinking/reporting pipeline over caller-supplied scenarios.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if executing, reviewing, or analyzing the scenarios fails.
+    pub async fn run_scenarios(
+        &self,
+        method: SimulationMethod,
+        scenarios: Vec<crate::simul_env_generator::SimulationScenario>,
+    ) -> Result<super::report::SimulationReport> {
+        let batch = self.simulator.run_scenarios(method, scenarios).await?;
+        self.report(&batch)
+    }
+
+    fn report(&self, batch: &SimulationBatch) -> Result<super::report::SimulationReport> {
+        let method = batch.method.clone();
+        let count = batch.scenarios.len();
+        let insights = self.thinker.analyze(batch)?;
         let report = SimulationReportBuilder::new()
-            .method(method)
-            .batch(&batch)
+            .method(method.clone())
+            .batch(batch)
             .insights(insights)
+            .reviewer(self.simulator.reviewer())
             .build();
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
@@ -8,5 +8,5 @@ pub mod report;
 pub mod thinking;
 
 pub use advanced_simulator::AdvancedSimulator;
-pub use report::{SimulationReport, SimulationReportBuilder};
+pub use report::{ScenarioOutcome, SimulationReport, SimulationReportBuilder};
 pub use thinking::{ScenarioInsight, ScenarioThinker};
@@ -1,7 +1,16 @@
+use std::io::{self, Write};
+
 use chrono::{DateTime, Utc};
+use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::{methods::SimulationMethod, simulator::SimulationBatch};
+use crate::{
+    compare::compare,
+    methods::SimulationMethod,
+    reviewer::{ScenarioReview, SimulationReviewer},
+    simulator::SimulationBatch,
+};
 
 use super::thinking::ScenarioInsight;
 
@@ -14,15 +23,77 @@ pub struct SimulationReport {
     pub scenario_count: usize,
     /// Generated insights.
     pub insights: Vec<ScenarioInsight>,
+    /// Per-scenario outcomes.
+    #[serde(default)]
+    pub scenarios: Vec<ScenarioOutcome>,
     /// Timestamp.
     pub generated_at: DateTime<Utc>,
 }
 
+/// Outcome recorded for a single scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioOutcome {
+    /// Scenario id.
+    pub scenario_id: Uuid,
+    /// Scenario label.
+    pub label: String,
+    /// Observed metrics.
+    pub metrics: IndexMap<String, f32>,
+    /// Mean absolute prediction error.
+    pub mae: f32,
+    /// Insight finding for the scenario.
+    pub finding: String,
+    /// Reviewer classification and remediations, when a reviewer was attached.
+    #[serde(default)]
+    pub review: Option<ScenarioReview>,
+}
+
+impl SimulationReport {
+    /// Writes one CSV row per scenario, preceded by a header row.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error raised by `writer`.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let columns = self
+            .scenarios
+            .iter()
+            .flat_map(|outcome| outcome.metrics.keys().cloned())
+            .collect::<IndexSet<_>>();
+        let mut header = vec!["scenario_id".to_string(), "label".to_string()];
+        header.extend(columns.iter().map(|column| csv_field(column)));
+        header.extend(["mae".to_string(), "finding".to_string()]);
+        writeln!(writer, "{}", header.join(","))?;
+        for outcome in &self.scenarios {
+            let mut row = vec![outcome.scenario_id.to_string(), csv_field(&outcome.label)];
+            row.extend(columns.iter().map(|column| {
+                outcome
+                    .metrics
+                    .get(column)
+                    .map_or_else(String::new, |value| format!("{value:.6}"))
+            }));
+            row.push(format!("{:.6}", outcome.mae));
+            row.push(csv_field(&outcome.finding));
+            writeln!(writer, "{}", row.join(","))?;
+        }
+        writer.flush()
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 /// Builder for `SimulationReport`.
 pub struct SimulationReportBuilder<'a> {
     method: SimulationMethod,
     batch: Option<&'a SimulationBatch>,
     insights: Vec<ScenarioInsight>,
+    reviewer: Option<&'a SimulationReviewer>,
 }
 
 impl<'a> SimulationReportBuilder<'a> {
@@ -33,6 +104,7 @@ impl<'a> SimulationReportBuilder<'a> {
             method: SimulationMethod::Approximate,
             batch: None,
             insights: Vec::new(),
+            reviewer: None,
         }
     }
 
@@ -57,13 +129,59 @@ impl<'a> SimulationReportBuilder<'a> {
         self
     }
 
+    /// Classifies each scenario with `reviewer`.
+    #[must_use]
+    pub const fn reviewer(mut self, reviewer: &'a SimulationReviewer) -> Self {
+        self.reviewer = Some(reviewer);
+        self
+    }
+
     /// Builds report.
     pub fn build(self) -> SimulationReport {
         let scenario_count = self.batch.map(|b| b.scenarios.len()).unwrap_or(0);
+        let scenarios = self
+            .batch
+            .map(|batch| {
+                let comparisons = compare(&batch.predictions, &batch.observations);
+                let reviews = self
+                    .reviewer
+                    .map(|reviewer| reviewer.classify(&comparisons))
+                    .unwrap_or_default();
+                batch
+                    .scenarios
+                    .iter()
+                    .map(|scenario| ScenarioOutcome {
+                        scenario_id: scenario.id,
+                        label: scenario.label.clone(),
+                        metrics: batch
+                            .observations
+                            .iter()
+                            .find(|obs| obs.scenario_id == scenario.id)
+                            .map(|obs| obs.observed_metrics.clone())
+                            .unwrap_or_default(),
+                        mae: comparisons
+                            .iter()
+                            .find(|res| res.scenario_id == scenario.id)
+                            .map_or(0.0, |res| res.mae),
+                        finding: self
+                            .insights
+                            .iter()
+                            .find(|insight| insight.label == scenario.label)
+                            .map(|insight| insight.finding.clone())
+                            .unwrap_or_default(),
+                        review: reviews
+                            .iter()
+                            .find(|review| review.scenario_id == scenario.id)
+                            .cloned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
         SimulationReport {
             method: self.method,
             scenario_count,
             insights: self.insights,
+            scenarios,
             generated_at: Utc::now(),
         }
     }
@@ -140,6 +140,7 @@ impl SimulationTelemetry {
                 event_type: event_type.into(),
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 payload,
+                sequence: 0,
             })?;
         }
         Ok(())
@@ -1,12 +1,17 @@
+use std::sync::Arc;
+
 use anyhow::Result;
+use indexmap::IndexMap;
+use uuid::Uuid;
 
 use crate::{
     advanced::{AdvancedSimulator, ScenarioThinker, SimulationReport},
     helper::SimulationTelemetry,
     methods::SimulationMethod,
+    montecarlo::MonteCarloSummary,
     predictor::ScenarioPredictor,
     reviewer::SimulationReviewer,
-    simul_env_generator::EnvironmentGenerator,
+    simul_env_generator::{EnvironmentGenerator, ScenarioGenerator, SimulationScenario},
     simulator::{SimulationBatch, Simulator},
 };
 
@@ -33,6 +38,42 @@ impl SimulationEngine {
         self.simulator.run(method, count).await
     }
 
+    /// Regenerates a single scenario from a [`Self::run_batch`] result by its id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no scenario with `scenario_id` was generated, as for
+    /// [`Simulator::replay`].
+    pub fn replay(&self, scenario_id: Uuid) -> Result<SimulationScenario> {
+        self.simulator.replay(scenario_id)
+    }
+
+    /// Runs a batch and summarizes per-metric mean, std dev, and 95% confidence intervals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be generated or executed, as for
+    /// [`Self::run_batch`].
+    pub async fn run_monte_carlo(
+        &self,
+        method: SimulationMethod,
+        count: usize,
+    ) -> Result<MonteCarloSummary> {
+        let batch = self.simulator.run(method.clone(), count).await?;
+        let summary = MonteCarloSummary::from_observations(method.clone(), &batch.observations);
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.event(
+                "simulation.monte_carlo.completed",
+                serde_json::json!({
+                    "method": method.label(),
+                    "count": count,
+                    "metrics": summary.metrics.len()
+                }),
+            );
+        }
+        Ok(summary)
+    }
+
     /// Runs advanced pipeline and returns final report.
     pub async fn run_advanced(
         &self,
@@ -42,6 +83,19 @@ impl SimulationEngine {
         self.advanced.run(method, count).await
     }
 
+    /// Runs the advanced pipeline over caller-supplied scenarios.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if executing, reviewing, or reporting on the scenarios fails.
+    pub async fn run_scenarios(
+        &self,
+        method: SimulationMethod,
+        scenarios: Vec<SimulationScenario>,
+    ) -> Result<SimulationReport> {
+        self.advanced.run_scenarios(method, scenarios).await
+    }
+
     /// Returns telemetry handle.
     #[must_use]
     pub fn telemetry(&self) -> Option<&SimulationTelemetry> {
@@ -54,6 +108,7 @@ pub struct SimulationEngineBuilder {
     telemetry: Option<SimulationTelemetry>,
     env_seed: u64,
     predictor_noise: f32,
+    generators: IndexMap<String, Arc<dyn ScenarioGenerator>>,
 }
 
 impl SimulationEngineBuilder {
@@ -78,14 +133,25 @@ impl SimulationEngineBuilder {
         self
     }
 
+    /// Registers a generator used for `SimulationMethod::Custom(name)`.
+    #[must_use]
+    pub fn custom_generator(
+        mut self,
+        name: impl Into<String>,
+        generator: impl ScenarioGenerator + 'static,
+    ) -> Self {
+        self.generators.insert(name.into(), Arc::new(generator));
+        self
+    }
+
     /// Builds the engine.
     pub fn build(self) -> Result<SimulationEngine> {
         let telemetry = self.telemetry;
         let generator = EnvironmentGenerator::new(self.env_seed);
         let predictor = ScenarioPredictor::new(self.predictor_noise);
         let reviewer = SimulationReviewer::new(telemetry.clone());
-        let simulator = Simulator::new(generator, predictor, reviewer, telemetry.clone());
-        let advanced = AdvancedSimulator::new(
+        let mut simulator = Simulator::new(generator, predictor, reviewer, telemetry.clone());
+        let mut advanced = AdvancedSimulator::new(
             Simulator::new(
                 EnvironmentGenerator::new(self.env_seed + 1),
                 ScenarioPredictor::new(self.predictor_noise / 2.0),
@@ -95,6 +161,10 @@ impl SimulationEngineBuilder {
             ScenarioThinker::default(),
             telemetry.clone(),
         );
+        for (name, generator) in self.generators {
+            simulator.register_generator(name.clone(), Arc::clone(&generator));
+            advanced.register_generator(name, generator);
+        }
         Ok(SimulationEngine {
             telemetry,
             simulator,
@@ -109,6 +179,7 @@ impl Default for SimulationEngineBuilder {
             telemetry: None,
             env_seed: crate::helper::random_seed(),
             predictor_noise: 0.15,
+            generators: IndexMap::new(),
         }
     }
 }
@@ -150,4 +221,75 @@ mod tests {
             .unwrap();
         assert_eq!(report.scenario_count, 1);
     }
+
+    #[tokio::test]
+    async fn engine_summarizes_monte_carlo_run() {
+        let engine = SimulationEngine::builder().env_seed(3).build().unwrap();
+        let summary = engine
+            .run_monte_carlo(SimulationMethod::Approximate, 12)
+            .await
+            .unwrap();
+        assert_eq!(summary.scenario_count, 12);
+        let load = summary.metrics["load"];
+        assert_eq!(load.samples, 12);
+        assert!(load.brackets(load.mean));
+    }
+
+    #[tokio::test]
+    async fn report_records_custom_method() {
+        let engine = SimulationEngine::builder()
+            .custom_generator("orbital", |count: usize| {
+                (0..count)
+                    .map(|idx| crate::simul_env_generator::SimulationScenario {
+                        id: uuid::Uuid::new_v4(),
+                        label: format!("orbit-{idx}"),
+                        parameters: indexmap::indexmap! { "load".into() => 0.9 },
+                    })
+                    .collect()
+            })
+            .build()
+            .unwrap();
+        let report = engine
+            .run_advanced(SimulationMethod::Custom("orbital".into()), 2)
+            .await
+            .unwrap();
+        assert_eq!(report.method.label(), "orbital");
+        assert_eq!(report.scenarios[0].label, "orbit-0");
+        assert_eq!(report.scenarios[0].finding, "high_load");
+    }
+
+    #[tokio::test]
+    async fn engine_runs_supplied_scenarios() {
+        let engine = SimulationEngine::builder().build().unwrap();
+        let scenarios = vec![SimulationScenario {
+            id: uuid::Uuid::new_v4(),
+            label: "supplied".into(),
+            parameters: indexmap::indexmap! { "load".into() => 0.9 },
+        }];
+        let report = engine
+            .run_scenarios(SimulationMethod::Approximate, scenarios)
+            .await
+            .unwrap();
+        assert_eq!(report.scenario_count, 1);
+        assert_eq!(report.scenarios[0].finding, "high_load");
+    }
+
+    #[tokio::test]
+    async fn report_exports_csv() {
+        let engine = SimulationEngine::builder().env_seed(7).build().unwrap();
+        let report = engine
+            .run_advanced(SimulationMethod::Approximate, 3)
+            .await
+            .unwrap();
+        let mut buffer = Vec::new();
+        report.to_csv(&mut buffer).unwrap();
+        let csv = String::from_utf8(buffer).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), report.scenario_count + 1);
+        assert_eq!(
+            lines[0],
+            "scenario_id,label,load,latency,traffic,mae,finding"
+        );
+        assert!(lines[1..].iter().all(|line| line.split(',').count() == 7));
+    }
 }
@@ -1,29 +1,32 @@
 use serde::{Deserialize, Serialize};
 
 /// Supported simulation methods.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SimulationMethod {
     /// Fast approximate simulation.
     Approximate,
     /// High fidelity multi-step simulation.
     HighFidelity,
+    /// User-registered generator identified by name.
+    Custom(String),
 }
 
 impl SimulationMethod {
     /// Label for logging.
     #[must_use]
-    pub fn label(self) -> &'static str {
+    pub fn label(&self) -> &str {
         match self {
             Self::Approximate => "approximate",
             Self::HighFidelity => "high_fidelity",
+            Self::Custom(name) => name,
         }
     }
 
     /// Returns step count multiplier.
     #[must_use]
-    pub fn step_multiplier(self) -> usize {
+    pub const fn step_multiplier(&self) -> usize {
         match self {
-            Self::Approximate => 1,
+            Self::Approximate | Self::Custom(_) => 1,
             Self::HighFidelity => 3,
         }
     }
@@ -0,0 +1,129 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{compare::SimulationObservation, methods::SimulationMethod};
+
+/// Two-sided z-score for a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Distribution statistics for a single metric across Monte Carlo scenarios.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MetricSummary {
+    /// Number of samples observed.
+    pub samples: usize,
+    /// Sample mean.
+    pub mean: f32,
+    /// Sample standard deviation.
+    pub std_dev: f32,
+    /// Lower bound of the 95% confidence interval for the mean.
+    pub ci_low: f32,
+    /// Upper bound of the 95% confidence interval for the mean.
+    pub ci_high: f32,
+}
+
+impl MetricSummary {
+    /// Computes statistics from raw samples.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn from_samples(samples: &[f32]) -> Self {
+        let n = samples.len();
+        if n == 0 {
+            return Self {
+                samples: 0,
+                mean: 0.0,
+                std_dev: 0.0,
+                ci_low: 0.0,
+                ci_high: 0.0,
+            };
+        }
+        let count = n as f64;
+        let mean = samples.iter().map(|v| f64::from(*v)).sum::<f64>() / count;
+        let variance = if n > 1 {
+            samples
+                .iter()
+                .map(|v| (f64::from(*v) - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1.0)
+        } else {
+            0.0
+        };
+        let std_dev = variance.sqrt();
+        let margin = Z_95 * std_dev / count.sqrt();
+        Self {
+            samples: n,
+            mean: mean as f32,
+            std_dev: std_dev as f32,
+            ci_low: (mean - margin) as f32,
+            ci_high: (mean + margin) as f32,
+        }
+    }
+
+    /// Returns true when the value lies inside the confidence interval.
+    #[must_use]
+    pub fn brackets(&self, value: f32) -> bool {
+        self.ci_low <= value && value <= self.ci_high
+    }
+}
+
+/// Per-metric statistical summary of a Monte Carlo run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloSummary {
+    /// Method used for the run.
+    pub method: SimulationMethod,
+    /// Number of scenarios simulated.
+    pub scenario_count: usize,
+    /// Statistics keyed by metric name.
+    pub metrics: IndexMap<String, MetricSummary>,
+}
+
+impl MonteCarloSummary {
+    /// Aggregates observed metrics across scenarios.
+    #[must_use]
+    pub fn from_observations(
+        method: SimulationMethod,
+        observations: &[SimulationObservation],
+    ) -> Self {
+        let mut samples: IndexMap<String, Vec<f32>> = IndexMap::new();
+        for observation in observations {
+            for (metric, value) in &observation.observed_metrics {
+                samples.entry(metric.clone()).or_default().push(*value);
+            }
+        }
+        Self {
+            method,
+            scenario_count: observations.len(),
+            metrics: samples
+                .into_iter()
+                .map(|(metric, values)| (metric, MetricSummary::from_samples(&values)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::seeded_rng;
+    use indexmap::indexmap;
+    use rand::Rng;
+    use uuid::Uuid;
+
+    #[test]
+    fn summary_recovers_uniform_mean() {
+        let mut rng = seeded_rng(11);
+        let observations = (0..2_000)
+            .map(|_| SimulationObservation {
+                scenario_id: Uuid::new_v4(),
+                observed_metrics: indexmap! { "load".into() => rng.gen_range(0.0..1.0) },
+            })
+            .collect::<Vec<_>>();
+        let summary =
+            MonteCarloSummary::from_observations(SimulationMethod::Approximate, &observations);
+        let load = summary.metrics["load"];
+        assert_eq!(load.samples, 2_000);
+        assert!((load.mean - 0.5).abs() < 0.03);
+        assert!((load.std_dev - 0.2887).abs() < 0.02);
+        assert!(load.brackets(0.5));
+        assert!(load.ci_low < load.mean && load.mean < load.ci_high);
+    }
+}
@@ -1,11 +1,38 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{compare::ComparisonResult, helper::SimulationTelemetry};
 
+/// Reviewer classification of a single scenario.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioVerdict {
+    /// Prediction error within tolerance.
+    Acceptable,
+    /// Error above the risk threshold but below the failure threshold.
+    Risky,
+    /// Error at or above the failure threshold.
+    Failing,
+}
+
+/// Classification of a scenario with the reasoning and suggested remediations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScenarioReview {
+    /// Scenario id.
+    pub scenario_id: uuid::Uuid,
+    /// Classification.
+    pub verdict: ScenarioVerdict,
+    /// Why the scenario received its verdict.
+    pub reason: String,
+    /// Suggested remediations, worst metric first; empty for acceptable scenarios.
+    pub remediations: Vec<String>,
+}
+
 /// Reviewer that inspects comparison results and raises alerts.
 pub struct SimulationReviewer {
     telemetry: Option<SimulationTelemetry>,
+    risk_threshold: f32,
     mae_threshold: f32,
 }
 
@@ -15,16 +42,26 @@ impl SimulationReviewer {
     pub fn new(telemetry: Option<SimulationTelemetry>) -> Self {
         Self {
             telemetry,
+            risk_threshold: 0.1,
             mae_threshold: 0.2,
         }
     }
 
+    /// Overrides the MAE at which scenarios become risky and failing.
+    #[must_use]
+    pub const fn with_thresholds(mut self, risky: f32, failing: f32) -> Self {
+        self.mae_threshold = failing;
+        self.risk_threshold = risky.min(failing);
+        self
+    }
+
     /// Reviews results and returns failing scenario ids.
     pub fn review(&self, results: &[ComparisonResult]) -> Result<Vec<uuid::Uuid>> {
-        let failing: Vec<_> = results
-            .iter()
-            .filter(|res| res.mae >= self.mae_threshold)
-            .map(|res| res.scenario_id)
+        let failing: Vec<_> = self
+            .classify(results)
+            .into_iter()
+            .filter(|review| review.verdict == ScenarioVerdict::Failing)
+            .map(|review| review.scenario_id)
             .collect();
         if let Some(tel) = &self.telemetry {
             let _ = tel.event(
@@ -37,6 +74,57 @@ impl SimulationReviewer {
         }
         Ok(failing)
     }
+
+    /// Classifies every result and suggests remediations for risky and failing ones.
+    #[must_use]
+    pub fn classify(&self, results: &[ComparisonResult]) -> Vec<ScenarioReview> {
+        results.iter().map(|res| self.classify_one(res)).collect()
+    }
+
+    fn classify_one(&self, result: &ComparisonResult) -> ScenarioReview {
+        let (verdict, threshold) = if result.mae >= self.mae_threshold {
+            (ScenarioVerdict::Failing, self.mae_threshold)
+        } else if result.mae >= self.risk_threshold {
+            (ScenarioVerdict::Risky, self.risk_threshold)
+        } else {
+            (ScenarioVerdict::Acceptable, self.risk_threshold)
+        };
+        let mut offenders: Vec<_> = result
+            .per_metric_error
+            .iter()
+            .filter(|(_, error)| **error >= self.risk_threshold)
+            .collect();
+        offenders.sort_by(|a, b| b.1.total_cmp(a.1));
+
+        let reason = match (verdict, offenders.first()) {
+            (ScenarioVerdict::Acceptable, _) => {
+                format!("mae {:.3} below risk threshold {threshold:.3}", result.mae)
+            }
+            (_, Some((metric, error))) => format!(
+                "mae {:.3} >= threshold {threshold:.3}; worst metric {metric} ({error:.3})",
+                result.mae
+            ),
+            (_, None) => format!("mae {:.3} >= threshold {threshold:.3}", result.mae),
+        };
+        let mut remediations = Vec::new();
+        if verdict != ScenarioVerdict::Acceptable {
+            remediations.extend(offenders.iter().map(|(metric, error)| {
+                format!("increase {metric} buffer by {:.0}%", *error * 100.0)
+            }));
+            if remediations.is_empty() {
+                remediations.push(format!(
+                    "widen safety margins by {:.0}%",
+                    result.mae * 100.0
+                ));
+            }
+        }
+        ScenarioReview {
+            scenario_id: result.scenario_id,
+            verdict,
+            reason,
+            remediations,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -56,4 +144,63 @@ mod tests {
         let failing = reviewer.review(&results).unwrap();
         assert_eq!(failing.len(), 1);
     }
+
+    #[test]
+    fn failing_scenario_report_carries_remediation() {
+        use crate::{
+            advanced::SimulationReportBuilder, compare::SimulationObservation,
+            methods::SimulationMethod, predictor::SimulationPrediction,
+            simul_env_generator::SimulationScenario, simulator::SimulationBatch,
+        };
+
+        let scenario = SimulationScenario {
+            id: Uuid::new_v4(),
+            label: "surge".into(),
+            parameters: indexmap! { "load".into() => 0.9 },
+        };
+        let batch = SimulationBatch {
+            method: SimulationMethod::Approximate,
+            predictions: vec![SimulationPrediction {
+                id: Uuid::new_v4(),
+                scenario_id: scenario.id,
+                projected_metrics: indexmap! { "latency".into() => 0.3, "load".into() => 0.5 },
+            }],
+            observations: vec![SimulationObservation {
+                scenario_id: scenario.id,
+                observed_metrics: indexmap! { "latency".into() => 0.75, "load".into() => 0.55 },
+            }],
+            scenarios: vec![scenario],
+        };
+        let reviewer = SimulationReviewer::new(None);
+        let report = SimulationReportBuilder::new()
+            .batch(&batch)
+            .reviewer(&reviewer)
+            .build();
+
+        let review = report.scenarios[0].review.as_ref().unwrap();
+        assert_eq!(review.verdict, ScenarioVerdict::Failing);
+        assert!(review.reason.contains("worst metric latency"));
+        assert_eq!(review.remediations, vec!["increase latency buffer by 45%"]);
+    }
+
+    #[test]
+    fn scenarios_are_classified_with_remediations() {
+        let reviewer = SimulationReviewer::new(None);
+        let reviews = reviewer.classify(&[
+            ComparisonResult {
+                scenario_id: Uuid::new_v4(),
+                mae: 0.02,
+                per_metric_error: indexmap! { "load".into() => 0.02 },
+            },
+            ComparisonResult {
+                scenario_id: Uuid::new_v4(),
+                mae: 0.15,
+                per_metric_error: indexmap! { "load".into() => 0.15 },
+            },
+        ]);
+        assert_eq!(reviews[0].verdict, ScenarioVerdict::Acceptable);
+        assert!(reviews[0].remediations.is_empty());
+        assert_eq!(reviews[1].verdict, ScenarioVerdict::Risky);
+        assert_eq!(reviews[1].remediations, vec!["increase load buffer by 15%"]);
+    }
 }
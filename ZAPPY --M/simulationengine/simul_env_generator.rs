@@ -1,14 +1,18 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use indexmap::IndexMap;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use zappy_world::WorldState;
 
 use crate::helper::{random_seed, seeded_rng};
 
 /// Scenario describing initial environment conditions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationScenario {
-    /// Scenario id.
+    /// Scenario id; generated scenarios encode their generator seed, batch number and
+    /// index within the batch.
     pub id: Uuid,
     /// Human readable label.
     pub label: String,
@@ -16,36 +20,141 @@ pub struct SimulationScenario {
     pub parameters: IndexMap<String, f32>,
 }
 
+/// Pluggable scenario generation used by [`SimulationMethod::Custom`](crate::methods::SimulationMethod::Custom).
+pub trait ScenarioGenerator: Send + Sync {
+    /// Generates `count` scenarios.
+    fn generate(&self, count: usize) -> Vec<SimulationScenario>;
+}
+
+impl<F> ScenarioGenerator for F
+where
+    F: Fn(usize) -> Vec<SimulationScenario> + Send + Sync,
+{
+    fn generate(&self, count: usize) -> Vec<SimulationScenario> {
+        self(count)
+    }
+}
+
+/// Relative spread applied to world-seeded parameters in perturbed scenarios.
+const WORLD_PERTURBATION: f32 = 0.15;
+
+/// Odd multiplier spreading scenario indices across per-scenario RNG seeds.
+const SCENARIO_SEED_STRIDE: u64 = 0x9E37_79B9_7F4A_7C15;
+
 /// Generates simulation scenarios using seeded randomness.
+///
+/// Every call to [`Self::generate`] starts a new batch, so repeated batches draw fresh
+/// scenarios with distinct ids. Ids are unique for up to 2^32 batches of 2^32
+/// scenarios each, and only replay on a generator with the same seed and base state.
 pub struct EnvironmentGenerator {
     seed: u64,
+    base: Option<IndexMap<String, f32>>,
+    batches: AtomicU32,
 }
 
 impl EnvironmentGenerator {
     /// Creates generator with seed.
     #[must_use]
     pub fn new(seed: u64) -> Self {
-        Self { seed }
+        Self {
+            seed,
+            base: None,
+            batches: AtomicU32::new(0),
+        }
+    }
+
+    /// Seeds scenarios from an observed world state instead of synthetic ranges.
+    ///
+    /// Parameters are keyed `region.metric`, plus the cross-region mean of each metric.
+    /// The first generated scenario reproduces the state; the rest perturb it.
+    #[must_use]
+    pub fn from_world_state(state: &WorldState) -> Self {
+        let mut base = IndexMap::new();
+        let mut totals: IndexMap<String, (f32, u16)> = IndexMap::new();
+        for (region_id, snapshot) in &state.regions {
+            for (metric, value) in &snapshot.metrics {
+                base.insert(format!("{region_id}.{metric}"), *value);
+                let entry = totals.entry(metric.clone()).or_insert((0.0, 0));
+                entry.0 += value;
+                entry.1 = entry.1.saturating_add(1);
+            }
+        }
+        for (metric, (sum, count)) in totals {
+            base.insert(metric, sum / f32::from(count));
+        }
+        Self {
+            seed: random_seed(),
+            base: Some(base),
+            batches: AtomicU32::new(0),
+        }
+    }
+
+    /// Overrides the perturbation seed.
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
     }
 
     /// Generates a set of scenarios.
+    ///
+    /// Each scenario draws from its own RNG derived from the generator seed, the batch
+    /// number and its index, all of which are encoded in the scenario id (see
+    /// [`Self::regenerate`]). At most `u32::MAX` scenarios are generated per batch.
     #[must_use]
     pub fn generate(&self, count: usize) -> Vec<SimulationScenario> {
-        let mut rng = seeded_rng(self.seed);
-        (0..count)
-            .map(|idx| {
-                let mut params = IndexMap::new();
-                params.insert("load".into(), rng.gen_range(0.2..0.95));
-                params.insert("latency".into(), rng.gen_range(15.0..180.0));
-                params.insert("traffic".into(), rng.gen_range(0.1..0.9));
-                SimulationScenario {
-                    id: Uuid::new_v4(),
-                    label: format!("scenario-{}", idx),
-                    parameters: params,
-                }
-            })
+        let batch = u64::from(self.batches.fetch_add(1, Ordering::Relaxed));
+        (0_u32..)
+            .zip(0..count)
+            .map(|(index, _)| self.scenario(batch << 32 | u64::from(index)))
             .collect()
     }
+
+    /// Regenerates the scenario identified by `id` without generating the rest of its batch.
+    ///
+    /// Returns `None` when the id was not produced by a generator with this seed.
+    #[must_use]
+    pub fn regenerate(&self, id: Uuid) -> Option<SimulationScenario> {
+        let (seed, slot) = id.as_u64_pair();
+        (seed == self.seed).then(|| self.scenario(slot))
+    }
+
+    /// Builds the scenario at `slot`, whose high 32 bits are the batch number and low
+    /// 32 bits the index within that batch.
+    fn scenario(&self, slot: u64) -> SimulationScenario {
+        let id = Uuid::from_u64_pair(self.seed, slot);
+        let index = slot & u64::from(u32::MAX);
+        let mut rng = seeded_rng(self.seed ^ slot.wrapping_mul(SCENARIO_SEED_STRIDE));
+        if let Some(base) = &self.base {
+            let (label, parameters) = if index == 0 {
+                ("baseline".to_string(), base.clone())
+            } else {
+                let parameters = base
+                    .iter()
+                    .map(|(key, value)| {
+                        let factor =
+                            rng.gen_range(1.0 - WORLD_PERTURBATION..1.0 + WORLD_PERTURBATION);
+                        (key.clone(), value * factor)
+                    })
+                    .collect();
+                (format!("scenario-{index}"), parameters)
+            };
+            return SimulationScenario {
+                id,
+                label,
+                parameters,
+            };
+        }
+        let mut params = IndexMap::new();
+        params.insert("load".into(), rng.gen_range(0.2..0.95));
+        params.insert("latency".into(), rng.gen_range(15.0..180.0));
+        params.insert("traffic".into(), rng.gen_range(0.1..0.9));
+        SimulationScenario {
+            id,
+            label: format!("scenario-{index}"),
+            parameters: params,
+        }
+    }
 }
 
 impl Default for EnvironmentGenerator {
@@ -65,4 +174,51 @@ mod tests {
         assert_eq!(scenarios.len(), 2);
         assert!(scenarios[0].parameters.contains_key("load"));
     }
+
+    #[test]
+    fn repeated_batches_get_distinct_replayable_ids() {
+        let generator = EnvironmentGenerator::new(42);
+        let first = generator.generate(3);
+        let second = generator.generate(3);
+        for (a, b) in first.iter().zip(&second) {
+            assert_ne!(a.id, b.id);
+            assert_ne!(a.parameters, b.parameters);
+        }
+        let replayed = generator.regenerate(second[2].id).unwrap();
+        assert_eq!(replayed.label, "scenario-2");
+        assert_eq!(replayed.parameters, second[2].parameters);
+    }
+
+    #[test]
+    fn world_state_seeds_baseline_scenario() {
+        use zappy_world::model::RegionSnapshot;
+
+        let mut state = WorldState::default();
+        state.apply_snapshot(RegionSnapshot::from_metrics(
+            "alpha",
+            indexmap::indexmap! { "load".into() => 0.8, "demand".into() => 0.4 },
+        ));
+        state.apply_snapshot(RegionSnapshot::from_metrics(
+            "beta",
+            indexmap::indexmap! { "load".into() => 0.4 },
+        ));
+
+        let scenarios = EnvironmentGenerator::from_world_state(&state)
+            .with_seed(7)
+            .generate(3);
+        assert_eq!(scenarios.len(), 3);
+        let baseline = &scenarios[0];
+        assert_eq!(baseline.label, "baseline");
+        for (region_id, snapshot) in &state.regions {
+            for (metric, value) in &snapshot.metrics {
+                let seeded = baseline.parameters[&format!("{region_id}.{metric}")];
+                assert!((seeded - value).abs() < f32::EPSILON);
+            }
+        }
+        assert!((baseline.parameters["load"] - 0.6).abs() < 1e-6);
+        assert!(scenarios[1..].iter().all(|scenario| {
+            let load = scenario.parameters["alpha.load"];
+            (0.68..=0.92).contains(&load)
+        }));
+    }
 }
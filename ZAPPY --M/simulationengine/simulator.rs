@@ -1,6 +1,10 @@
-use anyhow::Result;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
 use serde_json::json;
 use tokio::time::{sleep, Duration};
+use uuid::Uuid;
 
 use crate::{
     compare::{compare, SimulationObservation},
@@ -8,11 +12,13 @@ use crate::{
     methods::SimulationMethod,
     predictor::{ScenarioPredictor, SimulationPrediction},
     reviewer::SimulationReviewer,
-    simul_env_generator::{EnvironmentGenerator, SimulationScenario},
+    simul_env_generator::{EnvironmentGenerator, ScenarioGenerator, SimulationScenario},
 };
 
 /// Result after running a simulation batch.
 pub struct SimulationBatch {
+    /// Method used to generate the batch.
+    pub method: SimulationMethod,
     /// Scenarios executed.
     pub scenarios: Vec<SimulationScenario>,
     /// Predictions produced.
@@ -27,6 +33,7 @@ pub struct Simulator {
     predictor: ScenarioPredictor,
     reviewer: SimulationReviewer,
     telemetry: Option<SimulationTelemetry>,
+    custom: IndexMap<String, Arc<dyn ScenarioGenerator>>,
 }
 
 impl Simulator {
@@ -43,9 +50,25 @@ impl Simulator {
             predictor,
             reviewer,
             telemetry,
+            custom: IndexMap::new(),
         }
     }
 
+    /// Reviewer applied to every batch.
+    #[must_use]
+    pub const fn reviewer(&self) -> &SimulationReviewer {
+        &self.reviewer
+    }
+
+    /// Registers a generator dispatched for `SimulationMethod::Custom(name)`.
+    pub fn register_generator(
+        &mut self,
+        name: impl Into<String>,
+        generator: Arc<dyn ScenarioGenerator>,
+    ) {
+        self.custom.insert(name.into(), generator);
+    }
+
     /// Runs a single batch.
     pub async fn run(&self, method: SimulationMethod, count: usize) -> Result<SimulationBatch> {
         if let Some(tel) = &self.telemetry {
@@ -55,9 +78,40 @@ impl Simulator {
                 json!({ "method": method.label(), "count": count }),
             );
         }
-        let scenarios = self.generator.generate(count);
+        let scenarios = match &method {
+            SimulationMethod::Custom(name) => self
+                .custom
+                .get(name)
+                .ok_or_else(|| anyhow!("no generator registered for method `{name}`"))?
+                .generate(count),
+            _ => self.generator.generate(count),
+        };
+        self.run_scenarios(method, scenarios).await
+    }
+
+    /// Deterministically regenerates a single scenario from a previous batch by its id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scenario_id` was not produced by this simulator's generator.
+    pub fn replay(&self, scenario_id: Uuid) -> Result<SimulationScenario> {
+        self.generator
+            .regenerate(scenario_id)
+            .ok_or_else(|| anyhow!("scenario {scenario_id} was not produced by this generator"))
+    }
+
+    /// Runs a batch over caller-supplied scenarios instead of generating them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if executing or reviewing the scenarios fails.
+    pub async fn run_scenarios(
+        &self,
+        method: SimulationMethod,
+        scenarios: Vec<SimulationScenario>,
+    ) -> Result<SimulationBatch> {
         let predictions = self.predictor.predict(&scenarios);
-        let observations = self.execute_observations(&predictions, method).await?;
+        let observations = self.execute_observations(&predictions, &method).await?;
         let comparisons = compare(&predictions, &observations);
         let failing = self.reviewer.review(&comparisons)?;
         if let Some(tel) = &self.telemetry {
@@ -67,6 +121,7 @@ impl Simulator {
             );
         }
         Ok(SimulationBatch {
+            method,
             scenarios,
             predictions,
             observations,
@@ -76,7 +131,7 @@ impl Simulator {
     async fn execute_observations(
         &self,
         predictions: &[SimulationPrediction],
-        method: SimulationMethod,
+        method: &SimulationMethod,
     ) -> Result<Vec<SimulationObservation>> {
         let mut observations = Vec::new();
         for prediction in predictions {
@@ -121,4 +176,61 @@ mod tests {
             .unwrap();
         assert_eq!(batch.scenarios.len(), 2);
     }
+
+    #[tokio::test]
+    async fn replay_regenerates_identical_scenario() {
+        let simulator = Simulator::new(
+            EnvironmentGenerator::new(99),
+            ScenarioPredictor::default(),
+            SimulationReviewer::new(None),
+            None,
+        );
+        let batch = simulator
+            .run(SimulationMethod::Approximate, 5)
+            .await
+            .unwrap();
+        let original = &batch.scenarios[3];
+
+        let replayed = simulator.replay(original.id).unwrap();
+        assert_eq!(
+            serde_json::to_vec(&replayed).unwrap(),
+            serde_json::to_vec(original).unwrap()
+        );
+        assert!(simulator.replay(Uuid::new_v4()).is_err());
+    }
+
+    #[tokio::test]
+    async fn simulator_dispatches_custom_generator() {
+        let mut simulator = Simulator::new(
+            EnvironmentGenerator::default(),
+            ScenarioPredictor::default(),
+            SimulationReviewer::new(None),
+            None,
+        );
+        simulator.register_generator(
+            "reef",
+            Arc::new(|count: usize| {
+                (0..count)
+                    .map(|idx| SimulationScenario {
+                        id: uuid::Uuid::new_v4(),
+                        label: format!("reef-{idx}"),
+                        parameters: indexmap::indexmap! { "salinity".into() => 0.35 },
+                    })
+                    .collect::<Vec<_>>()
+            }),
+        );
+        let batch = simulator
+            .run(SimulationMethod::Custom("reef".into()), 2)
+            .await
+            .unwrap();
+        assert_eq!(batch.method.label(), "reef");
+        assert_eq!(batch.scenarios[1].label, "reef-1");
+        assert!(batch.observations[0]
+            .observed_metrics
+            .contains_key("salinity"));
+        assert!(simulator
+            .run(SimulationMethod::Custom("missing".into()), 1)
+            .await
+            .is_err());
+    }
 }
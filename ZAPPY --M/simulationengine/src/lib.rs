@@ -32,6 +32,10 @@ pub mod compare;
 #[path = "../reviewer.rs"]
 pub mod reviewer;
 
+/// Monte Carlo statistical summaries.
+#[path = "../montecarlo.rs"]
+pub mod montecarlo;
+
 /// Methods catalogue.
 #[path = "../methods.rs"]
 pub mod methods;
@@ -45,4 +49,5 @@ pub mod advanced;
 pub mod runtime;
 
 pub use helper::{SimulationTelemetry, SimulationTelemetryBuilder};
+pub use montecarlo::{MetricSummary, MonteCarloSummary};
 pub use runtime::{SimulationEngine, SimulationEngineBuilder};
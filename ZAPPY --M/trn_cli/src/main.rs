@@ -1,8 +1,10 @@
 use std::{
     fs::{self, File, OpenOptions},
-    io::{BufRead, BufReader, Write},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
-    process::Command,
+    process::{Child, Command, ExitStatus},
+    thread,
+    time::Duration,
 };
 
 use anyhow::{bail, Context, Result};
@@ -58,6 +60,17 @@ enum Commands {
         #[arg(long)]
         event_log: Option<PathBuf>,
     },
+    /// Trims the manifest to the most recent jobs, archiving the rest.
+    Prune {
+        /// Number of matching entries to keep.
+        #[arg(long, default_value_t = 100)]
+        keep: usize,
+        /// Statuses eligible for pruning (comma-separated); all when omitted.
+        #[arg(long, value_delimiter = ',')]
+        status: Vec<String>,
+        #[arg(long, default_value = "ZAPPY--M/learning/logs/jobs/index.jsonl")]
+        manifest: PathBuf,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -80,6 +93,9 @@ struct RunArgs {
     manifest: PathBuf,
     #[arg(long)]
     event_log: Option<PathBuf>,
+    /// Validates config and device allocation without recording or spawning the job.
+    #[arg(long)]
+    dry_run: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -160,14 +176,28 @@ fn main() -> Result<()> {
                 log_dir,
                 manifest,
                 event_log,
+                dry_run: false,
             };
             handle_run(args)
         }
+        Commands::Prune {
+            keep,
+            status,
+            manifest,
+        } => {
+            let outcome = prune_manifest(&manifest, keep, &status)?;
+            println!(
+                "kept {} | archived {} | {:?}",
+                outcome.kept, outcome.archived, outcome.archive_path
+            );
+            Ok(())
+        }
     }
 }
 
 fn handle_run(args: RunArgs) -> Result<()> {
     anyhow::ensure!(args.config.exists(), "config file not found");
+    validate_config(&args.config)?;
     if let Some(weights) = &args.weights {
         anyhow::ensure!(weights.exists(), "weights file not found");
     }
@@ -183,6 +213,11 @@ fn handle_run(args: RunArgs) -> Result<()> {
     };
 
     let plan = manager.allocate(preference, args.device_count);
+    if args.dry_run {
+        let summary = dry_run_summary(&args, &plan, &planned_log_path(&args.log_dir));
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return Ok(());
+    }
     let log_path = compute_log_path(&args.log_dir)?;
     let mut entry = JobManifestEntry::new(
         args.config.clone(),
@@ -232,7 +267,7 @@ fn handle_run(args: RunArgs) -> Result<()> {
         "job started",
         json!({ "job_id": entry.job_id }),
     )?;
-    match spawn_runner(&entry, &args, &plan) {
+    match spawn_runner(&entry, &args, &plan, event_sink.as_ref()) {
         Ok(_) => {
             update_status(&args.manifest, &entry.job_id, "completed")?;
             log_job_event(
@@ -300,20 +335,58 @@ fn read_manifest(path: &Path) -> Result<Vec<JobManifestEntry>> {
     Ok(entries)
 }
 
-fn compute_log_path(base: &Path) -> Result<PathBuf> {
+fn validate_config(path: &Path) -> Result<Value> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading config {:?}", path))?;
+    let config: Value =
+        serde_json::from_str(&raw).with_context(|| format!("parsing config {:?}", path))?;
+    for key in ["dataset_index", "checkpoint_dir"] {
+        anyhow::ensure!(
+            config.get(key).is_some_and(Value::is_string),
+            "config {:?} is missing `{key}`",
+            path
+        );
+    }
+    Ok(config)
+}
+
+fn dry_run_summary(args: &RunArgs, plan: &AllocationPlan, log_path: &Path) -> Value {
+    json!({
+        "event": "dry_run",
+        "config": args.config,
+        "weights": args.weights,
+        "profile": args.profile,
+        "devices": plan.devices().iter().map(|dev| &dev.id).collect::<Vec<_>>(),
+        "cuda_visible_devices": cuda_visible_devices(plan),
+        "log_path": log_path,
+        "manifest": args.manifest,
+    })
+}
+
+fn planned_log_path(base: &Path) -> PathBuf {
     let now = Local::now();
-    let dir = base
-        .join(format!("{:04}", now.year()))
+    base.join(format!("{:04}", now.year()))
         .join(format!("{:02}", now.month()))
-        .join(format!("{:02}", now.day()));
-    fs::create_dir_all(&dir)?;
-    Ok(dir.join(format!(
-        "job-{}.log.jsonl",
-        Utc::now().format("%Y%m%d-%H%M%S")
-    )))
+        .join(format!("{:02}", now.day()))
+        .join(format!(
+            "job-{}.log.jsonl",
+            Utc::now().format("%Y%m%d-%H%M%S")
+        ))
 }
 
-fn spawn_runner(entry: &JobManifestEntry, args: &RunArgs, plan: &AllocationPlan) -> Result<()> {
+fn compute_log_path(base: &Path) -> Result<PathBuf> {
+    let path = planned_log_path(base);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(path)
+}
+
+fn spawn_runner(
+    entry: &JobManifestEntry,
+    args: &RunArgs,
+    plan: &AllocationPlan,
+    sink: Option<&EventSink>,
+) -> Result<()> {
     let runner = Path::new("ZAPPY--M/learning/pytorch_runner/main.py");
     anyhow::ensure!(runner.exists(), "runner script {:?} not found", runner);
     fs::create_dir_all(entry.log_path.parent().unwrap())?;
@@ -334,7 +407,30 @@ fn spawn_runner(entry: &JobManifestEntry, args: &RunArgs, plan: &AllocationPlan)
     if let Some(cuda_visible) = cuda_visible_devices(plan) {
         cmd.env("CUDA_VISIBLE_DEVICES", cuda_visible);
     }
-    let status = cmd.status().context("running pytorch runner")?;
+    run_with_progress(cmd, entry, sink)
+}
+
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Runs the runner process, republishing `step` log lines as `training.progress` events.
+///
+/// The runner is killed and reaped if progress tracking fails before it exits.
+fn run_with_progress(
+    mut cmd: Command,
+    entry: &JobManifestEntry,
+    sink: Option<&EventSink>,
+) -> Result<()> {
+    let mut tail = LogTail::new(&entry.log_path);
+    let mut child = cmd.spawn().context("running pytorch runner")?;
+    let status = match wait_with_progress(&mut child, entry, sink, &mut tail) {
+        Ok(status) => status,
+        Err(err) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(err);
+        }
+    };
+    publish_progress(sink, entry, &mut tail)?;
     if status.success() {
         Ok(())
     } else {
@@ -342,6 +438,85 @@ fn spawn_runner(entry: &JobManifestEntry, args: &RunArgs, plan: &AllocationPlan)
     }
 }
 
+fn wait_with_progress(
+    child: &mut Child,
+    entry: &JobManifestEntry,
+    sink: Option<&EventSink>,
+    tail: &mut LogTail,
+) -> Result<ExitStatus> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        publish_progress(sink, entry, tail)?;
+        thread::sleep(PROGRESS_POLL_INTERVAL);
+    }
+}
+
+fn publish_progress(
+    sink: Option<&EventSink>,
+    entry: &JobManifestEntry,
+    tail: &mut LogTail,
+) -> Result<()> {
+    if sink.is_none() {
+        return Ok(());
+    }
+    for line in tail.poll()? {
+        let Ok(record) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if record.get("event").and_then(Value::as_str) != Some("step") {
+            continue;
+        }
+        let payload = &record["payload"];
+        publish_job_event(
+            sink,
+            "training.progress",
+            entry,
+            json!({ "step": payload["step"], "loss": payload["loss"] }),
+        )?;
+    }
+    Ok(())
+}
+
+/// Incremental reader returning complete lines appended since the last poll.
+///
+/// Bytes are buffered until a newline arrives, so a UTF-8 sequence split across
+/// polls is decoded only once the whole line is available.
+struct LogTail {
+    path: PathBuf,
+    offset: u64,
+    partial: Vec<u8>,
+}
+
+impl LogTail {
+    fn new(path: &Path) -> Self {
+        let offset = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+        Self {
+            path: path.to_path_buf(),
+            offset,
+            partial: Vec::new(),
+        }
+    }
+
+    fn poll(&mut self) -> Result<Vec<String>> {
+        let Ok(mut file) = File::open(&self.path) else {
+            return Ok(Vec::new());
+        };
+        file.seek(SeekFrom::Start(self.offset))?;
+        self.offset += file.read_to_end(&mut self.partial)? as u64;
+        let Some(end) = self.partial.iter().rposition(|byte| *byte == b'\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: Vec<u8> = self.partial.drain(..=end).collect();
+        Ok(String::from_utf8_lossy(&complete)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(ToOwned::to_owned)
+            .collect())
+    }
+}
+
 fn cuda_visible_devices(plan: &AllocationPlan) -> Option<String> {
     let ids: Vec<String> = plan
         .devices()
@@ -367,17 +542,93 @@ fn update_status(path: &Path, job_id: &str, status: &str) -> Result<()> {
     if !changed {
         return Ok(());
     }
+    write_manifest(path, &entries)
+}
+
+fn write_manifest(path: &Path, entries: &[JobManifestEntry]) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
-    let mut file = File::create(path)?;
+    let tmp = path.with_extension("jsonl.tmp");
+    let mut file = File::create(&tmp)?;
     for entry in entries {
-        serde_json::to_writer(&mut file, &entry)?;
+        serde_json::to_writer(&mut file, entry)?;
         file.write_all(b"\n")?;
     }
+    file.sync_all()?;
+    fs::rename(&tmp, path).with_context(|| format!("replacing manifest {:?}", path))?;
     Ok(())
 }
 
+#[derive(Debug)]
+struct PruneOutcome {
+    kept: usize,
+    archived: usize,
+    archive_path: Option<PathBuf>,
+}
+
+/// Keeps the `keep` most recent entries whose status matches `statuses`; entries
+/// with other statuses are never pruned. Removed entries go to a dated archive.
+fn prune_manifest(path: &Path, keep: usize, statuses: &[String]) -> Result<PruneOutcome> {
+    let entries = read_manifest(path)?;
+    let eligible = |entry: &JobManifestEntry| {
+        statuses.is_empty() || statuses.iter().any(|s| s.trim() == entry.status)
+    };
+    let matching = entries.iter().filter(|entry| eligible(entry)).count();
+    let mut to_drop = matching.saturating_sub(keep);
+    if to_drop == 0 {
+        return Ok(PruneOutcome {
+            kept: entries.len(),
+            archived: 0,
+            archive_path: None,
+        });
+    }
+
+    let mut kept = Vec::with_capacity(entries.len() - to_drop);
+    let mut archived = Vec::with_capacity(to_drop);
+    // The manifest is append-only, so the oldest entries come first.
+    for entry in entries {
+        if to_drop > 0 && eligible(&entry) {
+            to_drop -= 1;
+            archived.push(entry);
+        } else {
+            kept.push(entry);
+        }
+    }
+
+    let archive_path = archive_path_for(path);
+    if let Some(parent) = archive_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut archive = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&archive_path)
+        .with_context(|| format!("opening archive {:?}", archive_path))?;
+    for entry in &archived {
+        serde_json::to_writer(&mut archive, entry)?;
+        archive.write_all(b"\n")?;
+    }
+    archive.sync_all()?;
+    write_manifest(path, &kept)?;
+
+    Ok(PruneOutcome {
+        kept: kept.len(),
+        archived: archived.len(),
+        archive_path: Some(archive_path),
+    })
+}
+
+fn archive_path_for(manifest: &Path) -> PathBuf {
+    let stem = manifest
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("index");
+    let dir = manifest.parent().unwrap_or_else(|| Path::new("."));
+    dir.join("archive")
+        .join(format!("{stem}-{}.jsonl", Local::now().format("%Y%m%d")))
+}
+
 struct EventSink {
     runtime: Runtime,
     publisher: FileEventPublisher,
@@ -415,14 +666,7 @@ fn publish_job_event(
                 Value::Object(map)
             }
         };
-        let record = EventRecord {
-            id: format!("evt-{}", Uuid::new_v4()),
-            source: "trn".into(),
-            event_type: event_type.into(),
-            timestamp: Utc::now().to_rfc3339(),
-            payload,
-        };
-        sink.publish(record)?;
+        sink.publish(EventRecord::new("trn", event_type, payload))?;
     }
     Ok(())
 }
@@ -435,3 +679,158 @@ fn log_job_event(path: &Path, level: LogLevel, message: &str, metadata: Value) -
     }
     logger.log(&record)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn test_entry(log_path: PathBuf) -> JobManifestEntry {
+        JobManifestEntry {
+            job_id: "job-test".into(),
+            submitted_at: Utc::now(),
+            config: PathBuf::from("config.json"),
+            weights: None,
+            profile: "test".into(),
+            devices: vec!["cpu:0".into()],
+            log_path,
+            status: "running".into(),
+        }
+    }
+
+    #[test]
+    fn prune_keeps_most_recent_and_archives_rest() {
+        let dir = tempdir().unwrap();
+        let manifest = dir.path().join("jobs/index.jsonl");
+        for idx in 0..150 {
+            let mut entry = test_entry(dir.path().join("job.log"));
+            entry.job_id = format!("job-{idx}");
+            entry.status = if idx % 2 == 0 { "completed" } else { "failed" }.into();
+            append_manifest(&manifest, &entry).unwrap();
+        }
+        let statuses = vec!["completed".to_string(), "failed".to_string()];
+        let outcome = prune_manifest(&manifest, 100, &statuses).unwrap();
+        assert_eq!(outcome.archived, 50);
+
+        let remaining = read_manifest(&manifest).unwrap();
+        assert_eq!(remaining.len(), 100);
+        assert_eq!(remaining[0].job_id, "job-50");
+
+        let archived = read_manifest(&outcome.archive_path.unwrap()).unwrap();
+        assert_eq!(archived.len(), 50);
+        assert_eq!(archived[0].job_id, "job-0");
+        assert_eq!(archived[49].job_id, "job-49");
+    }
+
+    #[test]
+    fn dry_run_skips_manifest_and_runner() {
+        let dir = tempdir().unwrap();
+        let config = dir.path().join("train.json");
+        fs::write(
+            &config,
+            r#"{ "dataset_index": "index.json", "checkpoint_dir": "ckpt" }"#,
+        )
+        .unwrap();
+        let args = RunArgs {
+            config,
+            weights: None,
+            profile: "dev".into(),
+            devices: None,
+            cpu_only: true,
+            device_count: 1,
+            log_dir: dir.path().join("logs"),
+            manifest: dir.path().join("jobs/index.jsonl"),
+            event_log: Some(dir.path().join("events.jsonl")),
+            dry_run: true,
+        };
+        let plan = DeviceManager::autodetect().allocate(DevicePreference::CpuOnly, 1);
+        let summary = dry_run_summary(&args, &plan, &planned_log_path(&args.log_dir));
+        assert_eq!(summary["devices"].as_array().unwrap().len(), 1);
+        assert!(summary["log_path"]
+            .as_str()
+            .unwrap()
+            .ends_with(".log.jsonl"));
+
+        // The runner script is absent here, so any spawn attempt would error.
+        handle_run(args).unwrap();
+        assert!(!dir.path().join("jobs/index.jsonl").exists());
+        assert!(!dir.path().join("logs").exists());
+        assert!(!dir.path().join("events.jsonl").exists());
+    }
+
+    #[test]
+    fn dry_run_rejects_incomplete_config() {
+        let dir = tempdir().unwrap();
+        let config = dir.path().join("train.json");
+        fs::write(&config, r#"{ "steps": 10 }"#).unwrap();
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn progress_lines_are_republished() {
+        let dir = tempdir().unwrap();
+        let log_path = dir.path().join("job.log.jsonl");
+        let event_log = dir.path().join("events.jsonl");
+        fs::write(&log_path, "{\"message\":\"job started\"}\n").unwrap();
+        let sink = EventSink::new(&event_log).unwrap();
+        let entry = test_entry(log_path.clone());
+
+        let mut fake_runner = Command::new("sh");
+        fake_runner.arg("-c").arg(format!(
+            "for step in 0 10 20; do \
+               echo '{{\"event\":\"step\",\"payload\":{{\"step\":'$step',\"loss\":0.5}}}}' >> {log}; \
+               sleep 0.1; \
+             done; \
+             echo '{{\"event\":\"session_complete\",\"payload\":{{}}}}' >> {log}",
+            log = log_path.display()
+        ));
+        run_with_progress(fake_runner, &entry, Some(&sink)).unwrap();
+
+        let events = fs::read_to_string(&event_log)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<EventRecord>(line).unwrap())
+            .collect::<Vec<_>>();
+        let steps = events
+            .iter()
+            .filter(|event| event.event_type == "training.progress")
+            .map(|event| event.payload["step"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(steps, vec![0, 10, 20]);
+        assert!(events
+            .iter()
+            .all(|event| event.payload["job_id"] == "job-test"));
+    }
+
+    #[test]
+    fn log_tail_waits_for_split_utf8_lines() {
+        let dir = tempdir().unwrap();
+        let log_path = dir.path().join("job.log.jsonl");
+        fs::write(&log_path, "").unwrap();
+        let mut tail = LogTail::new(&log_path);
+        let line = "{\"message\":\"température\"}\n".as_bytes();
+        let split = line.iter().position(|byte| *byte >= 0x80).unwrap() + 1;
+
+        let mut file = OpenOptions::new().append(true).open(&log_path).unwrap();
+        file.write_all(&line[..split]).unwrap();
+        assert!(tail.poll().unwrap().is_empty());
+        file.write_all(&line[split..]).unwrap();
+        assert_eq!(tail.poll().unwrap(), vec!["{\"message\":\"température\"}"]);
+    }
+
+    #[test]
+    fn runner_is_killed_when_progress_tracking_fails() {
+        let dir = tempdir().unwrap();
+        // A directory in place of the log makes every poll fail.
+        let log_path = dir.path().join("job.log.jsonl");
+        fs::create_dir(&log_path).unwrap();
+        let sink = EventSink::new(&dir.path().join("events.jsonl")).unwrap();
+        let entry = test_entry(log_path);
+
+        let mut slow_runner = Command::new("sh");
+        slow_runner.arg("-c").arg("exec sleep 30");
+        let started = std::time::Instant::now();
+        assert!(run_with_progress(slow_runner, &entry, Some(&sink)).is_err());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,284 @@
+//! Goal-driven workflow chaining knowledge, reasoning, planning and actions.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use zappy_actions::{
+    actioncommander::ActionCommander,
+    actions::{
+        ActionDomain, ActionIntent, ActionMetadata, ActionOutcome, ActionPayload, ActionRequest,
+    },
+};
+use zappy_knowledge::{KnowledgeQuery, KnowledgeRuntime, KnowledgeSnippet};
+use zappy_learning::pipeline::ExperienceHub;
+use zappy_planning::{PlanningDirective, PlanningRuntime, PlanningSignal, PriorityBand};
+use zappy_reasoning::{
+    module::DirectivePriority as ReasoningPriority, ReasoningDirective, ReasoningRuntime,
+    SignalPacket, Verdict,
+};
+
+/// Upper bound on tactical tasks dispatched per workflow run.
+const MAX_DISPATCHED_TASKS: usize = 5;
+
+/// Summary of a single workflow run across every stage.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowReport {
+    /// Trace id shared by every stage event.
+    pub trace_id: Uuid,
+    /// Goal the workflow pursued.
+    pub goal: String,
+    /// Knowledge snippets fed into reasoning.
+    pub snippets: usize,
+    /// Reasoning verdict.
+    pub verdict: Verdict,
+    /// Objective of the strategic plan, when one was produced.
+    pub objective: Option<String>,
+    /// Action ids submitted to the commander.
+    pub submitted: Vec<Uuid>,
+    /// Outcomes of the submitted actions.
+    pub outcomes: Vec<ActionOutcome>,
+}
+
+/// Runs a goal through knowledge -> reasoning -> planning -> actions.
+pub struct WorkflowOrchestrator<'a> {
+    knowledge: &'a KnowledgeRuntime,
+    reasoning: &'a ReasoningRuntime,
+    planning: &'a mut PlanningRuntime,
+    actions: &'a ActionCommander,
+    hub: &'a ExperienceHub,
+}
+
+impl<'a> WorkflowOrchestrator<'a> {
+    /// Wires the workflow over existing runtimes; stage events go to `hub`.
+    pub fn new(
+        knowledge: &'a KnowledgeRuntime,
+        reasoning: &'a ReasoningRuntime,
+        planning: &'a mut PlanningRuntime,
+        actions: &'a ActionCommander,
+        hub: &'a ExperienceHub,
+    ) -> Self {
+        Self {
+            knowledge,
+            reasoning,
+            planning,
+            actions,
+            hub,
+        }
+    }
+
+    /// Executes the full chain for `goal`.
+    pub async fn run(&mut self, goal: &str) -> Result<WorkflowReport> {
+        let trace_id = Uuid::new_v4();
+        self.stage(
+            trace_id,
+            "knowledge",
+            "workflow.started",
+            json!({ "goal": goal }),
+        );
+
+        let snippets = self.knowledge.search(KnowledgeQuery::new(goal));
+        self.stage(
+            trace_id,
+            "knowledge",
+            "workflow.knowledge.searched",
+            json!({
+                "results": snippets.len(),
+                "records": snippets.iter().map(|s| s.record_id).collect::<Vec<_>>()
+            }),
+        );
+
+        let directive = ReasoningDirective::new(goal, ReasoningPriority::High);
+        let verdict = self
+            .reasoning
+            .reason(directive, signal_packets(goal, &snippets))
+            .await
+            .context("workflow reasoning failed")?;
+        self.stage(
+            trace_id,
+            "reasoning",
+            "workflow.reasoning.verdict",
+            json!({
+                "directive_id": verdict.directive_id,
+                "notes": verdict.notes,
+                "confidence": verdict.hypothesis.as_ref().map(|h| h.confidence)
+            }),
+        );
+
+        let directives = planning_directives(goal, &verdict);
+        let Some(plan) = self
+            .planning
+            .propose_strategic_plan(directives)
+            .context("workflow planning failed")?
+        else {
+            self.stage(trace_id, "planning", "workflow.planning.empty", json!({}));
+            return Ok(WorkflowReport {
+                trace_id,
+                goal: goal.to_string(),
+                snippets: snippets.len(),
+                verdict,
+                objective: None,
+                submitted: Vec::new(),
+                outcomes: Vec::new(),
+            });
+        };
+        let schedule = self.planning.build_tactical_schedule(&plan)?;
+        self.stage(
+            trace_id,
+            "planning",
+            "workflow.planning.scheduled",
+            json!({
+                "objective": plan.objective.description,
+                "phases": plan.phases.len(),
+                "tasks": schedule.tasks.len()
+            }),
+        );
+
+        let mut submitted = Vec::new();
+        let mut handles = Vec::new();
+        for task in schedule.tasks.iter().take(MAX_DISPATCHED_TASKS) {
+            let payload = ActionPayload::textual(
+                task.description.clone(),
+                format!("{} [{}] for goal: {goal}", task.phase_label, task.owner),
+            );
+            let request = ActionRequest::builder(
+                ActionDomain::Infrastructure,
+                ActionIntent::Execute,
+                payload,
+            )
+            .metadata(
+                ActionMetadata::default()
+                    .with_tag("workflow")
+                    .with_tag(format!("trace:{trace_id}")),
+            )
+            .build();
+            let action_id = request.id;
+            match self.actions.submit(request).await {
+                Ok(handle) => {
+                    self.stage(
+                        trace_id,
+                        "actions",
+                        "workflow.action.submitted",
+                        json!({ "action_id": action_id, "task_id": task.id }),
+                    );
+                    submitted.push(action_id);
+                    handles.push(handle);
+                }
+                Err(err) => self.stage(
+                    trace_id,
+                    "actions",
+                    "workflow.action.rejected",
+                    json!({ "task_id": task.id, "error": err.to_string() }),
+                ),
+            }
+        }
+
+        let mut outcomes = Vec::with_capacity(handles.len());
+        for handle in handles {
+            outcomes.push(handle.report().await);
+        }
+        self.stage(
+            trace_id,
+            "actions",
+            "workflow.completed",
+            json!({
+                "submitted": submitted.len(),
+                "succeeded": outcomes.iter().filter(|o| o.is_success()).count()
+            }),
+        );
+
+        Ok(WorkflowReport {
+            trace_id,
+            goal: goal.to_string(),
+            snippets: snippets.len(),
+            verdict,
+            objective: Some(plan.objective.description),
+            submitted,
+            outcomes,
+        })
+    }
+
+    fn stage(&self, trace_id: Uuid, module: &str, signal: &str, mut payload: Value) {
+        if let Value::Object(map) = &mut payload {
+            map.insert("trace_id".into(), json!(trace_id));
+        }
+        self.hub.publish(module, signal, payload);
+    }
+}
+
+fn signal_packets(goal: &str, snippets: &[KnowledgeSnippet]) -> Vec<SignalPacket> {
+    let mut signals = vec![SignalPacket::new("workflow.goal", json!({ "goal": goal }))];
+    signals.extend(snippets.iter().map(|snippet| {
+        SignalPacket::new(
+            snippet.excerpt.clone(),
+            json!({ "record_id": snippet.record_id, "score": snippet.score }),
+        )
+    }));
+    signals
+}
+
+fn planning_directives(goal: &str, verdict: &Verdict) -> Vec<PlanningDirective> {
+    let confidence = verdict.hypothesis.as_ref().map_or(0.0, |h| h.confidence);
+    let priority = if confidence >= 0.75 {
+        PriorityBand::High
+    } else if confidence >= 0.5 {
+        PriorityBand::Medium
+    } else {
+        PriorityBand::Low
+    };
+    let signal = verdict.hypothesis.as_ref().map(|hypothesis| {
+        PlanningSignal::new(
+            hypothesis.summary.clone(),
+            (hypothesis.confidence.clamp(0.0, 1.0) * 100.0).round() as u8,
+        )
+    });
+    vec![PlanningDirective {
+        signal,
+        priority,
+        objective: goal.to_string(),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zappy_knowledge::KnowledgeArtifact;
+    use zappy_planning::{LongTermPlanner, ShortTermPlanner};
+
+    #[tokio::test]
+    async fn toy_goal_runs_full_chain() {
+        let knowledge = KnowledgeRuntime::bootstrap();
+        knowledge
+            .ingest(KnowledgeArtifact::new(
+                "runbook",
+                "Cache warmup",
+                "Warm the edge cache before traffic peaks to keep latency low.",
+            ))
+            .unwrap();
+        let reasoning = ReasoningRuntime::default();
+        let mut planning = PlanningRuntime::new(
+            LongTermPlanner::default(),
+            ShortTermPlanner::default(),
+            None,
+        );
+        let actions = ActionCommander::builder().build();
+        let hub = ExperienceHub::new(64);
+
+        let report =
+            WorkflowOrchestrator::new(&knowledge, &reasoning, &mut planning, &actions, &hub)
+                .run("warm the edge cache")
+                .await
+                .unwrap();
+
+        assert!(report.snippets > 0);
+        assert!(!report.submitted.is_empty());
+        assert_eq!(report.outcomes.len(), report.submitted.len());
+        let events = hub.snapshot(64);
+        assert!(events
+            .iter()
+            .any(|event| event.signal == "workflow.action.submitted"));
+        assert!(events
+            .iter()
+            .all(|event| event.payload["trace_id"] == json!(report.trace_id)));
+    }
+}
@@ -3,7 +3,7 @@ use indexmap::IndexMap;
 
 use super::{
     advmodel::PredictiveModel,
-    reviewer::StateReviewer,
+    reviewer::{EscalationRule, ReviewOutcome, StateReviewer},
     train::{Trainer, TrainingArtifact, TrainingConfig},
 };
 use crate::{model::WorldState, telemetry::WorldTelemetry};
@@ -20,16 +20,35 @@ impl AdvancedController {
     /// Creates a controller from baseline metrics.
     #[must_use]
     pub fn new(baseline: IndexMap<String, f32>, telemetry: Option<WorldTelemetry>) -> Self {
+        Self::from_model(PredictiveModel::new(baseline), telemetry)
+    }
+
+    /// Creates a controller around an existing predictive model (e.g. from a checkpoint).
+    #[must_use]
+    pub fn from_model(model: PredictiveModel, telemetry: Option<WorldTelemetry>) -> Self {
         let reviewer = StateReviewer::new(telemetry.clone());
         let trainer = Trainer::new(telemetry.clone());
         Self {
-            model: PredictiveModel::new(baseline),
+            model,
             reviewer,
             trainer,
             telemetry,
         }
     }
 
+    /// Installs declarative escalation rules on the reviewer.
+    #[must_use]
+    pub fn with_escalation_rules(mut self, rules: Vec<EscalationRule>) -> Self {
+        self.reviewer = self.reviewer.with_rules(rules);
+        self
+    }
+
+    /// Returns the predictive model.
+    #[must_use]
+    pub const fn model(&self) -> &PredictiveModel {
+        &self.model
+    }
+
     /// Scores incoming metrics for a region.
     pub fn score_metrics(&mut self, metrics: &IndexMap<String, f32>) -> f32 {
         let score = self.model.update(metrics);
@@ -37,15 +56,18 @@ impl AdvancedController {
             let _ = tel.log(
                 shared_logging::LogLevel::Debug,
                 "world.advanced.score",
-                serde_json::json!({ "score": score }),
+                serde_json::json!({
+                    "score": score,
+                    "feature_importance": self.model.feature_importance(),
+                }),
             );
         }
         score
     }
 
-    /// Reviews full world state and returns whether action is required.
-    pub fn review_state(&self, state: &WorldState) -> Result<bool> {
-        self.reviewer.review(state)
+    /// Reviews full world state, returning the escalation outcome.
+    pub fn review_state(&self, state: &WorldState) -> Result<ReviewOutcome> {
+        self.reviewer.review_state(state)
     }
 
     /// Launches offline training.
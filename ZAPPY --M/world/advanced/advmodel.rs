@@ -13,6 +13,12 @@ pub struct PredictiveModel {
     pub baseline: IndexMap<String, f32>,
     /// History window.
     pub history: Vec<f32>,
+    /// Accumulated absolute deviation from baseline per metric.
+    #[serde(default)]
+    pub contributions: IndexMap<String, f32>,
+    /// Number of updates that reported each metric.
+    #[serde(default)]
+    pub observations: IndexMap<String, u32>,
 }
 
 impl PredictiveModel {
@@ -23,12 +29,19 @@ impl PredictiveModel {
             model_id: Uuid::new_v4(),
             baseline,
             history: Vec::new(),
+            contributions: IndexMap::new(),
+            observations: IndexMap::new(),
         }
     }
 
     /// Updates the model with new measurements and returns risk score.
     pub fn update(&mut self, metrics: &IndexMap<String, f32>) -> f32 {
         let score = anomaly_score(metrics, &self.baseline);
+        for (key, value) in metrics {
+            let base = self.baseline.get(key).copied().unwrap_or(*value);
+            *self.contributions.entry(key.clone()).or_default() += (value - base).abs();
+            *self.observations.entry(key.clone()).or_default() += 1;
+        }
         self.history.push(score);
         if self.history.len() > 64 {
             self.history.remove(0);
@@ -42,6 +55,36 @@ impl PredictiveModel {
         let smoothed = ewma(&self.history, 0.3);
         smoothed.last().copied().unwrap_or(0.0)
     }
+
+    /// Returns normalized per-metric importances summing to 1.0.
+    ///
+    /// The anomaly score is a linear sum of absolute deviations from the baseline, so a
+    /// metric's importance is its mean deviation per observation (coefficient magnitude)
+    /// relative to the total; metrics reported less often are not penalised for it. The map
+    /// is empty until an update has been observed, and every observed metric scores 0.0
+    /// while none has deviated. Entries are sorted from most to least important.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn feature_importance(&self) -> IndexMap<String, f32> {
+        let means: IndexMap<String, f32> = self
+            .contributions
+            .iter()
+            .map(|(key, total)| {
+                let count = self.observations.get(key).copied().unwrap_or(1).max(1);
+                (key.clone(), total / count as f32)
+            })
+            .collect();
+        let total: f32 = means.values().sum();
+        if total <= f32::EPSILON {
+            return means.into_keys().map(|key| (key, 0.0)).collect();
+        }
+        let mut importances: IndexMap<String, f32> = means
+            .into_iter()
+            .map(|(key, mean)| (key, mean / total))
+            .collect();
+        importances.sort_by(|_, a, _, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        importances
+    }
 }
 
 #[cfg(test)]
@@ -58,4 +101,63 @@ mod tests {
         model.update(&metrics);
         assert!(model.forecast() >= 0.0);
     }
+
+    #[test]
+    fn feature_importance_ranks_dominant_metric() {
+        let mut baseline = IndexMap::new();
+        baseline.insert("load".into(), 0.4);
+        baseline.insert("demand".into(), 0.4);
+        baseline.insert("latency".into(), 0.2);
+        let mut model = PredictiveModel::new(baseline);
+        for _ in 0..3 {
+            let mut metrics = IndexMap::new();
+            metrics.insert("load".into(), 0.95);
+            metrics.insert("demand".into(), 0.45);
+            metrics.insert("latency".into(), 0.25);
+            model.update(&metrics);
+        }
+
+        let importances = model.feature_importance();
+        let total: f32 = importances.values().sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert_eq!(
+            importances.get_index(0).map(|(k, _)| k.as_str()),
+            Some("load")
+        );
+    }
+
+    #[test]
+    fn feature_importance_is_empty_until_observed() {
+        let mut baseline = IndexMap::new();
+        baseline.insert("load".into(), 0.9);
+        let mut model = PredictiveModel::new(baseline);
+        assert!(model.feature_importance().is_empty());
+
+        let mut metrics = IndexMap::new();
+        metrics.insert("load".into(), 0.9);
+        model.update(&metrics);
+        assert_eq!(model.feature_importance().get("load"), Some(&0.0));
+    }
+
+    #[test]
+    fn feature_importance_averages_over_observations() {
+        let mut model = PredictiveModel::new(IndexMap::new());
+        model.baseline.insert("load".into(), 0.0);
+        model.baseline.insert("latency".into(), 0.0);
+        for step in 0..4 {
+            let mut metrics = IndexMap::new();
+            metrics.insert("load".into(), 0.2);
+            if step == 0 {
+                metrics.insert("latency".into(), 0.4);
+            }
+            model.update(&metrics);
+        }
+
+        let importances = model.feature_importance();
+        assert_eq!(
+            importances.get_index(0).map(|(k, _)| k.as_str()),
+            Some("latency")
+        );
+        assert!((importances["latency"] - 2.0 / 3.0).abs() < 1e-4);
+    }
 }
@@ -13,5 +13,7 @@ pub mod train;
 
 pub use advanced::AdvancedController;
 pub use advmodel::PredictiveModel;
-pub use reviewer::StateReviewer;
-pub use train::{Trainer, TrainingArtifact, TrainingConfig};
+pub use reviewer::{
+    load_escalation_rules, EscalationRule, EscalationSeverity, ReviewOutcome, StateReviewer,
+};
+pub use train::{ModelParameters, Trainer, TrainingArtifact, TrainingConfig, TrainingSample};
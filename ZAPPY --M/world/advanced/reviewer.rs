@@ -1,12 +1,75 @@
-use anyhow::Result;
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{model::WorldState, telemetry::WorldTelemetry};
 
+/// Severity assigned to a reviewed world state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EscalationSeverity {
+    /// No escalation needed.
+    Normal,
+    /// Operators should take a look.
+    Warning,
+    /// Immediate action required.
+    Critical,
+}
+
+/// Declarative rule: escalate when a region accumulates too many anomalies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscalationRule {
+    /// Rule name reported when it fires.
+    pub name: String,
+    /// Region the rule applies to; `None` matches every region.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Fires when the region's anomaly count is strictly greater than this.
+    pub anomaly_count_above: usize,
+    /// Severity assigned when the rule fires.
+    pub severity: EscalationSeverity,
+}
+
+#[derive(Debug, Deserialize)]
+struct EscalationRulesDocument {
+    #[serde(default)]
+    rules: Vec<EscalationRule>,
+}
+
+/// Loads escalation rules from a TOML file containing `[[rules]]` tables.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is not a valid rules document.
+pub fn load_escalation_rules(path: impl AsRef<Path>) -> Result<Vec<EscalationRule>> {
+    let path = path.as_ref();
+    let raw = fs::read_to_string(path)
+        .with_context(|| format!("reading escalation rules {}", path.display()))?;
+    let document: EscalationRulesDocument =
+        toml::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+    Ok(document.rules)
+}
+
+/// Outcome of a state review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewOutcome {
+    /// Whether operators or automation must act.
+    pub action_required: bool,
+    /// Highest severity reached.
+    pub severity: EscalationSeverity,
+    /// Rule that produced the severity, if any.
+    pub matched_rule: Option<EscalationRule>,
+    /// Region the matched rule fired for.
+    pub region: Option<String>,
+}
+
 /// Reviews world states and emits governance alerts.
 pub struct StateReviewer {
     telemetry: Option<WorldTelemetry>,
     critical_threshold: f32,
+    rules: Vec<EscalationRule>,
 }
 
 impl StateReviewer {
@@ -16,25 +79,121 @@ impl StateReviewer {
         Self {
             telemetry,
             critical_threshold: 1.1,
+            rules: Vec::new(),
         }
     }
 
+    /// Replaces the escalation rules.
+    #[must_use]
+    pub fn with_rules(mut self, rules: Vec<EscalationRule>) -> Self {
+        self.rules = rules;
+        self
+    }
+
     /// Reviews state and returns whether action is needed.
     pub fn review(&self, state: &WorldState) -> Result<bool> {
-        let decision = state
+        Ok(self.review_state(state)?.action_required)
+    }
+
+    /// Reviews state against the severity threshold and escalation rules.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible; the `Result` mirrors [`Self::review`].
+    pub fn review_state(&self, state: &WorldState) -> Result<ReviewOutcome> {
+        let mut outcome = ReviewOutcome {
+            action_required: false,
+            severity: EscalationSeverity::Normal,
+            matched_rule: None,
+            region: None,
+        };
+        if state
             .highest_severity()
-            .map(|anom| anom.severity >= self.critical_threshold)
-            .unwrap_or(false);
+            .is_some_and(|anom| anom.severity >= self.critical_threshold)
+        {
+            outcome.action_required = true;
+            outcome.severity = EscalationSeverity::Critical;
+        }
+
+        for region in state.regions.keys() {
+            let count = state
+                .anomalies
+                .iter()
+                .filter(|anom| &anom.region_id == region)
+                .count();
+            for rule in &self.rules {
+                let applies = rule.region.as_ref().is_none_or(|target| target == region);
+                if applies && count > rule.anomaly_count_above && rule.severity > outcome.severity {
+                    outcome.severity = rule.severity;
+                    outcome.matched_rule = Some(rule.clone());
+                    outcome.region = Some(region.clone());
+                }
+            }
+        }
+        outcome.action_required |= outcome.severity == EscalationSeverity::Critical;
+
         if let Some(tel) = &self.telemetry {
             let _ = tel.event(
                 "world.state.reviewed",
                 json!({
                     "regions": state.regions.len(),
                     "anomalies": state.anomalies.len(),
-                    "action_required": decision
+                    "action_required": outcome.action_required,
+                    "severity": outcome.severity,
+                    "rule": outcome.matched_rule.as_ref().map(|rule| rule.name.clone()),
+                    "region": outcome.region,
                 }),
             );
         }
-        Ok(decision)
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AnomalyEvent, RegionSnapshot};
+    use indexmap::IndexMap;
+    use tempfile::tempdir;
+
+    #[test]
+    fn escalates_high_anomaly_region() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("escalation.toml");
+        fs::write(
+            &path,
+            r#"
+[[rules]]
+name = "hot-alpha"
+region = "alpha"
+anomaly_count_above = 2
+severity = "critical"
+
+[[rules]]
+name = "noisy-any"
+anomaly_count_above = 0
+severity = "warning"
+"#,
+        )
+        .unwrap();
+        let reviewer = StateReviewer::new(None).with_rules(load_escalation_rules(&path).unwrap());
+
+        let mut state = WorldState::default();
+        for region in ["alpha", "beta"] {
+            state.apply_snapshot(RegionSnapshot::from_metrics(region, IndexMap::new()));
+        }
+        state.record_anomaly(AnomalyEvent::new("beta", 0.9, json!({})));
+        let outcome = reviewer.review_state(&state).unwrap();
+        assert_eq!(outcome.severity, EscalationSeverity::Warning);
+        assert!(!outcome.action_required);
+
+        for _ in 0..3 {
+            state.record_anomaly(AnomalyEvent::new("alpha", 0.9, json!({})));
+        }
+        let outcome = reviewer.review_state(&state).unwrap();
+        assert_eq!(outcome.severity, EscalationSeverity::Critical);
+        assert_eq!(outcome.matched_rule.unwrap().name, "hot-alpha");
+        assert_eq!(outcome.region.as_deref(), Some("alpha"));
+        assert!(outcome.action_required);
     }
 }
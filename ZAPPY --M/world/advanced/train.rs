@@ -1,6 +1,11 @@
-use std::{fs, path::PathBuf, time::Duration};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use anyhow::{Context, Result};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tokio::time::sleep;
@@ -8,6 +13,15 @@ use uuid::Uuid;
 
 use crate::telemetry::WorldTelemetry;
 
+/// One labelled observation read from a JSONL training dataset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingSample {
+    /// Metric values keyed by name.
+    pub metrics: IndexMap<String, f32>,
+    /// Observed risk the model should predict.
+    pub risk: f32,
+}
+
 /// Training configuration for predictive models.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingConfig {
@@ -15,6 +29,114 @@ pub struct TrainingConfig {
     pub dataset_path: PathBuf,
     /// Output directory.
     pub output_dir: PathBuf,
+    /// Number of passes over the dataset.
+    #[serde(default = "default_epochs")]
+    pub epochs: u32,
+    /// Writes an intermediate checkpoint every N epochs when set.
+    #[serde(default)]
+    pub checkpoint_every: Option<u32>,
+    /// Checkpoint to continue training from.
+    #[serde(default)]
+    pub resume_from: Option<PathBuf>,
+    /// Step size for gradient descent.
+    #[serde(default = "default_learning_rate")]
+    pub learning_rate: f32,
+}
+
+const fn default_epochs() -> u32 {
+    1
+}
+
+const fn default_learning_rate() -> f32 {
+    0.05
+}
+
+impl TrainingConfig {
+    /// Creates a single-epoch config without checkpointing.
+    #[must_use]
+    pub fn new(dataset_path: impl Into<PathBuf>, output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dataset_path: dataset_path.into(),
+            output_dir: output_dir.into(),
+            epochs: default_epochs(),
+            checkpoint_every: None,
+            resume_from: None,
+            learning_rate: default_learning_rate(),
+        }
+    }
+
+    /// Sets the number of epochs.
+    #[must_use]
+    pub const fn with_epochs(mut self, epochs: u32) -> Self {
+        self.epochs = epochs;
+        self
+    }
+
+    /// Enables periodic checkpointing.
+    #[must_use]
+    pub const fn with_checkpoint_every(mut self, epochs: u32) -> Self {
+        self.checkpoint_every = Some(epochs);
+        self
+    }
+
+    /// Resumes from a previously written checkpoint.
+    #[must_use]
+    pub fn with_resume_from(mut self, path: impl Into<PathBuf>) -> Self {
+        self.resume_from = Some(path.into());
+        self
+    }
+
+    /// Overrides the gradient descent step size.
+    #[must_use]
+    pub const fn with_learning_rate(mut self, learning_rate: f32) -> Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+}
+
+/// Linear risk model parameters: one weight per metric plus a bias.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelParameters {
+    /// Per-metric weights.
+    pub weights: IndexMap<String, f32>,
+    /// Intercept term.
+    pub bias: f32,
+}
+
+impl ModelParameters {
+    /// Predicts risk for a set of metrics; unknown metrics contribute nothing.
+    #[must_use]
+    pub fn predict(&self, metrics: &IndexMap<String, f32>) -> f32 {
+        self.bias
+            + metrics
+                .iter()
+                .map(|(key, value)| self.weights.get(key).copied().unwrap_or(0.0) * value)
+                .sum::<f32>()
+    }
+}
+
+const MOMENTUM: f32 = 0.9;
+
+/// Momentum SGD state carried across epochs and checkpoints.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct OptimizerState {
+    velocity: IndexMap<String, f32>,
+    bias_velocity: f32,
+    steps: u64,
+}
+
+impl OptimizerState {
+    fn step(&mut self, params: &mut ModelParameters, sample: &TrainingSample, learning_rate: f32) {
+        let error = params.predict(&sample.metrics) - sample.risk;
+        for (key, value) in &sample.metrics {
+            let velocity = self.velocity.entry(key.clone()).or_default();
+            *velocity = MOMENTUM.mul_add(*velocity, error * value);
+            *params.weights.entry(key.clone()).or_default() -= learning_rate * *velocity;
+        }
+        self.bias_velocity = MOMENTUM.mul_add(self.bias_velocity, error);
+        params.bias -= learning_rate * self.bias_velocity;
+        self.steps += 1;
+    }
 }
 
 /// Result after training completes.
@@ -24,6 +146,22 @@ pub struct TrainingArtifact {
     pub artifact_id: Uuid,
     /// Location of exported model.
     pub artifact_path: PathBuf,
+    /// Intermediate checkpoints this artifact descends from, oldest first.
+    #[serde(default)]
+    pub checkpoints: Vec<PathBuf>,
+    /// Trained model parameters.
+    #[serde(default)]
+    pub parameters: ModelParameters,
+}
+
+/// Intermediate state written while training.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrainingCheckpoint {
+    epoch: u32,
+    lineage: Vec<PathBuf>,
+    timestamp: String,
+    parameters: ModelParameters,
+    optimizer: OptimizerState,
 }
 
 /// Handles offline training for world predictive models.
@@ -39,35 +177,175 @@ impl Trainer {
     }
 
     /// Runs training job asynchronously.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dataset or resume checkpoint cannot be read or parsed, or
+    /// if checkpoints or the final artifact cannot be written.
     pub async fn train(&self, config: TrainingConfig) -> Result<TrainingArtifact> {
         if let Some(tel) = &self.telemetry {
             let _ = tel.log(
                 shared_logging::LogLevel::Info,
                 "world.training.start",
-                json!({ "dataset": config.dataset_path }),
+                json!({ "dataset": config.dataset_path, "resume_from": config.resume_from }),
             );
         }
-        sleep(Duration::from_millis(50)).await;
         fs::create_dir_all(&config.output_dir)
             .with_context(|| format!("creating {:?}", config.output_dir))?;
+
+        let samples = load_samples(&config.dataset_path)?;
+
+        let (mut epoch, mut lineage, mut parameters, mut optimizer) = match &config.resume_from {
+            Some(path) => {
+                let data = fs::read(path)
+                    .with_context(|| format!("reading checkpoint {}", path.display()))?;
+                let checkpoint: TrainingCheckpoint = serde_json::from_slice(&data)
+                    .with_context(|| format!("parsing checkpoint {}", path.display()))?;
+                (
+                    checkpoint.epoch,
+                    checkpoint.lineage,
+                    checkpoint.parameters,
+                    checkpoint.optimizer,
+                )
+            }
+            None => (
+                0,
+                Vec::new(),
+                ModelParameters::default(),
+                OptimizerState::default(),
+            ),
+        };
+
+        sleep(Duration::from_millis(50)).await;
+        while epoch < config.epochs {
+            epoch += 1;
+            for sample in &samples {
+                optimizer.step(&mut parameters, sample, config.learning_rate);
+            }
+            tokio::task::yield_now().await;
+            let due = config
+                .checkpoint_every
+                .is_some_and(|every| every > 0 && epoch % every == 0);
+            if due && epoch < config.epochs {
+                let path = config
+                    .output_dir
+                    .join(format!("checkpoint-epoch-{epoch:04}.json"));
+                lineage.push(path.clone());
+                let checkpoint = TrainingCheckpoint {
+                    epoch,
+                    lineage: lineage.clone(),
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                    parameters: parameters.clone(),
+                    optimizer: optimizer.clone(),
+                };
+                fs::write(&path, serde_json::to_vec_pretty(&checkpoint)?)
+                    .with_context(|| format!("writing checkpoint {}", path.display()))?;
+                if let Some(tel) = &self.telemetry {
+                    let _ = tel.event(
+                        "world.training.checkpoint",
+                        json!({ "epoch": epoch, "path": path }),
+                    );
+                }
+            }
+        }
+
         let artifact_path = config.output_dir.join("world-model.json");
         fs::write(
             &artifact_path,
             serde_json::to_vec_pretty(&json!({
                 "model": "predictive",
                 "timestamp": chrono::Utc::now().to_rfc3339(),
+                "epochs": epoch,
+                "lineage": lineage,
+                "parameters": parameters,
             }))?,
         )?;
         let artifact = TrainingArtifact {
             artifact_id: Uuid::new_v4(),
             artifact_path,
+            checkpoints: lineage,
+            parameters,
         };
         if let Some(tel) = &self.telemetry {
             let _ = tel.event(
                 "world.training.completed",
-                json!({ "artifact": artifact.artifact_path }),
+                json!({ "artifact": artifact.artifact_path, "checkpoints": artifact.checkpoints }),
             );
         }
         Ok(artifact)
     }
 }
+
+fn load_samples(path: &Path) -> Result<Vec<TrainingSample>> {
+    let data =
+        fs::read_to_string(path).with_context(|| format!("reading dataset {}", path.display()))?;
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("parsing {} line {}", path.display(), idx + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_dataset(path: &Path) {
+        let lines: Vec<String> = [(0.2, 0.1, 0.25), (0.8, 0.3, 0.75), (0.5, 0.9, 0.6)]
+            .iter()
+            .map(|(load, latency, risk)| {
+                json!({ "metrics": { "load": load, "latency": latency }, "risk": risk }).to_string()
+            })
+            .collect();
+        fs::write(path, lines.join("\n")).unwrap();
+    }
+
+    #[tokio::test]
+    async fn writes_intermediate_checkpoints() {
+        let dir = tempdir().unwrap();
+        let dataset = dir.path().join("data.jsonl");
+        write_dataset(&dataset);
+        let trainer = Trainer::new(None);
+        let config = TrainingConfig::new(&dataset, dir.path().join("out"))
+            .with_epochs(6)
+            .with_checkpoint_every(2);
+
+        let artifact = trainer.train(config).await.unwrap();
+        assert_eq!(artifact.checkpoints.len(), 2);
+        for path in &artifact.checkpoints {
+            assert!(path.exists());
+        }
+        assert!(artifact.artifact_path.exists());
+        assert!(artifact.parameters.weights.values().any(|w| *w != 0.0));
+
+        let saved: TrainingCheckpoint =
+            serde_json::from_slice(&fs::read(&artifact.checkpoints[0]).unwrap()).unwrap();
+        assert_eq!(saved.epoch, 2);
+        assert!(saved.optimizer.steps > 0);
+        assert_ne!(saved.parameters, artifact.parameters);
+
+        let resumed = trainer
+            .train(
+                TrainingConfig::new(&dataset, dir.path().join("resumed"))
+                    .with_epochs(6)
+                    .with_checkpoint_every(2)
+                    .with_resume_from(&artifact.checkpoints[0]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resumed.checkpoints[0], artifact.checkpoints[0]);
+        assert_eq!(resumed.checkpoints.len(), 2);
+        assert_eq!(resumed.parameters, artifact.parameters);
+    }
+
+    #[tokio::test]
+    async fn missing_dataset_is_an_error() {
+        let dir = tempdir().unwrap();
+        let config = TrainingConfig::new(dir.path().join("absent.jsonl"), dir.path().join("out"));
+        assert!(Trainer::new(None).train(config).await.is_err());
+    }
+}
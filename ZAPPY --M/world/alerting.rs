@@ -0,0 +1,224 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::Mutex;
+use reqwest::Client;
+use serde_json::{json, Value};
+use shared_logging::LogLevel;
+use tokio::{runtime::Handle, time::sleep};
+
+use crate::telemetry::WorldTelemetry;
+
+/// Posts world alerts to an HTTP webhook, suppressing repeats per region.
+#[derive(Debug)]
+pub struct WebhookAlertSink {
+    url: String,
+    client: Client,
+    dedup_window: Duration,
+    max_attempts: u32,
+    backoff: Duration,
+    dedup: Mutex<DedupState>,
+}
+
+/// Settles an admitted region when dropped, so an aborted or panicking delivery
+/// releases its slot instead of suppressing the region forever.
+struct InFlightSlot {
+    sink: Arc<WebhookAlertSink>,
+    region: String,
+    delivered: bool,
+}
+
+impl Drop for InFlightSlot {
+    fn drop(&mut self) {
+        self.sink.settle(&self.region, self.delivered);
+    }
+}
+
+/// Regions with a delivery in flight and the time of each region's last delivered alert.
+#[derive(Debug, Default)]
+struct DedupState {
+    in_flight: IndexSet<String>,
+    last_sent: IndexMap<String, Instant>,
+}
+
+impl WebhookAlertSink {
+    /// Creates a sink posting to `url` with a 5 minute dedup window and 3 attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the HTTP client cannot be built.
+    pub fn new(url: impl Into<String>) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent("zappy-world/alpha")
+            .timeout(Duration::from_secs(10))
+            .build()?;
+        Ok(Self {
+            url: url.into(),
+            client,
+            dedup_window: Duration::from_mins(5),
+            max_attempts: 3,
+            backoff: Duration::from_millis(200),
+            dedup: Mutex::new(DedupState::default()),
+        })
+    }
+
+    /// Suppresses alerts for the same region within `window`.
+    #[must_use]
+    pub const fn dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// Retries failed deliveries up to `max_attempts`, doubling `backoff` each time.
+    #[must_use]
+    pub fn retries(mut self, max_attempts: u32, backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.backoff = backoff;
+        self
+    }
+
+    /// Claims a delivery slot for `region`, returning false if an alert is in flight or
+    /// was delivered within the window. Pair every admitted alert with [`Self::settle`].
+    pub fn admit(&self, region: &str) -> bool {
+        let mut dedup = self.dedup.lock();
+        let recent = dedup
+            .last_sent
+            .get(region)
+            .is_some_and(|sent| sent.elapsed() < self.dedup_window);
+        if recent || dedup.in_flight.contains(region) {
+            return false;
+        }
+        dedup.in_flight.insert(region.to_string());
+        drop(dedup);
+        true
+    }
+
+    /// Releases the slot claimed by [`Self::admit`], starting the dedup window only when
+    /// the alert was delivered so failed deliveries do not suppress the next alert.
+    pub fn settle(&self, region: &str, delivered: bool) {
+        let mut dedup = self.dedup.lock();
+        dedup.in_flight.shift_remove(region);
+        if delivered {
+            dedup.last_sent.insert(region.to_string(), Instant::now());
+        }
+    }
+
+    /// POSTs the payload, retrying non-success responses and transport errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last transport error or non-success status once every attempt failed.
+    pub async fn deliver(&self, payload: &Value) -> Result<()> {
+        let mut delay = self.backoff;
+        let mut last_error = anyhow!("no delivery attempted");
+        for attempt in 1..=self.max_attempts {
+            match self.client.post(&self.url).json(payload).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => last_error = anyhow!("webhook returned {}", response.status()),
+                Err(err) => last_error = err.into(),
+            }
+            if attempt < self.max_attempts {
+                sleep(delay).await;
+                delay = delay.saturating_mul(2);
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Delivers in the background unless `region` alerted within the dedup window.
+    pub(crate) fn dispatch(
+        self: &Arc<Self>,
+        region: &str,
+        payload: Value,
+        telemetry: Option<WorldTelemetry>,
+    ) {
+        let Ok(handle) = Handle::try_current() else {
+            if let Some(tel) = &telemetry {
+                let _ = tel.log(
+                    LogLevel::Warn,
+                    "world.alert.webhook_skipped",
+                    json!({ "region": region, "reason": "no async runtime" }),
+                );
+            }
+            return;
+        };
+        if !self.admit(region) {
+            if let Some(tel) = &telemetry {
+                let _ = tel.log(
+                    LogLevel::Debug,
+                    "world.alert.webhook_suppressed",
+                    json!({ "region": region }),
+                );
+            }
+            return;
+        }
+        let mut slot = InFlightSlot {
+            sink: Arc::clone(self),
+            region: region.to_string(),
+            delivered: false,
+        };
+        handle.spawn(async move {
+            let delivered = slot.sink.deliver(&payload).await;
+            slot.delivered = delivered.is_ok();
+            let region = slot.region.clone();
+            drop(slot);
+            if let Err(err) = delivered {
+                if let Some(tel) = &telemetry {
+                    let _ = tel.log(
+                        LogLevel::Warn,
+                        "world.alert.webhook_failed",
+                        json!({ "region": region, "error": err.to_string() }),
+                    );
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_deliveries_do_not_start_the_dedup_window() {
+        let sink = WebhookAlertSink::new("http://127.0.0.1:9/alerts").unwrap();
+        assert!(sink.admit("alpha"));
+        assert!(!sink.admit("alpha"));
+
+        sink.settle("alpha", false);
+        assert!(sink.admit("alpha"));
+
+        sink.settle("alpha", true);
+        assert!(!sink.admit("alpha"));
+        assert!(sink.admit("beta"));
+    }
+
+    #[test]
+    fn dispatch_without_a_runtime_leaves_the_region_admissible() {
+        let sink = Arc::new(WebhookAlertSink::new("http://127.0.0.1:9/alerts").unwrap());
+        sink.dispatch("alpha", json!({ "region": "alpha" }), None);
+        assert!(sink.admit("alpha"));
+    }
+
+    #[tokio::test]
+    async fn aborted_deliveries_release_their_slot() {
+        let sink = Arc::new(WebhookAlertSink::new("http://127.0.0.1:9/alerts").unwrap());
+        assert!(sink.admit("alpha"));
+        let slot = InFlightSlot {
+            sink: Arc::clone(&sink),
+            region: "alpha".into(),
+            delivered: false,
+        };
+        let task = tokio::spawn(async move {
+            let _slot = slot;
+            std::future::pending::<()>().await;
+        });
+        task.abort();
+        let _ = task.await;
+        assert!(sink.admit("alpha"));
+    }
+}
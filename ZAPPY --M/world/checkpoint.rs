@@ -0,0 +1,103 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{advanced::PredictiveModel, model::WorldState};
+
+/// Current on-disk checkpoint format version.
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Persisted snapshot of the world runtime used to resume after a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldCheckpoint {
+    /// Format version; checkpoints from other versions are rejected.
+    pub version: u32,
+    /// Time the checkpoint was taken.
+    pub created_at: DateTime<Utc>,
+    /// Assimilated regions and anomaly timeline.
+    pub state: WorldState,
+    /// Predictive model including baseline and score history.
+    pub model: PredictiveModel,
+}
+
+impl WorldCheckpoint {
+    /// Creates a checkpoint at the current version.
+    #[must_use]
+    pub fn new(state: WorldState, model: PredictiveModel) -> Self {
+        Self {
+            version: CHECKPOINT_VERSION,
+            created_at: Utc::now(),
+            state,
+            model,
+        }
+    }
+
+    /// Writes the checkpoint atomically (temp file, fsync, rename).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or temp file cannot be created, the write or
+    /// fsync fails, or the rename cannot commit it; `path` is left untouched on failure.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("creating checkpoint dir {}", parent.display()))?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut file = File::create(&tmp_path)
+                .with_context(|| format!("creating {}", tmp_path.display()))?;
+            file.write_all(&serde_json::to_vec_pretty(self)?)?;
+            file.sync_all()?;
+        }
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("committing checkpoint {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Loads a checkpoint, rejecting unknown format versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if its version differs
+    /// from the current checkpoint format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+        let checkpoint: Self = serde_json::from_slice(&data)
+            .with_context(|| format!("parsing checkpoint {}", path.display()))?;
+        if checkpoint.version != CHECKPOINT_VERSION {
+            bail!(
+                "unsupported checkpoint version {} (expected {CHECKPOINT_VERSION})",
+                checkpoint.version
+            );
+        }
+        Ok(checkpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use tempfile::tempdir;
+
+    #[test]
+    fn rejects_unknown_version() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("world.ckpt.json");
+        let mut checkpoint =
+            WorldCheckpoint::new(WorldState::default(), PredictiveModel::new(IndexMap::new()));
+        checkpoint.version = CHECKPOINT_VERSION + 1;
+        checkpoint.save(&path).unwrap();
+        assert!(WorldCheckpoint::load(&path).is_err());
+        assert!(!path.with_extension("tmp").exists());
+    }
+}
@@ -1,26 +1,288 @@
 use std::{
-    fs::{self, OpenOptions},
-    io::Write,
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
+use indexmap::IndexMap;
 use parking_lot::Mutex;
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use uuid::Uuid;
 
-use crate::{infoseeker::InfoSignal, learning::AssimilationJob};
+use crate::{infoseeker::InfoSignal, learning::AssimilationJob, normalization::MetricStats};
 
-/// File-backed feature store for replaying world signals.
-#[derive(Debug)]
+/// Signal as persisted by [`FeatureStore::persist_signals`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredSignal {
+    /// Batch the signal was collected in.
+    pub batch_id: Uuid,
+    /// Batch timestamp.
+    pub ts: DateTime<Utc>,
+    /// Region impacted.
+    pub region: String,
+    /// Severity estimate.
+    pub severity: f32,
+    /// Metric payload.
+    pub metrics: Value,
+}
+
+/// Time index entry locating one persisted batch inside a segment file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SegmentEntry {
+    /// Batch identifier.
+    pub batch_id: Uuid,
+    /// Batch timestamp.
+    pub ts: DateTime<Utc>,
+    /// Segment file name, relative to the segment directory.
+    pub file: String,
+    /// Byte offset of the batch within the segment.
+    pub offset: u64,
+    /// Length of the batch in bytes.
+    pub len: u64,
+}
+
+/// Storage behind a [`FeatureStore`].
+pub trait FeatureBackend: Send + Sync {
+    /// Stores one signal batch observed at `ts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be written durably.
+    fn persist_signals(
+        &self,
+        batch_id: &Uuid,
+        ts: DateTime<Utc>,
+        signals: &[StoredSignal],
+    ) -> Result<()>;
+
+    /// Stores an aggregation job record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be written.
+    fn persist_job(&self, record: &Value) -> Result<()>;
+
+    /// Signals persisted with `from <= ts <= to`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stored batches cannot be read or decoded.
+    fn signals_in_range(&self, from: DateTime<Utc>, to: DateTime<Utc>)
+        -> Result<Vec<StoredSignal>>;
+
+    /// Segment index entries for batches in range; empty for backends without segments.
+    fn entries_in_range(&self, _from: DateTime<Utc>, _to: DateTime<Utc>) -> Vec<SegmentEntry> {
+        Vec::new()
+    }
+
+    /// Merges stored batches into `bucket`-sized segments, returning files removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if segments or the index cannot be rewritten; the default
+    /// implementation never fails.
+    fn compact(&self, _bucket: Duration) -> Result<usize> {
+        Ok(0)
+    }
+
+    /// Location on disk, if the backend has one.
+    fn path(&self) -> Option<&Path> {
+        None
+    }
+}
+
+/// Feature store for replaying world signals.
+///
+/// Persistence goes through a [`FeatureBackend`]; [`Self::open`] uses the
+/// [`FileFeatureBackend`], and a disabled store drops every write.
 pub struct FeatureStore {
-    path: Option<PathBuf>,
-    writer: Option<Mutex<std::fs::File>>,
+    backend: Option<Box<dyn FeatureBackend>>,
+}
+
+impl fmt::Debug for FeatureStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeatureStore")
+            .field("enabled", &self.backend.is_some())
+            .field("path", &self.path())
+            .finish()
+    }
 }
 
 impl FeatureStore {
-    /// Opens (or creates) a feature store at the given path.
+    /// Opens (or creates) a file-backed feature store at the given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`FileFeatureBackend::open`] fails.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        Ok(Self::with_backend(FileFeatureBackend::open(path)?))
+    }
+
+    /// Creates a store persisting through `backend`.
+    #[must_use]
+    pub fn with_backend(backend: impl FeatureBackend + 'static) -> Self {
+        Self {
+            backend: Some(Box::new(backend)),
+        }
+    }
+
+    /// Returns a disabled store (no-op writer).
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self { backend: None }
+    }
+
+    /// Persists raw signals for future learning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot store the batch.
+    pub fn persist_signals(&self, batch_id: &Uuid, signals: &[InfoSignal]) -> Result<()> {
+        self.persist_signals_at(batch_id, signals, Utc::now())
+    }
+
+    /// Persists a signal batch observed at `ts`; a disabled store drops it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot store the batch.
+    pub fn persist_signals_at(
+        &self,
+        batch_id: &Uuid,
+        signals: &[InfoSignal],
+        ts: DateTime<Utc>,
+    ) -> Result<()> {
+        let Some(backend) = &self.backend else {
+            return Ok(());
+        };
+        let stored: Vec<_> = signals
+            .iter()
+            .map(|signal| StoredSignal {
+                batch_id: *batch_id,
+                ts,
+                region: signal.region_id.clone(),
+                severity: signal.severity,
+                metrics: signal.metrics.clone(),
+            })
+            .collect();
+        backend.persist_signals(batch_id, ts, &stored)
+    }
+
+    /// Persists the aggregation job metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot store the job record.
+    pub fn persist_job(&self, job: &AssimilationJob) -> Result<()> {
+        let Some(backend) = &self.backend else {
+            return Ok(());
+        };
+        backend.persist_job(&json!({
+            "batch_id": job.batch_id,
+            "ts": Utc::now(),
+            "regions": job.region_metrics.keys().cloned().collect::<Vec<_>>(),
+        }))
+    }
+
+    /// Index entries for batches with `from <= ts <= to`, oldest first.
+    #[must_use]
+    pub fn entries_in_range(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<SegmentEntry> {
+        self.backend
+            .as_ref()
+            .map_or_else(Vec::new, |backend| backend.entries_in_range(from, to))
+    }
+
+    /// Reads the signals persisted with `from <= ts <= to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot read or decode the stored batches.
+    pub fn signals_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<StoredSignal>> {
+        self.backend.as_ref().map_or_else(
+            || Ok(Vec::new()),
+            |backend| backend.signals_in_range(from, to),
+        )
+    }
+
+    /// Running statistics of every numeric metric persisted with `from <= ts <= to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`Self::signals_in_range`] fails.
+    pub fn metric_stats(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<IndexMap<String, MetricStats>> {
+        let mut stats: IndexMap<String, MetricStats> = IndexMap::new();
+        for signal in self.signals_in_range(from, to)? {
+            let Some(metrics) = signal.metrics.as_object() else {
+                continue;
+            };
+            for (metric, value) in metrics {
+                if let Some(value) = value.as_f64() {
+                    stats.entry(metric.clone()).or_default().observe(value);
+                }
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Merges stored batches into one segment per `bucket` of time.
+    ///
+    /// Returns the number of files removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to rewrite its segments or index.
+    pub fn compact(&self, bucket: Duration) -> Result<usize> {
+        self.backend
+            .as_ref()
+            .map_or(Ok(0), |backend| backend.compact(bucket))
+    }
+
+    /// Returns the configured path, if enabled and file-backed.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.backend.as_ref().and_then(|backend| backend.path())
+    }
+}
+
+/// File backend, the default for [`FeatureStore::open`].
+///
+/// Job metadata is appended to the store file; signal batches are written to
+/// per-batch segment files in a sibling `<name>.segments` directory and located
+/// through a time index (`<name>.index.jsonl`), so range queries only read the
+/// bytes of matching batches. [`FeatureBackend::compact`] merges batch files into
+/// time-bucketed segments.
+///
+/// Older stores kept signal records in the store file itself. Opening a store that
+/// has no index yet moves those records into indexed segments once; the store file
+/// is left untouched.
+#[derive(Debug)]
+pub struct FileFeatureBackend {
+    path: PathBuf,
+    writer: Mutex<File>,
+    index: Mutex<Vec<SegmentEntry>>,
+}
+
+impl FileFeatureBackend {
+    /// Opens (or creates) the store file at `path`, loading its time index.
+    ///
+    /// Signals from stores written before the index existed are migrated on first open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store directory or file cannot be created, the index is
+    /// unreadable, or migrating legacy signals fails.
     pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
         let path = path.into();
         if let Some(parent) = path.parent() {
@@ -32,62 +294,264 @@ impl FeatureStore {
             .append(true)
             .open(&path)
             .with_context(|| format!("opening feature store {}", path.display()))?;
-        Ok(Self {
-            path: Some(path),
-            writer: Some(Mutex::new(file)),
-        })
+        let index_file = index_path(&path);
+        let migrate = !index_file.exists();
+        let backend = Self {
+            index: Mutex::new(read_index(&index_file)?),
+            path,
+            writer: Mutex::new(file),
+        };
+        if migrate {
+            backend.migrate_legacy_signals()?;
+        }
+        Ok(backend)
     }
 
-    /// Returns a disabled store (no-op writer).
-    #[must_use]
-    pub fn disabled() -> Self {
-        Self {
-            path: None,
-            writer: None,
+    /// Indexes signal records stored inline in the store file by older versions.
+    fn migrate_legacy_signals(&self) -> Result<()> {
+        let mut batches: IndexMap<(Uuid, DateTime<Utc>), Vec<StoredSignal>> = IndexMap::new();
+        for line in BufReader::new(File::open(&self.path)?).lines() {
+            // Job records lack the signal fields and are skipped.
+            if let Ok(signal) = serde_json::from_str::<StoredSignal>(&line?) {
+                batches
+                    .entry((signal.batch_id, signal.ts))
+                    .or_default()
+                    .push(signal);
+            }
+        }
+        for ((batch_id, ts), signals) in batches {
+            self.persist_signals(&batch_id, ts, &signals)?;
         }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(index_path(&self.path))?;
+        Ok(())
     }
+}
 
-    /// Persists raw signals for future learning.
-    pub fn persist_signals(&self, batch_id: &Uuid, signals: &[InfoSignal]) -> Result<()> {
-        if let Some(writer) = &self.writer {
-            let mut guard = writer.lock();
-            for signal in signals {
-                let record = json!({
-                    "batch_id": batch_id,
-                    "ts": Utc::now(),
-                    "region": signal.region_id,
-                    "severity": signal.severity,
-                    "metrics": signal.metrics,
+impl FeatureBackend for FileFeatureBackend {
+    fn persist_signals(
+        &self,
+        batch_id: &Uuid,
+        ts: DateTime<Utc>,
+        signals: &[StoredSignal],
+    ) -> Result<()> {
+        let mut bytes = Vec::new();
+        for signal in signals {
+            serde_json::to_writer(&mut bytes, signal)?;
+            bytes.push(b'\n');
+        }
+        let dir = segment_dir(&self.path);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("creating segment dir {}", dir.display()))?;
+        let file = format!("batch-{}-{batch_id}.jsonl", ts.timestamp_millis());
+        fs::write(dir.join(&file), &bytes)?;
+
+        let entry = SegmentEntry {
+            batch_id: *batch_id,
+            ts,
+            file,
+            offset: 0,
+            len: bytes.len() as u64,
+        };
+        let mut index = self.index.lock();
+        let mut line = serde_json::to_vec(&entry)?;
+        line.push(b'\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(index_path(&self.path))?
+            .write_all(&line)?;
+        index.push(entry);
+        drop(index);
+        Ok(())
+    }
+
+    fn persist_job(&self, record: &Value) -> Result<()> {
+        let mut guard = self.writer.lock();
+        serde_json::to_writer(&mut *guard, record)?;
+        guard.write_all(b"\n")?;
+        guard.flush()?;
+        drop(guard);
+        Ok(())
+    }
+
+    fn entries_in_range(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<SegmentEntry> {
+        let mut entries: Vec<_> = self
+            .index
+            .lock()
+            .iter()
+            .filter(|entry| entry.ts >= from && entry.ts <= to)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| entry.ts);
+        entries
+    }
+
+    /// Seeks straight to each matching batch.
+    fn signals_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<StoredSignal>> {
+        let dir = segment_dir(&self.path);
+        let mut signals = Vec::new();
+        for entry in self.entries_in_range(from, to) {
+            let mut bytes = Vec::new();
+            let mut file = File::open(dir.join(&entry.file))
+                .with_context(|| format!("opening segment {}", entry.file))?;
+            file.seek(SeekFrom::Start(entry.offset))?;
+            file.take(entry.len).read_to_end(&mut bytes)?;
+            for line in bytes.lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    signals.push(serde_json::from_str(&line)?);
+                }
+            }
+        }
+        Ok(signals)
+    }
+
+    /// Writes merged segments under fresh names and swaps the index before deleting
+    /// the old files, so a crash at any point leaves a readable index.
+    fn compact(&self, bucket: Duration) -> Result<usize> {
+        let path = &self.path;
+        let dir = segment_dir(path);
+        let bucket_secs = bucket.num_seconds().max(1);
+        let mut index = self.index.lock();
+
+        let mut buckets: IndexMap<i64, Vec<SegmentEntry>> = IndexMap::new();
+        for entry in index.iter() {
+            let start = entry.ts.timestamp().div_euclid(bucket_secs) * bucket_secs;
+            buckets.entry(start).or_default().push(entry.clone());
+        }
+        buckets.sort_keys();
+
+        let generation = Uuid::new_v4().simple();
+        let mut compacted = Vec::with_capacity(index.len());
+        for (start, mut entries) in buckets {
+            entries.sort_by_key(|entry| entry.ts);
+            let file = format!("segment-{start}-{generation}.jsonl");
+            let mut bytes = Vec::new();
+            for entry in entries {
+                let mut source = File::open(dir.join(&entry.file))
+                    .with_context(|| format!("opening segment {}", entry.file))?;
+                source.seek(SeekFrom::Start(entry.offset))?;
+                let offset = bytes.len() as u64;
+                let len = source.take(entry.len).read_to_end(&mut bytes)? as u64;
+                compacted.push(SegmentEntry {
+                    file: file.clone(),
+                    offset,
+                    len,
+                    ..entry
                 });
-                serde_json::to_writer(&mut *guard, &record)?;
-                guard.write_all(b"\n")?;
             }
-            guard.flush()?;
+            let staging = dir.join(format!("{file}.tmp"));
+            fs::write(&staging, &bytes)?;
+            fs::rename(&staging, dir.join(&file))?;
+        }
+
+        let index_file = index_path(path);
+        let staging = index_file.with_extension("jsonl.tmp");
+        let mut encoded = Vec::new();
+        for entry in &compacted {
+            serde_json::to_writer(&mut encoded, entry)?;
+            encoded.push(b'\n');
         }
+        fs::write(&staging, encoded)?;
+        fs::rename(&staging, &index_file)?;
+
+        let mut removed = 0;
+        for obsolete in index.iter().map(|entry| &entry.file) {
+            if fs::remove_file(dir.join(obsolete)).is_ok() {
+                removed += 1;
+            }
+        }
+        *index = compacted;
+        drop(index);
+        Ok(removed)
+    }
+
+    fn path(&self) -> Option<&Path> {
+        Some(&self.path)
+    }
+}
+
+/// In-memory backend, e.g. for tests; nothing touches disk.
+#[derive(Debug, Default)]
+pub struct MemoryFeatureBackend {
+    signals: Mutex<Vec<StoredSignal>>,
+    jobs: Mutex<Vec<Value>>,
+}
+
+impl MemoryFeatureBackend {
+    /// Creates an empty backend.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Job records persisted so far, oldest first.
+    #[must_use]
+    pub fn jobs(&self) -> Vec<Value> {
+        self.jobs.lock().clone()
+    }
+}
+
+impl FeatureBackend for MemoryFeatureBackend {
+    fn persist_signals(
+        &self,
+        _batch_id: &Uuid,
+        _ts: DateTime<Utc>,
+        signals: &[StoredSignal],
+    ) -> Result<()> {
+        self.signals.lock().extend_from_slice(signals);
         Ok(())
     }
 
-    /// Persists the aggregation job metadata.
-    pub fn persist_job(&self, job: &AssimilationJob) -> Result<()> {
-        if let Some(writer) = &self.writer {
-            let mut guard = writer.lock();
-            let record = json!({
-                "batch_id": job.batch_id,
-                "ts": Utc::now(),
-                "regions": job.region_metrics.keys().cloned().collect::<Vec<_>>(),
-            });
-            serde_json::to_writer(&mut *guard, &record)?;
-            guard.write_all(b"\n")?;
-            guard.flush()?;
-        }
+    fn persist_job(&self, record: &Value) -> Result<()> {
+        self.jobs.lock().push(record.clone());
         Ok(())
     }
 
-    /// Returns the configured path, if enabled.
-    #[must_use]
-    pub fn path(&self) -> Option<&Path> {
-        self.path.as_deref()
+    fn signals_in_range(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<StoredSignal>> {
+        let mut signals: Vec<_> = self
+            .signals
+            .lock()
+            .iter()
+            .filter(|signal| signal.ts >= from && signal.ts <= to)
+            .cloned()
+            .collect();
+        signals.sort_by_key(|signal| signal.ts);
+        Ok(signals)
+    }
+}
+
+fn index_path(path: &Path) -> PathBuf {
+    path.with_extension("index.jsonl")
+}
+
+fn segment_dir(path: &Path) -> PathBuf {
+    path.with_extension("segments")
+}
+
+fn read_index(path: &Path) -> Result<Vec<SegmentEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            entries.push(serde_json::from_str(&line)?);
+        }
     }
+    Ok(entries)
 }
 
 #[cfg(test)]
@@ -106,6 +570,7 @@ mod tests {
             region_id: "alpha".into(),
             metrics: json!({ "load": 0.9 }),
             severity: 0.91,
+            source: "test".into(),
         }];
         let batch_id = Uuid::new_v4();
         store.persist_signals(&batch_id, &signals).unwrap();
@@ -120,4 +585,145 @@ mod tests {
         let content = fs::read_to_string(store_path).unwrap();
         assert!(content.contains("alpha"));
     }
+
+    #[test]
+    fn compacted_range_query_reads_only_matching_segment() {
+        let dir = tempdir().unwrap();
+        let store_path = dir.path().join("features.jsonl");
+        let store = FeatureStore::open(&store_path).unwrap();
+        let origin = DateTime::parse_from_rfc3339("2024-05-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for hour in 0..6 {
+            for minute in [5, 25, 45] {
+                let signal = InfoSignal {
+                    region_id: format!("r{hour}-{minute}"),
+                    metrics: json!({ "load": hour }),
+                    severity: 0.5,
+                    source: "test".into(),
+                };
+                let ts = origin + Duration::hours(hour) + Duration::minutes(minute);
+                store
+                    .persist_signals_at(&Uuid::new_v4(), &[signal], ts)
+                    .unwrap();
+            }
+        }
+        assert_eq!(fs::read_dir(segment_dir(&store_path)).unwrap().count(), 18);
+
+        assert_eq!(store.compact(Duration::hours(1)).unwrap(), 18);
+        assert_eq!(fs::read_dir(segment_dir(&store_path)).unwrap().count(), 6);
+
+        let reopened = FeatureStore::open(&store_path).unwrap();
+        let from = origin + Duration::hours(3) + Duration::minutes(20);
+        let to = origin + Duration::hours(3) + Duration::minutes(50);
+        let entries = reopened.entries_in_range(from, to);
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|entry| entry.file == entries[0].file));
+
+        let regions: Vec<_> = reopened
+            .signals_in_range(from, to)
+            .unwrap()
+            .into_iter()
+            .map(|signal| signal.region)
+            .collect();
+        assert_eq!(regions, vec!["r3-25", "r3-45"]);
+    }
+
+    #[test]
+    fn recompaction_writes_fresh_segments() {
+        let dir = tempdir().unwrap();
+        let store_path = dir.path().join("features.jsonl");
+        let store = FeatureStore::open(&store_path).unwrap();
+        let ts = DateTime::parse_from_rfc3339("2024-05-01T00:10:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let signal = InfoSignal {
+            region_id: "alpha".into(),
+            metrics: json!({ "load": 0.4 }),
+            severity: 0.5,
+            source: "test".into(),
+        };
+        store
+            .persist_signals_at(&Uuid::new_v4(), &[signal], ts)
+            .unwrap();
+
+        assert_eq!(store.compact(Duration::hours(1)).unwrap(), 1);
+        let first = store.entries_in_range(ts, ts)[0].file.clone();
+        assert_eq!(store.compact(Duration::hours(1)).unwrap(), 1);
+        let second = store.entries_in_range(ts, ts)[0].file.clone();
+        assert_ne!(first, second);
+        assert!(!segment_dir(&store_path).join(first).exists());
+        assert_eq!(store.signals_in_range(ts, ts).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn legacy_inline_signals_are_migrated_on_open() {
+        let dir = tempdir().unwrap();
+        let store_path = dir.path().join("features.jsonl");
+        let batch_id = Uuid::new_v4();
+        let ts = DateTime::parse_from_rfc3339("2024-05-01T00:10:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let legacy = [
+            json!({ "batch_id": batch_id, "ts": ts, "region": "alpha", "severity": 0.5, "metrics": { "load": 0.4 } }),
+            json!({ "batch_id": batch_id, "ts": ts, "region": "beta", "severity": 0.7, "metrics": { "load": 0.6 } }),
+            json!({ "batch_id": batch_id, "ts": ts, "regions": ["alpha", "beta"] }),
+        ];
+        let lines: Vec<_> = legacy.iter().map(Value::to_string).collect();
+        fs::write(&store_path, lines.join("\n") + "\n").unwrap();
+
+        let store = FeatureStore::open(&store_path).unwrap();
+        let regions: Vec<_> = store
+            .signals_in_range(ts, ts)
+            .unwrap()
+            .into_iter()
+            .map(|signal| signal.region)
+            .collect();
+        assert_eq!(regions, vec!["alpha", "beta"]);
+
+        let reopened = FeatureStore::open(&store_path).unwrap();
+        assert_eq!(reopened.entries_in_range(ts, ts).len(), 1);
+    }
+
+    #[test]
+    fn memory_backend_round_trips_without_disk() {
+        let store = FeatureStore::with_backend(MemoryFeatureBackend::new());
+        assert!(store.path().is_none());
+        let origin = DateTime::parse_from_rfc3339("2024-05-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for (hour, load) in [(2, 0.2), (0, 0.4), (1, 0.6)] {
+            let signal = InfoSignal {
+                region_id: format!("r{hour}"),
+                metrics: json!({ "load": load }),
+                severity: 0.5,
+                source: "test".into(),
+            };
+            store
+                .persist_signals_at(&Uuid::new_v4(), &[signal], origin + Duration::hours(hour))
+                .unwrap();
+        }
+        store
+            .persist_job(&AssimilationJob {
+                batch_id: Uuid::new_v4(),
+                region_metrics: IndexMap::new(),
+            })
+            .unwrap();
+
+        let signals = store
+            .signals_in_range(origin, origin + Duration::minutes(90))
+            .unwrap();
+        let regions: Vec<_> = signals
+            .iter()
+            .map(|signal| signal.region.as_str())
+            .collect();
+        assert_eq!(regions, vec!["r0", "r1"]);
+        let stats = store
+            .metric_stats(origin, origin + Duration::hours(2))
+            .unwrap();
+        assert_eq!(stats["load"].count, 3);
+        assert!(store
+            .entries_in_range(origin, origin + Duration::hours(2))
+            .is_empty());
+    }
 }
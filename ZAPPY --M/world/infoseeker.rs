@@ -1,4 +1,9 @@
-use std::{collections::HashMap, path::PathBuf, time::Duration};
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    time::Duration,
+};
 
 use anyhow::{anyhow, bail, Context, Result};
 use async_trait::async_trait;
@@ -6,7 +11,7 @@ use rand::{rngs::SmallRng, Rng, SeedableRng};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::{fs, time::sleep};
+use tokio::{fs, sync::mpsc, time::sleep};
 
 use crate::{
     feed_config::{FeedKind, FeedsDocument, FieldMapping, FileFeedConfig, HttpFeedConfig},
@@ -25,6 +30,28 @@ pub struct InfoSignal {
     pub metrics: Value,
     /// Severity estimate.
     pub severity: f32,
+    /// Name of the feed that produced the signal (set during collection).
+    #[serde(default)]
+    pub source: String,
+}
+
+impl InfoSignal {
+    /// Hash over the metric payload and severity, ignoring region and source.
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.metrics.to_string().hash(&mut hasher);
+        self.severity.to_bits().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dedup_key(&self) -> (String, String, u64) {
+        (
+            self.region_id.clone(),
+            self.source.clone(),
+            self.fingerprint(),
+        )
+    }
 }
 
 /// Trait for providers that fetch environmental signals.
@@ -40,6 +67,7 @@ pub struct InfoSeeker {
     telemetry: Option<WorldTelemetry>,
     validator: SignalValidator,
     batch_limit: usize,
+    channel_capacity: usize,
 }
 
 impl InfoSeeker {
@@ -109,86 +137,94 @@ impl InfoSeeker {
     }
 
     /// Pulls signals and returns them.
+    ///
+    /// Providers stream validated signals through a bounded channel; once `batch_limit`
+    /// distinct signals are accepted the remaining feeds are not pulled. Repeats of the
+    /// same (region, feed, fingerprint) within a pass are dropped.
     pub async fn collect(&self) -> Result<Vec<InfoSignal>> {
-        let mut aggregated = Vec::new();
-        let mut failures = Vec::new();
-        for handle in &self.providers {
-            match handle.provider.pull().await {
-                Ok(batch) => {
-                    let valid = batch
-                        .into_iter()
-                        .filter_map(|signal| match self.validator.validate(&signal) {
-                            Ok(_) => Some(signal),
-                            Err(err) => {
+        let (tx, mut rx) = mpsc::channel(self.channel_capacity);
+        let produce = async move {
+            let mut failures = Vec::new();
+            for handle in &self.providers {
+                match handle.provider.pull().await {
+                    Ok(batch) => {
+                        let mut forwarded = 0_usize;
+                        for mut signal in batch {
+                            if let Err(err) = self.validator.validate(&signal) {
                                 failures.push(format!("{} invalid signal: {err:#}", handle.name));
-                                None
+                                continue;
                             }
-                        })
-                        .collect::<Vec<_>>();
-                    if let Some(tel) = &self.telemetry {
-                        let _ = tel.log(
-                            shared_logging::LogLevel::Info,
-                            "world.infoseeker.batch",
-                            serde_json::json!({
-                                "provider": handle.name,
-                                "count": valid.len()
-                            }),
-                        );
+                            signal.source.clone_from(&handle.name);
+                            if tx.send(signal).await.is_err() {
+                                return failures;
+                            }
+                            forwarded += 1;
+                        }
+                        if let Some(tel) = &self.telemetry {
+                            let _ = tel.log(
+                                shared_logging::LogLevel::Info,
+                                "world.infoseeker.batch",
+                                serde_json::json!({
+                                    "provider": handle.name,
+                                    "count": forwarded
+                                }),
+                            );
+                        }
                     }
-                    aggregated.extend(valid);
-                }
-                Err(err) => {
-                    failures.push(format!("{} failed: {err:#}", handle.name));
-                    if let Some(tel) = &self.telemetry {
-                        let _ = tel.log(
-                            shared_logging::LogLevel::Warn,
-                            "world.infoseeker.error",
-                            serde_json::json!({
-                                "provider": handle.name,
-                                "error": err.to_string()
-                            }),
-                        );
+                    Err(err) => {
+                        failures.push(format!("{} failed: {err:#}", handle.name));
+                        if let Some(tel) = &self.telemetry {
+                            let _ = tel.log(
+                                shared_logging::LogLevel::Warn,
+                                "world.infoseeker.error",
+                                serde_json::json!({
+                                    "provider": handle.name,
+                                    "error": err.to_string()
+                                }),
+                            );
+                        }
                     }
                 }
             }
-        }
+            failures
+        };
+        let assimilate = async move {
+            let mut seen = HashSet::new();
+            let mut accepted = Vec::new();
+            let mut duplicates = 0_usize;
+            while let Some(signal) = rx.recv().await {
+                if !seen.insert(signal.dedup_key()) {
+                    duplicates += 1;
+                    continue;
+                }
+                accepted.push(signal);
+                if accepted.len() >= self.batch_limit {
+                    break;
+                }
+            }
+            (accepted, duplicates)
+        };
+        let (failures, (aggregated, duplicates)) = tokio::join!(produce, assimilate);
         if aggregated.is_empty() && !failures.is_empty() {
             bail!("all providers failed: {}", failures.join("; "));
         }
-        aggregated = dedupe_by_region(aggregated);
-        if aggregated.len() > self.batch_limit {
-            aggregated.truncate(self.batch_limit);
-        }
         if let Some(tel) = &self.telemetry {
             let _ = tel.event(
                 "world.infoseeker.batch",
-                serde_json::json!({ "signals": aggregated.len() }),
+                serde_json::json!({ "signals": aggregated.len(), "duplicates": duplicates }),
             );
         }
         Ok(aggregated)
     }
 }
 
-fn dedupe_by_region(mut signals: Vec<InfoSignal>) -> Vec<InfoSignal> {
-    let mut seen: HashMap<String, InfoSignal> = HashMap::new();
-    for signal in signals.drain(..) {
-        seen.entry(signal.region_id.clone())
-            .and_modify(|existing| {
-                if signal.severity > existing.severity {
-                    *existing = signal.clone();
-                }
-            })
-            .or_insert(signal);
-    }
-    seen.into_values().collect()
-}
-
 /// Builder for `InfoSeeker`.
 pub struct InfoSeekerBuilder {
     providers: Vec<ProviderHandle>,
     telemetry: Option<WorldTelemetry>,
     validator: SignalValidator,
     batch_limit: usize,
+    channel_capacity: usize,
 }
 
 impl Default for InfoSeekerBuilder {
@@ -198,6 +234,7 @@ impl Default for InfoSeekerBuilder {
             telemetry: None,
             validator: SignalValidator::default(),
             batch_limit: 256,
+            channel_capacity: 64,
         }
     }
 }
@@ -260,6 +297,13 @@ impl InfoSeekerBuilder {
         self
     }
 
+    /// Sets how many signals may be in flight between feeds and assimilation.
+    #[must_use]
+    pub fn channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = capacity.max(1);
+        self
+    }
+
     /// Builds the seeker.
     pub fn build(mut self) -> InfoSeeker {
         if self.providers.is_empty() {
@@ -274,6 +318,7 @@ impl InfoSeekerBuilder {
             telemetry: self.telemetry,
             validator: self.validator,
             batch_limit: self.batch_limit,
+            channel_capacity: self.channel_capacity,
         }
     }
 }
@@ -365,6 +410,7 @@ impl InfoProvider for RandomInfoProvider {
                     "demand": rng.gen_range(0.3..0.9),
                 }),
                 severity: rng.gen_range(0.0..1.0),
+                source: String::new(),
             });
         }
         Ok(signals)
@@ -410,6 +456,7 @@ impl SignalExtractor {
             region_id: region,
             metrics,
             severity,
+            source: String::new(),
         })
     }
 
@@ -615,9 +662,68 @@ mod tests {
             region_id: "alpha".into(),
             metrics: Value::Null,
             severity: 0.5,
+            source: String::new(),
         });
         assert!(result.is_err());
     }
+
+    struct FixedProvider(Vec<InfoSignal>);
+
+    #[async_trait]
+    impl InfoProvider for FixedProvider {
+        async fn pull(&self) -> Result<Vec<InfoSignal>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn signal(region: &str, load: f64) -> InfoSignal {
+        InfoSignal {
+            region_id: region.into(),
+            metrics: serde_json::json!({ "load": load, "demand": 0.5 }),
+            severity: 0.4,
+            source: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicate_signals_collapse_per_dedup_key() {
+        let overlapping = vec![
+            signal("alpha", 0.7),
+            signal("alpha", 0.7),
+            signal("alpha", 0.9),
+            signal("beta", 0.7),
+        ];
+        let seeker = InfoSeeker::builder()
+            .provider_named("east", Box::new(FixedProvider(overlapping.clone())), 1)
+            .provider_named(
+                "west",
+                Box::new(FixedProvider(vec![signal("alpha", 0.7)])),
+                1,
+            )
+            .channel_capacity(1)
+            .build();
+        let signals = seeker.collect().await.unwrap();
+        let keys = signals
+            .iter()
+            .map(InfoSignal::dedup_key)
+            .collect::<HashSet<_>>();
+        assert_eq!(signals.len(), 4);
+        assert_eq!(keys.len(), signals.len());
+        assert_eq!(
+            signals
+                .iter()
+                .filter(|s| s.source == "east" && s.region_id == "alpha")
+                .count(),
+            2
+        );
+
+        let limited = InfoSeeker::builder()
+            .provider_named("east", Box::new(FixedProvider(overlapping)), 1)
+            .batch_limit(2)
+            .channel_capacity(1)
+            .build();
+        assert_eq!(limited.collect().await.unwrap().len(), 2);
+    }
 }
 
 fn value_at_path<'a>(mut node: &'a Value, path: &str) -> Option<&'a Value> {
@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use crate::{
     model::{AnomalyEvent, RegionSnapshot, WorldModel, WorldState},
+    normalization::SignalNormalizer,
     telemetry::WorldTelemetry,
 };
 
@@ -26,6 +27,7 @@ pub struct AssimilationEngine {
     telemetry: Option<WorldTelemetry>,
     history: VecDeque<WorldState>,
     threshold: f32,
+    normalizer: Option<SignalNormalizer>,
 }
 
 impl AssimilationEngine {
@@ -37,13 +39,24 @@ impl AssimilationEngine {
             telemetry,
             history: VecDeque::with_capacity(16),
             threshold: 0.85,
+            normalizer: None,
         }
     }
 
+    /// Rescales region metrics with `normalizer` before they reach the model.
+    #[must_use]
+    pub fn with_normalizer(mut self, normalizer: SignalNormalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
     /// Processes a job and returns updated world state.
     pub fn assimilate(&mut self, job: AssimilationJob) -> Result<WorldState> {
         for (region, metrics_value) in &job.region_metrics {
-            let metrics_map = extract_metrics(metrics_value)?;
+            let mut metrics_map = extract_metrics(metrics_value)?;
+            if let Some(normalizer) = &mut self.normalizer {
+                normalizer.apply(&mut metrics_map);
+            }
             let snapshot = RegionSnapshot::from_metrics(region.clone(), metrics_map.clone());
             let delta = self.model.ingest(snapshot);
             let severity = metrics_map.get("load").copied().unwrap_or_default();
@@ -55,6 +68,7 @@ impl AssimilationEngine {
                 ));
             }
         }
+        self.model.roll_up();
         let state = self.model.snapshot();
         self.history.push_back(state.clone());
         if self.history.len() > 16 {
@@ -70,6 +84,12 @@ impl AssimilationEngine {
         Ok(state)
     }
 
+    /// Returns the current model state.
+    #[must_use]
+    pub fn snapshot(&self) -> WorldState {
+        self.model.snapshot()
+    }
+
     /// Returns last known state.
     #[must_use]
     pub fn last_state(&self) -> Option<&WorldState> {
@@ -92,6 +112,13 @@ fn extract_metrics(value: &Value) -> Result<IndexMap<String, f32>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        feature_store::FeatureStore,
+        infoseeker::InfoSignal,
+        model::{RegionAggregation, RegionHierarchy},
+        normalization::NormalizationMethod,
+    };
+    use chrono::{DateTime, Utc};
     use serde_json::json;
 
     #[test]
@@ -106,4 +133,83 @@ mod tests {
         let state = engine.assimilate(job).unwrap();
         assert!(!state.anomalies.is_empty());
     }
+
+    #[test]
+    fn normalized_metrics_do_not_trip_on_scale() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FeatureStore::open(dir.path().join("features.jsonl")).unwrap();
+        let history = (0..=10)
+            .map(|step| InfoSignal {
+                region_id: "alpha".into(),
+                metrics: json!({ "load": f64::from(step) * 10.0 }),
+                severity: 0.1,
+                source: "test".into(),
+            })
+            .collect::<Vec<_>>();
+        store.persist_signals(&Uuid::new_v4(), &history).unwrap();
+        let seeded = store
+            .metric_stats(DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC)
+            .unwrap();
+        let job = || AssimilationJob {
+            batch_id: Uuid::new_v4(),
+            region_metrics: IndexMap::from([("alpha".to_string(), json!({ "load": 50.0 }))]),
+        };
+
+        let mut raw = AssimilationEngine::new(WorldModel::new(), None);
+        assert!(!raw.assimilate(job()).unwrap().anomalies.is_empty());
+
+        let normalizer = SignalNormalizer::new()
+            .metric("load", NormalizationMethod::MinMax)
+            .with_stats(seeded);
+        let mut engine =
+            AssimilationEngine::new(WorldModel::new(), None).with_normalizer(normalizer);
+        let state = engine.assimilate(job()).unwrap();
+        assert!((state.regions["alpha"].metrics["load"] - 0.5).abs() < 1e-6);
+        assert!(state.anomalies.is_empty());
+    }
+
+    #[test]
+    fn child_metrics_roll_up_into_parent() {
+        let hierarchy = RegionHierarchy::new(RegionAggregation::Mean)
+            .with_parent("us-east", "us")
+            .with_parent("us-west", "us");
+        let mut engine = AssimilationEngine::new(WorldModel::new().with_hierarchy(hierarchy), None);
+        let mut regions = IndexMap::new();
+        regions.insert("us-east".into(), json!({ "load": 0.2, "demand": 0.5 }));
+        regions.insert("us-west".into(), json!({ "load": 0.6 }));
+        let state = engine
+            .assimilate(AssimilationJob {
+                batch_id: Uuid::new_v4(),
+                region_metrics: regions,
+            })
+            .unwrap();
+
+        let parent = &state.rollups["us"];
+        assert!((parent.metrics["load"] - 0.4).abs() < 1e-6);
+        assert!((parent.metrics["demand"] - 0.5).abs() < 1e-6);
+        assert!(!state.regions.contains_key("us"));
+        assert_eq!(state.regions_at_level(0).len(), 1);
+        assert_eq!(state.regions_at_level(1).len(), 2);
+    }
+
+    #[test]
+    fn rollups_leave_observed_parent_metrics_alone() {
+        let hierarchy = RegionHierarchy::new(RegionAggregation::Max)
+            .with_parent("us-east", "us")
+            .with_parent("us", "na");
+        let mut engine = AssimilationEngine::new(WorldModel::new().with_hierarchy(hierarchy), None);
+        let mut regions = IndexMap::new();
+        regions.insert("us".into(), json!({ "load": 0.1 }));
+        regions.insert("us-east".into(), json!({ "load": 0.7 }));
+        let state = engine
+            .assimilate(AssimilationJob {
+                batch_id: Uuid::new_v4(),
+                region_metrics: regions,
+            })
+            .unwrap();
+
+        assert!((state.regions["us"].metrics["load"] - 0.1).abs() < 1e-6);
+        assert!((state.rollups["us"].metrics["load"] - 0.7).abs() < 1e-6);
+        assert!((state.rollups["na"].metrics["load"] - 0.7).abs() < 1e-6);
+    }
 }
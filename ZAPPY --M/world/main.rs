@@ -1,17 +1,26 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use serde_json::{json, Value};
 use uuid::Uuid;
 
 use crate::{
-    advanced::{AdvancedController, TrainingArtifact, TrainingConfig},
+    advanced::{
+        load_escalation_rules, AdvancedController, EscalationRule, TrainingArtifact, TrainingConfig,
+    },
+    alerting::WebhookAlertSink,
+    checkpoint::WorldCheckpoint,
     feature_store::FeatureStore,
     feed_config::FeedsDocument,
     infoseeker::{InfoSeeker, InfoSeekerBuilder, InfoSignal},
     learning::{AssimilationEngine, AssimilationJob},
-    model::{WorldModel, WorldState},
+    model::{RegionHierarchy, WorldModel, WorldState},
+    normalization::SignalNormalizer,
     telemetry::WorldTelemetry,
 };
 
@@ -22,6 +31,7 @@ pub struct WorldRuntime {
     assimilation: AssimilationEngine,
     advanced: AdvancedController,
     feature_store: FeatureStore,
+    alert_sink: Option<Arc<WebhookAlertSink>>,
 }
 
 impl WorldRuntime {
@@ -50,13 +60,26 @@ impl WorldRuntime {
     /// Ingests a prepared assimilation job.
     pub fn ingest(&mut self, job: AssimilationJob) -> Result<WorldState> {
         let state = self.assimilation.assimilate(job)?;
-        let requires_action = self.advanced.review_state(&state)?;
-        if requires_action {
+        let review = self.advanced.review_state(&state)?;
+        if review.action_required {
+            let region = review
+                .region
+                .clone()
+                .or_else(|| state.highest_severity().map(|anom| anom.region_id.clone()))
+                .unwrap_or_else(|| "global".into());
+            let alert = json!({
+                "anomalies": state.anomalies.len(),
+                "regions": state.regions.len(),
+                "severity": review.severity,
+                "rule": review.matched_rule.map(|rule| rule.name),
+                "region": region,
+                "feature_importance": self.advanced.model().feature_importance(),
+            });
             if let Some(tel) = &self.telemetry {
-                let _ = tel.event(
-                    "world.alert.triggered",
-                    json!({ "anomalies": state.anomalies.len(), "regions": state.regions.len() }),
-                );
+                let _ = tel.event("world.alert.triggered", alert.clone());
+            }
+            if let Some(sink) = &self.alert_sink {
+                sink.dispatch(&region, alert, self.telemetry.clone());
             }
         }
         Ok(state)
@@ -67,6 +90,29 @@ impl WorldRuntime {
         self.advanced.retrain(config).await
     }
 
+    /// Returns the current assimilated world state.
+    #[must_use]
+    pub fn state(&self) -> WorldState {
+        self.assimilation.snapshot()
+    }
+
+    /// Atomically persists model, baseline, and anomaly state for a later resume.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`WorldCheckpoint::save`] fails.
+    pub fn checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        let checkpoint = WorldCheckpoint::new(self.state(), self.advanced.model().clone());
+        checkpoint.save(path.as_ref())?;
+        if let Some(tel) = &self.telemetry {
+            let _ = tel.event(
+                "world.checkpoint.saved",
+                json!({ "path": path.as_ref(), "regions": checkpoint.state.regions.len() }),
+            );
+        }
+        Ok(())
+    }
+
     /// Returns telemetry handle.
     #[must_use]
     pub fn telemetry(&self) -> Option<&WorldTelemetry> {
@@ -81,6 +127,11 @@ pub struct WorldRuntimeBuilder {
     seeker: Option<InfoSeeker>,
     feeds_document: Option<FeedsDocument>,
     feature_store: Option<FeatureStore>,
+    checkpoint: Option<WorldCheckpoint>,
+    escalation_rules: Vec<EscalationRule>,
+    alert_sink: Option<WebhookAlertSink>,
+    hierarchy: Option<RegionHierarchy>,
+    normalizer: Option<SignalNormalizer>,
 }
 
 impl WorldRuntimeBuilder {
@@ -126,6 +177,53 @@ impl WorldRuntimeBuilder {
         Ok(self)
     }
 
+    /// Loads declarative escalation rules from a TOML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`load_escalation_rules`] fails.
+    pub fn escalation_rules_path(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        self.escalation_rules =
+            load_escalation_rules(path).context("loading world escalation rules")?;
+        Ok(self)
+    }
+
+    /// Restores state from a checkpoint written by [`WorldRuntime::checkpoint`].
+    ///
+    /// The checkpointed baseline replaces any baseline set on the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint cannot be read, parsed, or has an unsupported
+    /// version.
+    pub fn resume_from(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let checkpoint = WorldCheckpoint::load(path).context("loading world runtime checkpoint")?;
+        self.checkpoint = Some(checkpoint);
+        Ok(self)
+    }
+
+    /// POSTs triggered alerts to a webhook in addition to telemetry.
+    #[must_use]
+    pub fn alert_webhook(mut self, sink: WebhookAlertSink) -> Self {
+        self.alert_sink = Some(sink);
+        self
+    }
+
+    /// Rolls region metrics up through `hierarchy` on every assimilation.
+    #[must_use]
+    pub fn region_hierarchy(mut self, hierarchy: RegionHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+
+    /// Normalizes signal metrics before assimilation, seeding the running statistics
+    /// from everything already persisted in the feature store.
+    #[must_use]
+    pub fn signal_normalizer(mut self, normalizer: SignalNormalizer) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
     /// Builds runtime.
     pub fn build(self) -> Result<WorldRuntime> {
         let telemetry = self.telemetry;
@@ -138,15 +236,35 @@ impl WorldRuntimeBuilder {
                 .telemetry_opt(telemetry.clone())
                 .build()
         };
-        let assimilation = AssimilationEngine::new(WorldModel::new(), telemetry.clone());
-        let advanced = AdvancedController::new(self.baseline, telemetry.clone());
+        let (model, advanced) = match self.checkpoint {
+            Some(checkpoint) => (
+                WorldModel::from_state(checkpoint.state),
+                AdvancedController::from_model(checkpoint.model, telemetry.clone()),
+            ),
+            None => (
+                WorldModel::new(),
+                AdvancedController::new(self.baseline, telemetry.clone()),
+            ),
+        };
+        let model = match self.hierarchy {
+            Some(hierarchy) => model.with_hierarchy(hierarchy),
+            None => model,
+        };
         let feature_store = self.feature_store.unwrap_or_else(FeatureStore::disabled);
+        let mut assimilation = AssimilationEngine::new(model, telemetry.clone());
+        if let Some(normalizer) = self.normalizer {
+            let stats =
+                feature_store.metric_stats(DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC)?;
+            assimilation = assimilation.with_normalizer(normalizer.with_stats(stats));
+        }
+        let advanced = advanced.with_escalation_rules(self.escalation_rules);
         Ok(WorldRuntime {
             telemetry,
             seeker,
             assimilation,
             advanced,
             feature_store,
+            alert_sink: self.alert_sink.map(Arc::new),
         })
     }
 }
@@ -162,6 +280,11 @@ impl Default for WorldRuntimeBuilder {
             seeker: None,
             feeds_document: None,
             feature_store: None,
+            checkpoint: None,
+            escalation_rules: Vec::new(),
+            alert_sink: None,
+            hierarchy: None,
+            normalizer: None,
         }
     }
 }
@@ -170,6 +293,12 @@ impl Default for WorldRuntimeBuilder {
 mod tests {
     use super::*;
     use serde_json::json;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        time::Duration,
+    };
 
     #[tokio::test]
     async fn runtime_refreshes_state() {
@@ -191,12 +320,115 @@ mod tests {
             .unwrap();
         assert!(state.regions.contains_key("alpha"));
     }
+
+    #[tokio::test]
+    async fn runtime_resumes_from_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("world.ckpt.json");
+        let mut runtime = WorldRuntime::builder().build().unwrap();
+        runtime.refresh().await.unwrap();
+        let mut region_metrics = IndexMap::new();
+        region_metrics.insert("alpha".into(), json!({ "load": 0.95 }));
+        runtime
+            .ingest(AssimilationJob {
+                batch_id: Uuid::new_v4(),
+                region_metrics,
+            })
+            .unwrap();
+        let before = runtime.state();
+        runtime.checkpoint(&path).unwrap();
+
+        let resumed = WorldRuntime::builder()
+            .resume_from(&path)
+            .unwrap()
+            .build()
+            .unwrap();
+        let after = resumed.state();
+        assert_eq!(
+            after.regions.keys().collect::<Vec<_>>(),
+            before.regions.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(after.anomalies.len(), before.anomalies.len());
+        assert_eq!(
+            resumed.advanced.model().baseline,
+            runtime.advanced.model().baseline
+        );
+    }
+
+    fn spawn_webhook_server() -> (String, mpsc::Receiver<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/alerts", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .unwrap();
+                if tx.send(serde_json::from_slice(&body).unwrap()).is_err() {
+                    break;
+                }
+            }
+        });
+        (url, rx)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn webhook_alerts_are_deduplicated_per_region() {
+        let (url, alerts) = spawn_webhook_server();
+        let sink = WebhookAlertSink::new(url)
+            .unwrap()
+            .dedup_window(Duration::from_mins(1));
+        let mut runtime = WorldRuntime::builder().alert_webhook(sink).build().unwrap();
+        let anomalous = || {
+            let mut region_metrics = IndexMap::new();
+            region_metrics.insert("alpha".into(), json!({ "load": 1.2 }));
+            AssimilationJob {
+                batch_id: Uuid::new_v4(),
+                region_metrics,
+            }
+        };
+
+        runtime.ingest(anomalous()).unwrap();
+        let alert = alerts.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(alert["region"], "alpha");
+
+        runtime.ingest(anomalous()).unwrap();
+        assert!(alerts.recv_timeout(Duration::from_millis(500)).is_err());
+    }
 }
 
+/// Picks the most severe signal per region so overlapping feeds don't resolve by arrival order.
 fn aggregate_signals(signals: &[InfoSignal]) -> IndexMap<String, Value> {
-    let mut region_metrics = IndexMap::new();
+    let mut strongest: IndexMap<&str, &InfoSignal> = IndexMap::new();
     for signal in signals {
-        region_metrics.insert(signal.region_id.clone(), signal.metrics.clone());
+        strongest
+            .entry(signal.region_id.as_str())
+            .and_modify(|existing| {
+                if signal.severity > existing.severity {
+                    *existing = signal;
+                }
+            })
+            .or_insert(signal);
     }
-    region_metrics
+    strongest
+        .into_iter()
+        .map(|(region, signal)| (region.to_string(), signal.metrics.clone()))
+        .collect()
 }
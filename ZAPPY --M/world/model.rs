@@ -1,4 +1,4 @@
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
@@ -52,6 +52,96 @@ impl AnomalyEvent {
     }
 }
 
+/// How child region metrics are combined into their parent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionAggregation {
+    /// Average over the children reporting the metric.
+    #[default]
+    Mean,
+    /// Sum over the children reporting the metric.
+    Sum,
+    /// Largest child value.
+    Max,
+}
+
+impl RegionAggregation {
+    fn combine(self, values: &[f32]) -> f32 {
+        match self {
+            Self::Mean => {
+                let count = u16::try_from(values.len()).unwrap_or(u16::MAX);
+                values.iter().sum::<f32>() / f32::from(count)
+            }
+            Self::Sum => values.iter().sum(),
+            Self::Max => values.iter().copied().fold(f32::MIN, f32::max),
+        }
+    }
+}
+
+/// Parent links between regions (e.g. country -> state -> city).
+///
+/// Regions without a parent sit at resolution level 0; each step down the tree adds one.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RegionHierarchy {
+    /// Parent region keyed by child region.
+    pub parents: IndexMap<String, String>,
+    /// Aggregation used when rolling child metrics up.
+    pub aggregation: RegionAggregation,
+}
+
+impl RegionHierarchy {
+    /// Creates an empty hierarchy with the given aggregation.
+    #[must_use]
+    pub fn new(aggregation: RegionAggregation) -> Self {
+        Self {
+            parents: IndexMap::new(),
+            aggregation,
+        }
+    }
+
+    /// Places `child` under `parent`.
+    #[must_use]
+    pub fn with_parent(mut self, child: impl Into<String>, parent: impl Into<String>) -> Self {
+        self.parents.insert(child.into(), parent.into());
+        self
+    }
+
+    /// Returns the direct parent of `region`.
+    #[must_use]
+    pub fn parent(&self, region: &str) -> Option<&str> {
+        self.parents.get(region).map(String::as_str)
+    }
+
+    /// Returns the direct children of `region`.
+    pub fn children<'a>(&'a self, region: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.parents
+            .iter()
+            .filter(move |(_, parent)| parent.as_str() == region)
+            .map(|(child, _)| child.as_str())
+    }
+
+    /// Resolution level of `region`; cyclic links stop after visiting every edge once.
+    #[must_use]
+    pub fn level(&self, region: &str) -> usize {
+        let mut level = 0;
+        let mut current = region;
+        while let Some(parent) = self.parent(current) {
+            if level == self.parents.len() {
+                break;
+            }
+            level += 1;
+            current = parent;
+        }
+        level
+    }
+
+    /// Whether any parent links are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+}
+
 /// Full world state with multiple regions and anomaly timeline.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WorldState {
@@ -59,6 +149,12 @@ pub struct WorldState {
     pub regions: IndexMap<String, RegionSnapshot>,
     /// Anomaly events timeline.
     pub anomalies: Vec<AnomalyEvent>,
+    /// Region hierarchy used to roll metrics up to parent regions.
+    #[serde(default)]
+    pub hierarchy: RegionHierarchy,
+    /// Parent aggregates computed from their children, kept apart from observed regions.
+    #[serde(default)]
+    pub rollups: IndexMap<String, RegionSnapshot>,
 }
 
 impl WorldState {
@@ -75,6 +171,54 @@ impl WorldState {
         }
     }
 
+    /// Recomputes every parent aggregate from its children, deepest parents first so
+    /// multi-level trees roll all the way up. Observed snapshots are never overwritten.
+    pub fn roll_up(&mut self) {
+        self.rollups.clear();
+        let mut parents: Vec<String> = self
+            .hierarchy
+            .parents
+            .values()
+            .cloned()
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect();
+        parents.sort_by_key(|parent| std::cmp::Reverse(self.hierarchy.level(parent)));
+        for parent in parents {
+            let mut values: IndexMap<&str, Vec<f32>> = IndexMap::new();
+            for child in self.hierarchy.children(&parent) {
+                let Some(snapshot) = self.rollups.get(child).or_else(|| self.regions.get(child))
+                else {
+                    continue;
+                };
+                for (key, value) in &snapshot.metrics {
+                    values.entry(key.as_str()).or_default().push(*value);
+                }
+            }
+            if values.is_empty() {
+                continue;
+            }
+            let metrics = values
+                .into_iter()
+                .map(|(key, values)| (key.to_string(), self.hierarchy.aggregation.combine(&values)))
+                .collect();
+            let snapshot = RegionSnapshot::from_metrics(parent.clone(), metrics);
+            self.rollups.insert(parent, snapshot);
+        }
+    }
+
+    /// Regions at the requested resolution level (0 = top of the hierarchy), preferring a
+    /// parent's roll-up over its own observed snapshot.
+    #[must_use]
+    pub fn regions_at_level(&self, level: usize) -> Vec<&RegionSnapshot> {
+        self.regions
+            .values()
+            .filter(|snapshot| !self.rollups.contains_key(&snapshot.region_id))
+            .chain(self.rollups.values())
+            .filter(|snapshot| self.hierarchy.level(&snapshot.region_id) == level)
+            .collect()
+    }
+
     /// Returns highest severity anomaly.
     #[must_use]
     pub fn highest_severity(&self) -> Option<&AnomalyEvent> {
@@ -99,6 +243,19 @@ impl WorldModel {
         }
     }
 
+    /// Replaces the region hierarchy used for roll-ups.
+    #[must_use]
+    pub fn with_hierarchy(mut self, hierarchy: RegionHierarchy) -> Self {
+        self.state.hierarchy = hierarchy;
+        self
+    }
+
+    /// Creates a model seeded with a previously captured state.
+    #[must_use]
+    pub const fn from_state(state: WorldState) -> Self {
+        Self { state }
+    }
+
     /// Ingests a region snapshot, returns delta metrics.
     pub fn ingest(&mut self, snapshot: RegionSnapshot) -> IndexMap<String, f32> {
         let prev = self.state.regions.get(&snapshot.region_id).cloned();
@@ -109,6 +266,11 @@ impl WorldModel {
         }
     }
 
+    /// Rolls child metrics up into their parent regions.
+    pub fn roll_up(&mut self) {
+        self.state.roll_up();
+    }
+
     /// Adds anomaly event.
     pub fn anomaly(&mut self, event: AnomalyEvent) {
         self.state.record_anomaly(event);
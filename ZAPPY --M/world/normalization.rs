@@ -0,0 +1,143 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Rescaling applied to a single metric.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationMethod {
+    /// Maps the observed range onto 0-1.
+    MinMax,
+    /// Standard score: distance from the mean in standard deviations.
+    ZScore,
+}
+
+/// Running statistics for one metric (Welford's online algorithm).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MetricStats {
+    /// Number of observed values.
+    pub count: u64,
+    /// Running mean.
+    pub mean: f64,
+    /// Sum of squared distances from the mean.
+    pub m2: f64,
+    /// Smallest observed value.
+    pub min: f64,
+    /// Largest observed value.
+    pub max: f64,
+}
+
+impl Default for MetricStats {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl MetricStats {
+    /// Folds a new value into the statistics.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn observe(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Population standard deviation of the observed values.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn std_dev(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        (self.m2 / self.count as f64).sqrt()
+    }
+
+    /// Rescales `value`; degenerate statistics (no spread) map to 0.
+    #[must_use]
+    pub fn normalize(&self, method: NormalizationMethod, value: f64) -> f64 {
+        let (offset, scale) = match method {
+            NormalizationMethod::MinMax => (self.min, self.max - self.min),
+            NormalizationMethod::ZScore => (self.mean, self.std_dev()),
+        };
+        if self.count == 0 || scale <= f64::EPSILON {
+            return 0.0;
+        }
+        (value - offset) / scale
+    }
+}
+
+/// Per-metric normalization stage applied to signals before assimilation.
+///
+/// Only configured metrics are rescaled; every value seen is folded into the running
+/// statistics before it is normalized.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignalNormalizer {
+    methods: IndexMap<String, NormalizationMethod>,
+    stats: IndexMap<String, MetricStats>,
+}
+
+impl SignalNormalizer {
+    /// Creates a normalizer that leaves every metric untouched.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalizes `metric` with `method`.
+    #[must_use]
+    pub fn metric(mut self, metric: impl Into<String>, method: NormalizationMethod) -> Self {
+        self.methods.insert(metric.into(), method);
+        self
+    }
+
+    /// Seeds the running statistics, e.g. from [`crate::FeatureStore::metric_stats`].
+    #[must_use]
+    pub fn with_stats(mut self, stats: IndexMap<String, MetricStats>) -> Self {
+        self.stats.extend(stats);
+        self
+    }
+
+    /// Running statistics for `metric`, if any values were observed.
+    #[must_use]
+    pub fn stats(&self, metric: &str) -> Option<&MetricStats> {
+        self.stats.get(metric)
+    }
+
+    /// Updates statistics with `metrics` and rescales the configured ones in place.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn apply(&mut self, metrics: &mut IndexMap<String, f32>) {
+        for (metric, value) in metrics.iter_mut() {
+            let Some(&method) = self.methods.get(metric) else {
+                continue;
+            };
+            let stats = self.stats.entry(metric.clone()).or_default();
+            stats.observe(f64::from(*value));
+            *value = stats.normalize(method, f64::from(*value)) as f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn z_score_uses_running_mean_and_deviation() {
+        let mut stats = MetricStats::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.observe(value);
+        }
+        assert!((stats.mean - 5.0).abs() < 1e-9);
+        assert!((stats.std_dev() - 2.0).abs() < 1e-9);
+        assert!((stats.normalize(NormalizationMethod::ZScore, 9.0) - 2.0).abs() < 1e-9);
+        assert!((stats.normalize(NormalizationMethod::MinMax, 5.5) - 0.5).abs() < 1e-9);
+    }
+}
@@ -28,22 +28,40 @@ pub mod infoseeker;
 #[path = "../feed_config.rs"]
 pub mod feed_config;
 
+/// Per-metric normalization of signals before assimilation.
+#[path = "../normalization.rs"]
+pub mod normalization;
+
 /// Feature store for persisting assimilation inputs.
 #[path = "../feature_store.rs"]
 pub mod feature_store;
 
+/// Versioned runtime checkpoints.
+#[path = "../checkpoint.rs"]
+pub mod checkpoint;
+
 /// Advanced world algorithms.
 #[path = "../advanced/main.rs"]
 pub mod advanced;
 
+/// Webhook sink for triggered world alerts.
+#[path = "../alerting.rs"]
+pub mod alerting;
+
 /// Runtime entry point orchestrating state updates.
 #[path = "../main.rs"]
 pub mod runtime;
 
-pub use feature_store::FeatureStore;
+pub use alerting::WebhookAlertSink;
+pub use checkpoint::WorldCheckpoint;
+pub use feature_store::{
+    FeatureBackend, FeatureStore, FileFeatureBackend, MemoryFeatureBackend, SegmentEntry,
+    StoredSignal,
+};
 pub use feed_config::{FeedConfig, FeedKind, FeedsDocument};
 pub use infoseeker::{InfoSeeker, InfoSeekerBuilder, InfoSignal};
 pub use learning::{AssimilationEngine, AssimilationJob};
-pub use model::{WorldModel, WorldState};
+pub use model::{RegionAggregation, RegionHierarchy, WorldModel, WorldState};
+pub use normalization::{MetricStats, NormalizationMethod, SignalNormalizer};
 pub use runtime::{WorldRuntime, WorldRuntimeBuilder};
 pub use telemetry::{WorldTelemetry, WorldTelemetryBuilder};
@@ -135,6 +135,7 @@ impl WorldTelemetry {
                 event_type: event_type.into(),
                 timestamp: chrono::Utc::now().to_rfc3339(),
                 payload,
+                sequence: 0,
             })?;
         }
         Ok(())